@@ -4,5 +4,6 @@ pub mod describe;
 pub mod exit_code;
 pub mod init;
 pub mod license;
+pub mod lint;
 pub mod refresh;
 pub mod tree;
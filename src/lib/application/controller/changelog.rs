@@ -1,112 +1,359 @@
 use std::str::FromStr;
 
+use minijinja::Environment;
+use regex::Regex;
+
 use crate::{
     application::{
         manager::message_egress_manager::MessageEgressManager,
         manager::{
             bounded_commit_summary_ingress_manager::BoundedCommitSummaryIngressManager,
+            changelog_file_egress_manager::ChangelogFileEgressManager,
+            changelog_file_ingress_manager::ChangelogFileIngressManager,
+            changelog_template_ingress_manager::ChangelogTemplateIngressManager,
             version_ingress_manager::VersionIngressManager,
         },
-        options::changelog::{ChangelogOptions, FORMAT_PLACEHOLDER},
+        options::{
+            changelog::{ChangelogOptions, GroupBy, FORMAT_PLACEHOLDER},
+            output_format::OutputFormat,
+        },
         repository_impl::{
             bounded_commit_summary_ingress_repository_impl::BoundedCommitSummaryIngressRepositoryImpl,
             semantic_version_ingress_repository_impl::SemanticVersionIngressRepositoryImpl,
         },
     },
-    domain::trigger::Trigger,
+    domain::{semantic_version::SemanticVersion, trigger::Trigger},
     usecase::{
-        configuration::changelog::{ChangelogConfiguration, ChangelogFormat},
-        usecases::{create_changelog::CreateChangelogUseCase, usecase::UseCase},
+        changelog::{Changelog, ChangelogBound},
+        configuration::changelog::{
+            ChangelogConfiguration, ChangelogFormat, ChangelogGroupBy, ChangelogLinks,
+            ChangelogOrder, ChangelogRange, ChangelogTransformer,
+        },
+        tag_format::TagFormat,
+        type_aliases::AnyError,
+        usecases::{
+            create_changelog::CreateChangelogUseCase,
+            create_full_changelog::CreateFullChangelogUseCase, usecase::UseCase,
+        },
     },
 };
 
 use super::exit_code::ControllerExitCode;
 
+/**
+ * The templates shipped with gitbox, as (name, template name, source).
+ * The extension of the template name enables the HTML auto-escaping.
+ */
+const BUILTIN_TEMPLATES: [(&str, &str, &str); 3] = [
+    (
+        "markdown",
+        "changelog.md",
+        include_str!("templates/changelog.md"),
+    ),
+    (
+        "asciidoc",
+        "changelog.adoc",
+        include_str!("templates/changelog.adoc"),
+    ),
+    (
+        "html",
+        "changelog.html",
+        include_str!("templates/changelog.html"),
+    ),
+];
+
 pub struct ChangelogController<'a> {
     options: ChangelogOptions,
     commit_retriever: &'a dyn BoundedCommitSummaryIngressManager,
     version_retriever: &'a dyn VersionIngressManager,
+    template_retriever: &'a dyn ChangelogTemplateIngressManager,
+    file_retriever: &'a dyn ChangelogFileIngressManager,
+    file_writer: &'a dyn ChangelogFileEgressManager,
     output_manager: &'a dyn MessageEgressManager,
 }
 
-impl<'a, 'b: 'a, 'c: 'a, 'd: 'a> ChangelogController<'a> {
+impl<'a, 'b: 'a, 'c: 'a, 'd: 'a, 'e: 'a, 'f: 'a, 'g: 'a> ChangelogController<'a> {
     pub fn new(
         options: ChangelogOptions,
         commit_retriever: &'b dyn BoundedCommitSummaryIngressManager,
         version_retriever: &'c dyn VersionIngressManager,
-        output_manager: &'d dyn MessageEgressManager,
+        template_retriever: &'d dyn ChangelogTemplateIngressManager,
+        file_retriever: &'e dyn ChangelogFileIngressManager,
+        file_writer: &'f dyn ChangelogFileEgressManager,
+        output_manager: &'g dyn MessageEgressManager,
     ) -> Self {
         ChangelogController {
             options,
             commit_retriever,
             version_retriever,
+            template_retriever,
+            file_retriever,
+            file_writer,
             output_manager,
         }
     }
 
     pub fn changelog(&self) -> ControllerExitCode {
-        let trigger: Option<Trigger> = match self.options.exclude_trigger() {
-            Some(t) => match Trigger::from_str(t) {
-                Ok(v) => Some(v),
-                Err(e) => {
-                    self.output_manager.error(&e.to_string());
-                    return ControllerExitCode::Error(1);
-                }
-            },
-            None => None,
-        };
+        match self.run() {
+            Ok(_) => ControllerExitCode::Ok,
+            Err(e) => {
+                self.output_manager.error(&e.to_string());
+                ControllerExitCode::Error(1)
+            }
+        }
+    }
+
+    fn run(&self) -> Result<(), AnyError> {
+        let trigger = self
+            .options
+            .content()
+            .exclude_trigger()
+            .map(Trigger::from_str)
+            .transpose()?;
+        let version = self
+            .options
+            .version()
+            .map(SemanticVersion::from_str)
+            .transpose()?;
         let configuration = ChangelogConfiguration::new(
-            self.options.generate_from_latest_version(),
-            ChangelogFormat::new(
-                Box::new(|it| {
-                    self.options
-                        .format()
-                        .title()
-                        .replace(FORMAT_PLACEHOLDER, it)
-                }),
-                Box::new(|it| self.options.format().typ().replace(FORMAT_PLACEHOLDER, it)),
-                Box::new(|it| {
-                    self.options
-                        .format()
-                        .scope()
-                        .replace(FORMAT_PLACEHOLDER, it)
-                }),
-                Box::new(|it| self.options.format().list().replace(FORMAT_PLACEHOLDER, it)),
-                Box::new(|it| self.options.format().item().replace(FORMAT_PLACEHOLDER, it)),
-                Box::new(|it| {
-                    self.options
-                        .format()
-                        .breaking()
-                        .replace(FORMAT_PLACEHOLDER, it)
-                }),
+            ChangelogRange::new(
+                self.options.range().generate_from_latest_version(),
+                self.options.range().from().map(changelog_bound),
+                self.options.range().to().map(changelog_bound),
+                self.options.range().paths().to_vec(),
+                TagFormat::new(self.options.range().tag_template())?,
             ),
             trigger,
+            ChangelogOrder::new(
+                self.options
+                    .content()
+                    .types()
+                    .iter()
+                    .map(|(typ, title)| (typ.clone(), title.clone().unwrap_or(typ.clone())))
+                    .collect(),
+                self.options.content().general_scope_last(),
+            ),
+            ChangelogLinks::new(
+                self.options.content().commit_url().map(str::to_owned),
+                self.options.content().issue_url().map(str::to_owned),
+            ),
+            self.options.content().breaking_section(),
+            match self.options.content().group_by() {
+                GroupBy::Type => ChangelogGroupBy::Type,
+                GroupBy::Scope => ChangelogGroupBy::Scope,
+                GroupBy::None => ChangelogGroupBy::None,
+            },
+            version,
         );
         let bounded_commit_summary_ingress_repository_impl =
             BoundedCommitSummaryIngressRepositoryImpl::new(self.commit_retriever);
         let semantic_version_ingress_repository_impl =
             SemanticVersionIngressRepositoryImpl::new(self.version_retriever);
-        let usecase = CreateChangelogUseCase::new(
-            configuration,
-            &bounded_commit_summary_ingress_repository_impl,
-            &semantic_version_ingress_repository_impl,
-        );
-        match usecase.execute() {
-            Ok(c) => {
-                self.output_manager.output(&c);
-                ControllerExitCode::Ok
+        let changelogs = if self.options.range().all_versions() {
+            CreateFullChangelogUseCase::new(
+                configuration,
+                &bounded_commit_summary_ingress_repository_impl,
+                &semantic_version_ingress_repository_impl,
+            )
+            .execute()?
+        } else {
+            vec![CreateChangelogUseCase::new(
+                configuration,
+                &bounded_commit_summary_ingress_repository_impl,
+                &semantic_version_ingress_repository_impl,
+            )
+            .execute()?]
+        };
+        let text = match (self.options.output_format(), self.options.template()) {
+            (OutputFormat::Text, None) => changelogs
+                .iter()
+                .map(|it| it.format(&self.format()))
+                .collect::<Vec<String>>()
+                .join("\n"),
+            (OutputFormat::Text, Some(template)) => changelogs
+                .iter()
+                .map(|it| self.render_template(it, template))
+                .collect::<Result<Vec<String>, AnyError>>()
+                .map_err(|e| format!("Failed to render template '{}': {}", template, e))?
+                .join("\n\n"),
+            (OutputFormat::Json, _) if self.options.range().all_versions() => {
+                serde_json::to_string_pretty(&changelogs)?
             }
-            Err(e) => {
-                self.output_manager.error(&e.to_string());
-                ControllerExitCode::Error(1)
+            (OutputFormat::Json, _) => serde_json::to_string_pretty(&changelogs[0])?,
+        };
+        match self.options.write() {
+            // The history is empty when no version has been released and no commit is left.
+            None if changelogs.is_empty() && self.options.output_format() == OutputFormat::Text => {
             }
+            None => self.output_manager.output(&text),
+            // --write conflicts with --all-versions, so there is exactly one changelog.
+            Some(path) => match changelogs[0].version() {
+                Some(version) => {
+                    self.write_section(path, &text, version)?;
+                    self.output_manager.output(&format!(
+                        "Changes of version {} written to {}",
+                        version, path
+                    ));
+                }
+                None => Err("a version is required to write the changelog to a file")?,
+            },
         }
+        Ok(())
+    }
+
+    fn format(&self) -> ChangelogFormat<'_> {
+        ChangelogFormat::new(
+            Box::new(|it| {
+                self.options
+                    .format()
+                    .title()
+                    .replace(FORMAT_PLACEHOLDER, it)
+            }),
+            Box::new(|it| self.options.format().typ().replace(FORMAT_PLACEHOLDER, it)),
+            Box::new(|it| {
+                self.options
+                    .format()
+                    .scope()
+                    .replace(FORMAT_PLACEHOLDER, it)
+            }),
+            Box::new(|it| self.options.format().list().replace(FORMAT_PLACEHOLDER, it)),
+            Box::new(|it| self.options.format().item().replace(FORMAT_PLACEHOLDER, it)),
+            Box::new(|it| {
+                self.options
+                    .format()
+                    .breaking()
+                    .replace(FORMAT_PLACEHOLDER, it)
+            }),
+            self.options.format().hash().map(|hash| {
+                Box::new(move |it: &String| hash.replace(FORMAT_PLACEHOLDER, it))
+                    as ChangelogTransformer<'_>
+            }),
+        )
+    }
+
+    /**
+     * The template is either the name of a built-in template or the path of a template file.
+     */
+    fn render_template(&self, changelog: &Changelog, template: &str) -> Result<String, AnyError> {
+        let (name, source) = match BUILTIN_TEMPLATES.iter().find(|it| it.0 == template) {
+            Some((_, name, source)) => (name.to_string(), source.to_string()),
+            None => (
+                template.to_owned(),
+                self.template_retriever.read_template(template)?,
+            ),
+        };
+        let mut environment = Environment::new();
+        environment.set_trim_blocks(true);
+        environment.set_lstrip_blocks(true);
+        let text = environment
+            .template_from_named_str(&name, &source)?
+            .render(changelog)?;
+        Ok(text.trim_end().to_owned())
+    }
+
+    /**
+     * Puts the section of the version at the top of the changelog file, before the sections of
+     * the other versions, or in place of the existing section of the same version.
+     */
+    fn write_section(
+        &self,
+        path: &str,
+        section: &str,
+        version: &SemanticVersion,
+    ) -> Result<(), AnyError> {
+        let document = self
+            .file_retriever
+            .read_changelog(path)?
+            .unwrap_or_default();
+        let updated = merge_section(&document, section, version)?;
+        self.file_writer.write_changelog(path, &updated)
     }
 }
 
+/**
+ * A bound given as a semantic version is a version tag, otherwise it is used as a plain git reference.
+ */
+fn changelog_bound(value: &str) -> ChangelogBound {
+    SemanticVersion::from_str(value).map_or_else(
+        |_| ChangelogBound::Ref(value.to_string()),
+        ChangelogBound::Version,
+    )
+}
+
+/**
+ * The sections of the document are recognised by their title, which must look like the one of
+ * the new section with another version in place of the given one, optionally followed by the
+ * release date. If no title is recognised, the new section goes after the preamble, i.e. before
+ * the first line which contains a version.
+ */
+fn merge_section(
+    document: &str,
+    section: &str,
+    version: &SemanticVersion,
+) -> Result<String, AnyError> {
+    let version_str = version.to_string();
+    let title = section.lines().next().unwrap_or_default();
+    let (prefix, suffix) = title.split_once(&version_str).ok_or_else(|| {
+        format!(
+            "the first line of the changelog must contain the version '{}'",
+            version_str
+        )
+    })?;
+    let title_regex = Regex::new(&format!(
+        "^{}(\\S+)(?: \\([^)]*\\))?{}$",
+        regex::escape(prefix),
+        regex::escape(suffix)
+    ))?;
+    let section_version = |line: &str| {
+        title_regex
+            .captures(line)
+            .and_then(|it| SemanticVersion::from_str(&it[1]).ok())
+    };
+    let lines: Vec<&str> = document.lines().collect();
+    let titles: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| section_version(line).is_some())
+        .map(|(i, _)| i)
+        .collect();
+    let existing = titles
+        .iter()
+        .position(|i| section_version(lines[*i]).as_ref() == Some(version));
+    let (before, after) = match existing {
+        Some(p) => (
+            &lines[..titles[p]],
+            titles
+                .get(p + 1)
+                .map_or(&lines[lines.len()..], |i| &lines[*i..]),
+        ),
+        None => {
+            let any_version = Regex::new(r"(?:^|[^\d.])\d+\.\d+\.\d+")?;
+            match titles
+                .first()
+                .copied()
+                .or_else(|| lines.iter().position(|it| any_version.is_match(it)))
+            {
+                Some(i) => (&lines[..i], &lines[i..]),
+                None => (&lines[..], &lines[lines.len()..]),
+            }
+        }
+    };
+    Ok([
+        before.join("\n"),
+        section.trim().to_owned(),
+        after.join("\n"),
+    ]
+    .iter()
+    .map(|it| it.trim())
+    .filter(|it| !it.is_empty())
+    .collect::<Vec<&str>>()
+    .join("\n\n")
+        + "\n")
+}
+
 #[cfg(test)]
 mod tests {
-    use std::{error::Error, fmt::Display, rc::Rc};
+    use std::{cell::RefCell, error::Error, fmt::Display};
 
     use crate::{
         application::{
@@ -114,29 +361,41 @@ mod tests {
             manager::message_egress_manager::MessageEgressManager,
             manager::{
                 bounded_commit_summary_ingress_manager::BoundedCommitSummaryIngressManager,
+                changelog_file_egress_manager::ChangelogFileEgressManager,
+                changelog_file_ingress_manager::ChangelogFileIngressManager,
+                changelog_template_ingress_manager::ChangelogTemplateIngressManager,
                 version_ingress_manager::VersionIngressManager,
             },
-            options::changelog::{ChangelogFormatOptions, ChangelogOptions},
+            options::{
+                changelog::{
+                    ChangelogContentOptions, ChangelogFormatOptions, ChangelogOptions,
+                    ChangelogRangeOptions, GroupBy,
+                },
+                output_format::OutputFormat,
+            },
         },
         domain::semantic_version::SemanticVersion,
-        usecase::type_aliases::AnyError,
+        usecase::{tag_format::TagFormat, type_aliases::AnyError},
     };
 
-    use super::ChangelogController;
+    use super::{merge_section, ChangelogController};
 
     struct MockCommitRetriever {}
     impl BoundedCommitSummaryIngressManager for MockCommitRetriever {
         fn get_commits_from(
             &self,
-            _version: Rc<Option<SemanticVersion>>,
-        ) -> Result<Box<dyn DoubleEndedIterator<Item = String>>, AnyError> {
+            _from: Option<&str>,
+            _to: Option<&str>,
+            _paths: &[String],
+        ) -> Result<Box<dyn DoubleEndedIterator<Item = (String, String)>>, AnyError> {
             Ok(Box::new(
                 vec![
-                    "feat: test".to_owned(),
-                    "fix: test".to_owned(),
-                    "test: test".to_owned(),
+                    ("a1", "feat: test"),
+                    ("b2", "fix: test"),
+                    ("c3", "test: test"),
                 ]
-                .into_iter(),
+                .into_iter()
+                .map(|(hash, message)| (hash.to_owned(), message.to_owned())),
             ))
         }
     }
@@ -153,26 +412,108 @@ mod tests {
 
     struct MockVersionRetriever {}
     impl VersionIngressManager for MockVersionRetriever {
-        fn last_version(&self) -> Result<Option<String>, AnyError> {
+        fn last_version(&self, _tag_format: &TagFormat) -> Result<Option<String>, AnyError> {
             Err(Box::new(MockVersionError {}))
         }
 
-        fn last_stable_version(&self) -> Result<Option<String>, AnyError> {
+        fn last_stable_version(&self, _tag_format: &TagFormat) -> Result<Option<String>, AnyError> {
             Ok(Some("0.1.0".to_owned()))
         }
+
+        fn all_versions(&self, _tag_format: &TagFormat) -> Result<Vec<(String, String)>, AnyError> {
+            Ok(vec![("0.1.0".to_owned(), "2024-01-01".to_owned())])
+        }
+    }
+
+    struct MockEmptyHistory {}
+    impl BoundedCommitSummaryIngressManager for MockEmptyHistory {
+        fn get_commits_from(
+            &self,
+            _from: Option<&str>,
+            _to: Option<&str>,
+            _paths: &[String],
+        ) -> Result<Box<dyn DoubleEndedIterator<Item = (String, String)>>, AnyError> {
+            Ok(Box::new(std::iter::empty()))
+        }
+    }
+    impl VersionIngressManager for MockEmptyHistory {
+        fn last_version(&self, _tag_format: &TagFormat) -> Result<Option<String>, AnyError> {
+            Ok(None)
+        }
+
+        fn last_stable_version(&self, _tag_format: &TagFormat) -> Result<Option<String>, AnyError> {
+            Ok(None)
+        }
+
+        fn all_versions(&self, _tag_format: &TagFormat) -> Result<Vec<(String, String)>, AnyError> {
+            Ok(vec![])
+        }
+    }
+
+    struct MockTemplateRetriever {}
+    impl ChangelogTemplateIngressManager for MockTemplateRetriever {
+        fn read_template(&self, _path: &str) -> Result<String, AnyError> {
+            Ok("{% for type in types %}{{ type.type }} {% endfor %}".to_string())
+        }
+    }
+
+    struct MockFileManager {
+        content: RefCell<Option<String>>,
+    }
+    impl MockFileManager {
+        pub fn new(content: Option<&str>) -> Self {
+            MockFileManager {
+                content: RefCell::new(content.map(|it| it.to_string())),
+            }
+        }
+    }
+    impl ChangelogFileIngressManager for MockFileManager {
+        fn read_changelog(&self, _path: &str) -> Result<Option<String>, AnyError> {
+            Ok(self.content.borrow().clone())
+        }
+    }
+    impl ChangelogFileEgressManager for MockFileManager {
+        fn write_changelog(&self, _path: &str, text: &str) -> Result<(), AnyError> {
+            self.content.replace(Some(text.to_string()));
+            Ok(())
+        }
     }
 
-    struct MockOutputManager {}
+    struct MockOutputManager {
+        output_buffer: RefCell<Vec<String>>,
+    }
+    impl MockOutputManager {
+        pub fn new() -> Self {
+            MockOutputManager {
+                output_buffer: RefCell::new(vec![]),
+            }
+        }
+    }
     impl MessageEgressManager for MockOutputManager {
-        fn output(&self, _message: &str) {}
+        fn output(&self, message: &str) {
+            self.output_buffer.borrow_mut().push(message.to_string());
+        }
 
         fn error(&self, _error: &str) {}
     }
 
+    fn format_options() -> ChangelogFormatOptions {
+        ChangelogFormatOptions::new(
+            String::from("%s"),
+            String::from("%s"),
+            String::from("%s"),
+            String::from("%s"),
+            String::from("%s"),
+            String::from("%s"),
+            None,
+        )
+        .expect("hand-crafted options should be correct")
+    }
+
     #[test]
     fn wrong_trigger_exits_with_error() {
         let options = ChangelogOptions::new(
-            false,
+            ChangelogRangeOptions::new(false, false, None, None, vec![], "{version}".to_string()),
             ChangelogFormatOptions::new(
                 String::from("%s"),
                 String::from("%s"),
@@ -180,17 +521,35 @@ mod tests {
                 String::from("%s"),
                 String::from("%s"),
                 String::from("%s"),
+                None,
             )
             .expect("hand-crafted options are correct"),
-            Some("abc".to_string()),
+            ChangelogContentOptions::new(
+                Some("abc".to_string()),
+                vec![],
+                false,
+                None,
+                None,
+                false,
+                GroupBy::Type,
+            ),
+            None,
+            None,
+            None,
+            OutputFormat::Text,
         );
         let commit_retriever = MockCommitRetriever {};
         let version_retriever = MockVersionRetriever {};
-        let output_manager = MockOutputManager {};
+        let template_retriever = MockTemplateRetriever {};
+        let file_manager = MockFileManager::new(None);
+        let output_manager = MockOutputManager::new();
         let controller = ChangelogController::new(
             options,
             &commit_retriever,
             &version_retriever,
+            &template_retriever,
+            &file_manager,
+            &file_manager,
             &output_manager,
         );
         let result = controller.changelog();
@@ -200,7 +559,7 @@ mod tests {
     #[test]
     fn correct_usecase_execution() {
         let options = ChangelogOptions::new(
-            false,
+            ChangelogRangeOptions::new(false, false, None, None, vec![], "{version}".to_string()),
             ChangelogFormatOptions::new(
                 String::from("%s"),
                 String::from("%s"),
@@ -208,17 +567,27 @@ mod tests {
                 String::from("%s"),
                 String::from("%s"),
                 String::from("%s"),
+                None,
             )
             .expect("hand-crafted options should be correct"),
+            ChangelogContentOptions::new(None, vec![], false, None, None, false, GroupBy::Type),
+            None,
+            None,
             None,
+            OutputFormat::Text,
         );
         let commit_retriever = MockCommitRetriever {};
         let version_retriever = MockVersionRetriever {};
-        let output_manager = MockOutputManager {};
+        let template_retriever = MockTemplateRetriever {};
+        let file_manager = MockFileManager::new(None);
+        let output_manager = MockOutputManager::new();
         let controller = ChangelogController::new(
             options,
             &commit_retriever,
             &version_retriever,
+            &template_retriever,
+            &file_manager,
+            &file_manager,
             &output_manager,
         );
         let result = controller.changelog();
@@ -228,7 +597,7 @@ mod tests {
     #[test]
     fn failed_execution_of_usecase() {
         let options = ChangelogOptions::new(
-            true,
+            ChangelogRangeOptions::new(true, false, None, None, vec![], "{version}".to_string()),
             ChangelogFormatOptions::new(
                 String::from("%s"),
                 String::from("%s"),
@@ -236,20 +605,319 @@ mod tests {
                 String::from("%s"),
                 String::from("%s"),
                 String::from("%s"),
+                None,
             )
             .expect("hand-crafted options should be correct"),
+            ChangelogContentOptions::new(None, vec![], false, None, None, false, GroupBy::Type),
             None,
+            None,
+            None,
+            OutputFormat::Text,
         );
         let commit_retriever = MockCommitRetriever {};
         let version_retriever = MockVersionRetriever {};
-        let output_manager = MockOutputManager {};
+        let template_retriever = MockTemplateRetriever {};
+        let file_manager = MockFileManager::new(None);
+        let output_manager = MockOutputManager::new();
         let controller = ChangelogController::new(
             options,
             &commit_retriever,
             &version_retriever,
+            &template_retriever,
+            &file_manager,
+            &file_manager,
             &output_manager,
         );
         let result = controller.changelog();
         assert!(matches!(result, ControllerExitCode::Error(..)));
     }
+
+    #[test]
+    fn builtin_template() {
+        let options = ChangelogOptions::new(
+            ChangelogRangeOptions::new(false, false, None, None, vec![], "{version}".to_string()),
+            format_options(),
+            ChangelogContentOptions::new(None, vec![], false, None, None, false, GroupBy::Type),
+            Some("markdown".to_string()),
+            None,
+            None,
+            OutputFormat::Text,
+        );
+        let commit_retriever = MockCommitRetriever {};
+        let version_retriever = MockVersionRetriever {};
+        let template_retriever = MockTemplateRetriever {};
+        let file_manager = MockFileManager::new(None);
+        let output_manager = MockOutputManager::new();
+        let controller = ChangelogController::new(
+            options,
+            &commit_retriever,
+            &version_retriever,
+            &template_retriever,
+            &file_manager,
+            &file_manager,
+            &output_manager,
+        );
+        let result = controller.changelog();
+        assert!(matches!(result, ControllerExitCode::Ok));
+        assert_eq!(
+            output_manager.output_buffer.borrow().as_slice(),
+            &[concat!(
+                "# Changes from version 0.1.0\n",
+                "\n",
+                "## feat\n",
+                "\n",
+                "- test\n",
+                "\n",
+                "## fix\n",
+                "\n",
+                "- test\n",
+                "\n",
+                "## test\n",
+                "\n",
+                "- test",
+            )]
+        );
+    }
+
+    #[test]
+    fn template_file() {
+        let options = ChangelogOptions::new(
+            ChangelogRangeOptions::new(false, false, None, None, vec![], "{version}".to_string()),
+            format_options(),
+            ChangelogContentOptions::new(None, vec![], false, None, None, false, GroupBy::Type),
+            Some("changelog.tmpl".to_string()),
+            None,
+            None,
+            OutputFormat::Text,
+        );
+        let commit_retriever = MockCommitRetriever {};
+        let version_retriever = MockVersionRetriever {};
+        let template_retriever = MockTemplateRetriever {};
+        let file_manager = MockFileManager::new(None);
+        let output_manager = MockOutputManager::new();
+        let controller = ChangelogController::new(
+            options,
+            &commit_retriever,
+            &version_retriever,
+            &template_retriever,
+            &file_manager,
+            &file_manager,
+            &output_manager,
+        );
+        let result = controller.changelog();
+        assert!(matches!(result, ControllerExitCode::Ok));
+        assert_eq!(
+            output_manager.output_buffer.borrow().as_slice(),
+            &["feat fix test"]
+        );
+    }
+
+    #[test]
+    fn write_to_file() {
+        let options = ChangelogOptions::new(
+            ChangelogRangeOptions::new(false, false, None, None, vec![], "{version}".to_string()),
+            format_options(),
+            ChangelogContentOptions::new(None, vec![], false, None, None, false, GroupBy::Type),
+            Some("markdown".to_string()),
+            Some("0.2.0".to_string()),
+            Some("CHANGELOG.md".to_string()),
+            OutputFormat::Text,
+        );
+        let commit_retriever = MockCommitRetriever {};
+        let version_retriever = MockVersionRetriever {};
+        let template_retriever = MockTemplateRetriever {};
+        let file_manager = MockFileManager::new(Some("# Changelog\n\n# 0.1.0\n\n- first\n"));
+        let output_manager = MockOutputManager::new();
+        let controller = ChangelogController::new(
+            options,
+            &commit_retriever,
+            &version_retriever,
+            &template_retriever,
+            &file_manager,
+            &file_manager,
+            &output_manager,
+        );
+        let result = controller.changelog();
+        assert!(matches!(result, ControllerExitCode::Ok));
+        assert_eq!(
+            file_manager.content.borrow().as_deref(),
+            Some(concat!(
+                "# Changelog\n",
+                "\n",
+                "# 0.2.0\n",
+                "\n",
+                "## feat\n",
+                "\n",
+                "- test\n",
+                "\n",
+                "## fix\n",
+                "\n",
+                "- test\n",
+                "\n",
+                "## test\n",
+                "\n",
+                "- test\n",
+                "\n",
+                "# 0.1.0\n",
+                "\n",
+                "- first\n",
+            ))
+        );
+    }
+
+    fn version(v: &str) -> SemanticVersion {
+        SemanticVersion::new(
+            0,
+            v.parse().expect("Hand-crafted minor is correct"),
+            0,
+            None,
+            None,
+        )
+        .expect("Hand-crafted version is correct")
+    }
+
+    #[test]
+    fn merge_section_empty_document() {
+        let merged = merge_section("", "# 0.2.0\n\n- new\n", &version("2"))
+            .expect("The title contains the version");
+        assert_eq!(merged, "# 0.2.0\n\n- new\n");
+    }
+
+    #[test]
+    fn merge_section_new_version() {
+        let merged = merge_section(
+            "# Changelog\n\n# 0.1.0\n\n- old\n",
+            "# 0.2.0\n\n- new",
+            &version("2"),
+        )
+        .expect("The title contains the version");
+        assert_eq!(
+            merged,
+            "# Changelog\n\n# 0.2.0\n\n- new\n\n# 0.1.0\n\n- old\n"
+        );
+    }
+
+    #[test]
+    fn merge_section_existing_version() {
+        let document = "# Changelog\n\n# 0.2.0\n\n- stale\n\n# 0.1.0\n\n- old\n";
+        let merged = merge_section(document, "# 0.2.0\n\n- new", &version("2"))
+            .expect("The title contains the version");
+        assert_eq!(
+            merged,
+            "# Changelog\n\n# 0.2.0\n\n- new\n\n# 0.1.0\n\n- old\n"
+        );
+        assert_eq!(
+            merge_section(&merged, "# 0.2.0\n\n- new", &version("2"))
+                .expect("The title contains the version"),
+            merged
+        );
+    }
+
+    #[test]
+    fn merge_section_different_title_format() {
+        let merged = merge_section(
+            "# Changelog\n\nAll notable changes.\n\n## 0.1.0\n\n- old\n",
+            "# 0.2.0\n\n- new",
+            &version("2"),
+        )
+        .expect("The title contains the version");
+        assert_eq!(
+            merged,
+            "# Changelog\n\nAll notable changes.\n\n# 0.2.0\n\n- new\n\n## 0.1.0\n\n- old\n"
+        );
+    }
+
+    #[test]
+    fn merge_section_dated_titles() {
+        let merged = merge_section(
+            "# 0.2.0 (2024-02-01)\n\n- stale\n\n# 0.1.0 (2024-01-01)\n\n- old\n",
+            "# 0.2.0\n\n- new",
+            &version("2"),
+        )
+        .expect("The title contains the version");
+        assert_eq!(
+            merged,
+            "# 0.2.0\n\n- new\n\n# 0.1.0 (2024-01-01)\n\n- old\n"
+        );
+    }
+
+    #[test]
+    fn all_versions() {
+        let options = ChangelogOptions::new(
+            ChangelogRangeOptions::new(false, true, None, None, vec![], "{version}".to_string()),
+            format_options(),
+            ChangelogContentOptions::new(None, vec![], false, None, None, false, GroupBy::Type),
+            Some("changelog.tmpl".to_string()),
+            None,
+            None,
+            OutputFormat::Text,
+        );
+        let commit_retriever = MockCommitRetriever {};
+        let version_retriever = MockVersionRetriever {};
+        let template_retriever = MockTemplateRetriever {};
+        let file_manager = MockFileManager::new(None);
+        let output_manager = MockOutputManager::new();
+        let controller = ChangelogController::new(
+            options,
+            &commit_retriever,
+            &version_retriever,
+            &template_retriever,
+            &file_manager,
+            &file_manager,
+            &output_manager,
+        );
+        let result = controller.changelog();
+        assert!(matches!(result, ControllerExitCode::Ok));
+        assert_eq!(
+            output_manager.output_buffer.borrow().as_slice(),
+            &["feat fix test\n\nfeat fix test"]
+        );
+    }
+
+    #[test]
+    fn all_versions_of_empty_history() {
+        for (output_format, expected) in [
+            (OutputFormat::Text, vec![]),
+            (OutputFormat::Json, vec!["[]".to_string()]),
+        ] {
+            let options = ChangelogOptions::new(
+                ChangelogRangeOptions::new(
+                    false,
+                    true,
+                    None,
+                    None,
+                    vec![],
+                    "{version}".to_string(),
+                ),
+                format_options(),
+                ChangelogContentOptions::new(None, vec![], false, None, None, false, GroupBy::Type),
+                None,
+                None,
+                None,
+                output_format,
+            );
+            let history = MockEmptyHistory {};
+            let template_retriever = MockTemplateRetriever {};
+            let file_manager = MockFileManager::new(None);
+            let output_manager = MockOutputManager::new();
+            let controller = ChangelogController::new(
+                options,
+                &history,
+                &history,
+                &template_retriever,
+                &file_manager,
+                &file_manager,
+                &output_manager,
+            );
+            let result = controller.changelog();
+            assert!(matches!(result, ControllerExitCode::Ok));
+            assert_eq!(*output_manager.output_buffer.borrow(), expected);
+        }
+    }
+
+    #[test]
+    fn merge_section_without_version_title() {
+        let merged = merge_section("", "# Latest changes\n\n- new", &version("2"));
+        assert!(merged.is_err());
+    }
 }
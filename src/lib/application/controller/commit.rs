@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 use crate::{
     application::{
         manager::{
@@ -7,14 +9,19 @@ use crate::{
         options::commit::CommitOptions,
         repository_impl::conventional_commit_egress_repository_impl::ConventionalCommitEgressRepositoryImpl,
     },
+    domain::{commit_footer::CommitFooter, commit_policy::CommitPolicy},
     usecase::{
         configuration::commit::{AllowEmptyFlag, CommitConfiguration},
+        type_aliases::AnyError,
         usecases::{create_conventional_commit::CreateConventionalCommitUseCase, usecase::UseCase},
     },
 };
 
 use super::exit_code::ControllerExitCode;
 
+// The dashed form is a valid git trailer token, unlike the spaced one
+const BREAKING_CHANGE_TOKEN: &str = "BREAKING-CHANGE";
+
 pub struct CommitController<'a> {
     options: CommitOptions,
     commit_manager: &'a dyn ConventionalCommitEgressManager,
@@ -35,13 +42,25 @@ impl<'a, 'b: 'a, 'c: 'a> CommitController<'a> {
     }
 
     pub fn commit(&self) -> ControllerExitCode {
+        let footers = match self.footers() {
+            Ok(f) => f,
+            Err(e) => {
+                self.output_manager.error(&e.to_string());
+                return ControllerExitCode::Error(1);
+            }
+        };
         match CommitConfiguration::new(
             self.options.commit_type().to_string(),
             self.options.scope().map(|it| it.to_owned()),
             self.options.is_breaking(),
             self.options.summary().to_string(),
             self.options.message().map(|it| it.to_owned()),
+            footers,
             AllowEmptyFlag::Disabled,
+            CommitPolicy::new(
+                self.options.policy().allowed_types().cloned(),
+                self.options.policy().allowed_scopes().cloned(),
+            ),
         ) {
             Ok(configuration) => {
                 let commit_repository =
@@ -67,6 +86,26 @@ impl<'a, 'b: 'a, 'c: 'a> CommitController<'a> {
             }
         }
     }
+
+    /**
+     * The breaking change description becomes the last footer.
+     */
+    fn footers(&self) -> Result<Vec<CommitFooter>, AnyError> {
+        let mut footers = self
+            .options
+            .footers()
+            .footers()
+            .iter()
+            .map(|it| CommitFooter::from_str(it))
+            .collect::<Result<Vec<CommitFooter>, _>>()?;
+        if let Some(description) = self.options.footers().breaking_description() {
+            footers.push(CommitFooter::new(
+                BREAKING_CHANGE_TOKEN.to_string(),
+                description.to_string(),
+            )?);
+        }
+        Ok(footers)
+    }
 }
 
 #[cfg(test)]
@@ -80,7 +119,7 @@ mod tests {
                 conventional_commit_egress_manager::ConventionalCommitEgressManager,
                 message_egress_manager::MessageEgressManager,
             },
-            options::commit::CommitOptions,
+            options::commit::{CommitFooterOptions, CommitOptions, CommitPolicyOptions},
         },
         usecase::type_aliases::AnyError,
     };
@@ -132,7 +171,9 @@ mod tests {
             false,
             "test".to_string(),
             None,
+            CommitFooterOptions::new(vec![], None),
             false,
+            CommitPolicyOptions::new(None, None),
         )
         .expect("commit options are hand made");
         let commit_manager = MockCommitManager { fail: false };
@@ -150,7 +191,9 @@ mod tests {
             false,
             "test".to_string(),
             None,
+            CommitFooterOptions::new(vec![], None),
             false,
+            CommitPolicyOptions::new(None, None),
         )
         .expect("commit options are hand made");
         let commit_manager = MockCommitManager { fail: true };
@@ -159,4 +202,67 @@ mod tests {
         let result = controller.commit();
         assert!(matches!(result, ControllerExitCode::Error(..)));
     }
+
+    #[test]
+    fn commit_refused_by_policy() {
+        let options = CommitOptions::new(
+            "tset".to_string(),
+            None,
+            false,
+            "test".to_string(),
+            None,
+            CommitFooterOptions::new(vec![], None),
+            false,
+            CommitPolicyOptions::new(Some(vec!["test".to_string()]), None),
+        )
+        .expect("commit options are hand made");
+        let commit_manager = MockCommitManager { fail: false };
+        let output_manager = MockOutputManager {};
+        let controller = CommitController::new(options, &commit_manager, &output_manager);
+        let result = controller.commit();
+        assert!(matches!(result, ControllerExitCode::Error(..)));
+    }
+
+    #[test]
+    fn commit_with_footers() {
+        let options = CommitOptions::new(
+            "test".to_string(),
+            None,
+            false,
+            "test".to_string(),
+            None,
+            CommitFooterOptions::new(
+                vec!["Refs: #123".to_string()],
+                Some("the API changed".to_string()),
+            ),
+            false,
+            CommitPolicyOptions::new(None, None),
+        )
+        .expect("commit options are hand made");
+        let commit_manager = MockCommitManager { fail: false };
+        let output_manager = MockOutputManager {};
+        let controller = CommitController::new(options, &commit_manager, &output_manager);
+        let result = controller.commit();
+        assert!(matches!(result, ControllerExitCode::Ok));
+    }
+
+    #[test]
+    fn commit_with_malformed_footer() {
+        let options = CommitOptions::new(
+            "test".to_string(),
+            None,
+            false,
+            "test".to_string(),
+            None,
+            CommitFooterOptions::new(vec!["not a footer".to_string()], None),
+            false,
+            CommitPolicyOptions::new(None, None),
+        )
+        .expect("commit options are hand made");
+        let commit_manager = MockCommitManager { fail: false };
+        let output_manager = MockOutputManager {};
+        let controller = CommitController::new(options, &commit_manager, &output_manager);
+        let result = controller.commit();
+        assert!(matches!(result, ControllerExitCode::Error(..)));
+    }
 }
@@ -12,7 +12,11 @@ use crate::{
         manager::{
             message_egress_manager::MessageEgressManager, tag_egress_manager::TagEgressManager,
         },
-        options::describe::{DescribeOptions, PRERELEASE_NUM_PLACEHOLDER},
+        options::{
+            describe::{Bump, DescribeOptions, PRERELEASE_NUM_PLACEHOLDER},
+            output_format::OutputFormat,
+        },
+        presenter::version_description_presenter::ExplainedVersionDescription,
         repository_impl::{
             bounded_commit_summary_ingress_repository_impl::BoundedCommitSummaryIngressRepositoryImpl,
             commit_metadata_ingress_repository_impl::CommitMetadataIngressRepositoryImpl,
@@ -20,20 +24,24 @@ use crate::{
             tag_egress_repository_impl::TagEgressRepositoryImpl,
         },
     },
-    domain::trigger::Trigger,
+    domain::{semantic_version::SemanticVersion, trigger::Trigger},
     usecase::{
         configuration::{
             describe::{
-                DescribeConfiguration, DescribeMetadataConfiguration,
+                DescribeConfiguration, DescribeMetadataConfiguration, DescribePackageConfiguration,
                 DescribePrereleaseConfiguration, DescribeTriggerConfiguration,
+                DescribeVersionConfiguration,
             },
             tag::TagConfiguration,
         },
+        error::tag_format_invariant_error::TagFormatInvariantError,
+        tag_format::TagFormat,
         type_aliases::AnyError,
         usecases::{
             create_tag::CreateTagUseCase, describe_new_version::CalculateNewVersionUseCase,
             usecase::UseCase,
         },
+        version_description::{Change, VersionDescription},
     },
 };
 
@@ -90,33 +98,120 @@ impl<'a, 'b: 'a, 'c: 'a, 'd: 'a, 'e: 'a, 'f: 'a> DescribeController<'a> {
             &commit_metadata_repository,
             &version_repository,
         );
-        let (new_version, old_version) = describe_usecase.execute()?;
-        if self.options.diff() {
-            self.output_manager.output(&format!(
-                "Previous version: {}",
-                old_version.map_or_else(|| String::from("None"), |it| it.to_string())
-            ));
+        let description = describe_usecase.execute()?;
+        match self.options.output().format() {
+            OutputFormat::Text => {
+                if self.options.output().explain() {
+                    self.explain(&description);
+                }
+                if self.options.output().diff() {
+                    self.output_manager.output(&format!(
+                        "Previous version: {}",
+                        description
+                            .old_version()
+                            .map_or_else(|| String::from("None"), |it| it.to_string())
+                    ));
+                }
+                self.output_manager
+                    .output(&description.new_version().to_string());
+            }
+            OutputFormat::Json => {
+                let json = if self.options.output().explain() {
+                    serde_json::to_string_pretty(&ExplainedVersionDescription::new(
+                        &description,
+                        self.trigger_str(Change::Major)
+                            .expect("major has a trigger"),
+                        self.trigger_str(Change::Minor)
+                            .expect("minor has a trigger"),
+                        self.trigger_str(Change::Patch)
+                            .expect("patch has a trigger"),
+                    ))?
+                } else {
+                    serde_json::to_string_pretty(&description)?
+                };
+                self.output_manager.output(&json);
+            }
         }
-        self.output_manager.output(&new_version.to_string());
         if self.options.tag().enabled() {
             let tag_configuration = TagConfiguration::new(
-                new_version,
+                description.new_version().clone(),
+                self.tag_format()?,
                 self.options.tag().message().map(|it| it.to_owned()),
                 self.options.tag().sign_enabled(),
             )?;
             let tag_write_repository = TagEgressRepositoryImpl::new(self.tag_write_manager);
             let tag_usecase = CreateTagUseCase::new(tag_configuration, &tag_write_repository);
             tag_usecase.execute()?;
-            self.output_manager.output("Tag created successfully");
+            if self.options.output().format() == OutputFormat::Text {
+                self.output_manager.output("Tag created successfully");
+            }
         }
         Ok(())
     }
 
+    /**
+     * Prints every commit considered, with the change it maps to and the trigger it matched.
+     */
+    fn explain(&self, description: &VersionDescription) {
+        self.output_manager.output(&format!(
+            "Commits since {}:",
+            description
+                .old_version()
+                .map_or_else(|| String::from("the first commit"), |it| it.to_string())
+        ));
+        if description.commits().is_empty() {
+            self.output_manager.output("  (none)");
+        }
+        for (commit, change) in description.commits() {
+            self.output_manager
+                .output(&match self.trigger_str(*change) {
+                    Some(trigger) => format!(
+                        "  {:<5}  {}  [{} trigger: {}]",
+                        change, commit, change, trigger
+                    ),
+                    None => format!("  {:<5}  {}", change, commit),
+                });
+        }
+        self.output_manager.output(&format!(
+            "Bump: {}{}",
+            description.change(),
+            if description.old_version().is_none() {
+                " (first release)"
+            } else {
+                ""
+            }
+        ));
+    }
+
+    fn trigger_str(&self, change: Change) -> Option<&str> {
+        match change {
+            Change::Major => Some(
+                self.options
+                    .triggers()
+                    .major()
+                    .unwrap_or(Self::DEFAULT_MAJOR_TRIGGER_STR),
+            ),
+            Change::Minor => Some(
+                self.options
+                    .triggers()
+                    .minor()
+                    .unwrap_or(Self::DEFAULT_MINOR_TRIGGER_STR),
+            ),
+            Change::Patch => Some(
+                self.options
+                    .triggers()
+                    .patch()
+                    .unwrap_or(Self::DEFAULT_PATCH_TRIGGER_STR),
+            ),
+            Change::None => None,
+        }
+    }
+
     const DEFAULT_MAJOR_TRIGGER_STR: &'static str = "breaking";
     const DEFAULT_MINOR_TRIGGER_STR: &'static str = "type IN [ feat ]";
     const DEFAULT_PATCH_TRIGGER_STR: &'static str = "type IN [ fix ]";
 
-    fn generate_describe_configuration(&self) -> Result<DescribeConfiguration, AnyError> {
+    fn generate_describe_configuration(&self) -> Result<DescribeConfiguration<'_>, AnyError> {
         let prerelease_configuration = DescribePrereleaseConfiguration::new(
             self.options.prerelease().enabled(),
             Box::new(|it| {
@@ -126,22 +221,20 @@ impl<'a, 'b: 'a, 'c: 'a, 'd: 'a, 'e: 'a, 'f: 'a> DescribeController<'a> {
                     .replace(PRERELEASE_NUM_PLACEHOLDER, &it.to_string())
             }),
             Box::new(|it| {
-                let regex = Regex::new(
-                    &self
-                        .options
-                        .prerelease()
-                        .old_pattern()
-                        .replace(PRERELEASE_NUM_PLACEHOLDER, "(\\d+)"),
-                )
+                let regex = Regex::new(&format!(
+                    "^{}$",
+                    regex::escape(self.options.prerelease().old_pattern())
+                        .replace(PRERELEASE_NUM_PLACEHOLDER, "(\\d+)")
+                ))
                 .unwrap();
-                regex
-                    .captures(it)
-                    .expect("regex should match")
-                    .get(1)
-                    .expect("group 1 must be present to match")
-                    .as_str()
-                    .parse()
-                    .unwrap()
+                regex.captures(it).map(|captures| {
+                    captures
+                        .get(1)
+                        .expect("group 1 must be present to match")
+                        .as_str()
+                        .parse()
+                        .unwrap()
+                })
             }),
             self.options.prerelease().pattern() != self.options.prerelease().old_pattern(),
         );
@@ -149,35 +242,59 @@ impl<'a, 'b: 'a, 'c: 'a, 'd: 'a, 'e: 'a, 'f: 'a> DescribeController<'a> {
             DescribeMetadataConfiguration::new(self.options.metadata().specs().to_vec());
         let trigger_configuration = DescribeTriggerConfiguration::new(
             Trigger::from_str(
-                self.options
-                    .triggers()
-                    .major()
-                    .unwrap_or(Self::DEFAULT_MAJOR_TRIGGER_STR),
+                self.trigger_str(Change::Major)
+                    .expect("major has a trigger"),
             )?,
             Trigger::from_str(
-                self.options
-                    .triggers()
-                    .minor()
-                    .unwrap_or(Self::DEFAULT_MINOR_TRIGGER_STR),
+                self.trigger_str(Change::Minor)
+                    .expect("minor has a trigger"),
             )?,
             Trigger::from_str(
-                self.options
-                    .triggers()
-                    .patch()
-                    .unwrap_or(Self::DEFAULT_PATCH_TRIGGER_STR),
+                self.trigger_str(Change::Patch)
+                    .expect("patch has a trigger"),
             )?,
         );
         Ok(DescribeConfiguration::new(
             prerelease_configuration,
             metadata_configuration,
             trigger_configuration,
+            self.tag_format()?,
+            self.options.package().map(|it| {
+                DescribePackageConfiguration::new(it.name().to_owned(), it.paths().to_vec())
+            }),
+            self.options.paths().to_vec(),
+            DescribeVersionConfiguration::new(
+                self.options.version().bump().map(|it| match it {
+                    Bump::Major => Change::Major,
+                    Bump::Minor => Change::Minor,
+                    Bump::Patch => Change::Patch,
+                }),
+                self.options
+                    .version()
+                    .set_version()
+                    .map(SemanticVersion::from_str)
+                    .transpose()?,
+                self.options.version().initial_development(),
+                self.options.version().graduate(),
+                self.options.version().promote(),
+            ),
         ))
     }
+
+    /**
+     * The tags of a package are prefixed with its name.
+     */
+    fn tag_format(&self) -> Result<TagFormat, TagFormatInvariantError> {
+        match self.options.package() {
+            Some(package) => TagFormat::for_package(package.name(), self.options.tag().template()),
+            None => TagFormat::new(self.options.tag().template()),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::{cell::RefCell, rc::Rc};
+    use std::cell::RefCell;
 
     use crate::{
         application::{
@@ -188,28 +305,34 @@ mod tests {
                 message_egress_manager::MessageEgressManager, tag_egress_manager::TagEgressManager,
                 version_ingress_manager::VersionIngressManager,
             },
-            options::describe::{
-                DescribeMetadataOptions, DescribeOptions, DescribePrereleaseOptions,
-                DescribeTagOptions, DescribeTriggerOptions,
+            options::{
+                describe::{
+                    DescribeMetadataOptions, DescribeOptions, DescribeOutputOptions,
+                    DescribePackageOptions, DescribePrereleaseOptions, DescribeTagOptions,
+                    DescribeTriggerOptions, DescribeVersionOptions,
+                },
+                output_format::OutputFormat,
             },
         },
-        domain::semantic_version::SemanticVersion,
-        usecase::{metadata_spec::MetadataSpec, type_aliases::AnyError},
+        usecase::{metadata_spec::MetadataSpec, tag_format::TagFormat, type_aliases::AnyError},
     };
 
     struct MockCommitSummaryManager {}
     impl BoundedCommitSummaryIngressManager for MockCommitSummaryManager {
         fn get_commits_from(
             &self,
-            _version: Rc<Option<SemanticVersion>>,
-        ) -> Result<Box<dyn DoubleEndedIterator<Item = String>>, AnyError> {
+            _from: Option<&str>,
+            _to: Option<&str>,
+            _paths: &[String],
+        ) -> Result<Box<dyn DoubleEndedIterator<Item = (String, String)>>, AnyError> {
             Ok(Box::new(
                 vec![
-                    "feat: add a feature".to_string(),
-                    "test(api): add test for API".to_string(),
-                    "refactor: refactor struct".to_string(),
+                    ("a1", "feat: add a feature"),
+                    ("b2", "test(api): add test for API"),
+                    ("c3", "refactor: refactor struct"),
                 ]
-                .into_iter(),
+                .into_iter()
+                .map(|(hash, message)| (hash.to_string(), message.to_string())),
             ))
         }
     }
@@ -227,12 +350,16 @@ mod tests {
 
     struct MockSemanticVersionIngressManager {}
     impl VersionIngressManager for MockSemanticVersionIngressManager {
-        fn last_version(&self) -> Result<Option<String>, AnyError> {
+        fn last_version(&self, _tag_format: &TagFormat) -> Result<Option<String>, AnyError> {
             Ok(None)
         }
-        fn last_stable_version(&self) -> Result<Option<String>, AnyError> {
+        fn last_stable_version(&self, _tag_format: &TagFormat) -> Result<Option<String>, AnyError> {
             Ok(None)
         }
+
+        fn all_versions(&self, _tag_format: &TagFormat) -> Result<Vec<(String, String)>, AnyError> {
+            unreachable!()
+        }
     }
 
     struct MockTagEgressManager {
@@ -283,10 +410,13 @@ mod tests {
         let options = DescribeOptions::new(
             DescribePrereleaseOptions::new(false, "dev%d".to_string(), "dev%d".to_string())
                 .expect("hand-crafted options are correct"),
-            false,
             DescribeMetadataOptions::new(vec![]),
             DescribeTriggerOptions::new(None, None, None),
-            DescribeTagOptions::new(false, None, false),
+            DescribeTagOptions::new(false, None, false, "{version}".to_string()),
+            None,
+            vec![],
+            DescribeVersionOptions::default(),
+            DescribeOutputOptions::new(false, false, OutputFormat::Text),
         );
         let commit_summary_manager = MockCommitSummaryManager {};
         let commit_metadata_ingress_manager = MockCommitMetadataManager {};
@@ -311,10 +441,13 @@ mod tests {
         let options = DescribeOptions::new(
             DescribePrereleaseOptions::new(false, "dev%d".to_string(), "dev%d".to_string())
                 .expect("hand-crafted options are correct"),
-            true,
             DescribeMetadataOptions::new(vec![]),
             DescribeTriggerOptions::new(None, None, None),
-            DescribeTagOptions::new(false, None, false),
+            DescribeTagOptions::new(false, None, false, "{version}".to_string()),
+            None,
+            vec![],
+            DescribeVersionOptions::default(),
+            DescribeOutputOptions::new(true, false, OutputFormat::Text),
         );
         let commit_summary_manager = MockCommitSummaryManager {};
         let commit_metadata_ingress_manager = MockCommitMetadataManager {};
@@ -342,10 +475,13 @@ mod tests {
         let options = DescribeOptions::new(
             DescribePrereleaseOptions::new(false, "dev%d".to_string(), "dev%d".to_string())
                 .expect("hand-crafted options are correct"),
-            false,
             DescribeMetadataOptions::new(vec![]),
             DescribeTriggerOptions::new(None, None, None),
-            DescribeTagOptions::new(true, None, false),
+            DescribeTagOptions::new(true, None, false, "{version}".to_string()),
+            None,
+            vec![],
+            DescribeVersionOptions::default(),
+            DescribeOutputOptions::new(false, false, OutputFormat::Text),
         );
         let commit_summary_manager = MockCommitSummaryManager {};
         let commit_metadata_ingress_manager = MockCommitMetadataManager {};
@@ -364,4 +500,284 @@ mod tests {
         assert!(matches!(result, ControllerExitCode::Ok));
         assert_eq!(tag_egress_manager.label.borrow().as_ref(), "0.1.0");
     }
+
+    #[test]
+    fn tag_enabled_with_template() {
+        let options = DescribeOptions::new(
+            DescribePrereleaseOptions::new(false, "dev%d".to_string(), "dev%d".to_string())
+                .expect("hand-crafted options are correct"),
+            DescribeMetadataOptions::new(vec![]),
+            DescribeTriggerOptions::new(None, None, None),
+            DescribeTagOptions::new(true, None, false, "v{version}".to_string()),
+            None,
+            vec![],
+            DescribeVersionOptions::default(),
+            DescribeOutputOptions::new(false, false, OutputFormat::Text),
+        );
+        let commit_summary_manager = MockCommitSummaryManager {};
+        let commit_metadata_ingress_manager = MockCommitMetadataManager {};
+        let version_ingress_manager = MockSemanticVersionIngressManager {};
+        let tag_egress_manager = MockTagEgressManager::new();
+        let output_manager = MockOutputManager::new();
+        let controller = DescribeController::new(
+            options,
+            &commit_summary_manager,
+            &commit_metadata_ingress_manager,
+            &version_ingress_manager,
+            &tag_egress_manager,
+            &output_manager,
+        );
+        let result = controller.describe();
+        assert!(matches!(result, ControllerExitCode::Ok));
+        assert_eq!(tag_egress_manager.label.borrow().as_ref(), "v0.1.0");
+    }
+
+    #[test]
+    fn package_tag_with_template() {
+        let options = DescribeOptions::new(
+            DescribePrereleaseOptions::new(false, "dev%d".to_string(), "dev%d".to_string())
+                .expect("hand-crafted options are correct"),
+            DescribeMetadataOptions::new(vec![]),
+            DescribeTriggerOptions::new(None, None, None),
+            DescribeTagOptions::new(true, None, false, "release-{version}".to_string()),
+            Some(DescribePackageOptions::new(
+                "api".to_string(),
+                vec!["api".to_string()],
+            )),
+            vec![],
+            DescribeVersionOptions::default(),
+            DescribeOutputOptions::new(false, false, OutputFormat::Text),
+        );
+        let commit_summary_manager = MockCommitSummaryManager {};
+        let commit_metadata_ingress_manager = MockCommitMetadataManager {};
+        let version_ingress_manager = MockSemanticVersionIngressManager {};
+        let tag_egress_manager = MockTagEgressManager::new();
+        let output_manager = MockOutputManager::new();
+        let controller = DescribeController::new(
+            options,
+            &commit_summary_manager,
+            &commit_metadata_ingress_manager,
+            &version_ingress_manager,
+            &tag_egress_manager,
+            &output_manager,
+        );
+        let result = controller.describe();
+        assert!(matches!(result, ControllerExitCode::Ok));
+        assert_eq!(
+            tag_egress_manager.label.borrow().as_ref(),
+            "api/release-0.1.0"
+        );
+    }
+
+    #[test]
+    fn set_version_is_tagged() {
+        let options = DescribeOptions::new(
+            DescribePrereleaseOptions::new(false, "dev%d".to_string(), "dev%d".to_string())
+                .expect("hand-crafted options are correct"),
+            DescribeMetadataOptions::new(vec![]),
+            DescribeTriggerOptions::new(None, None, None),
+            DescribeTagOptions::new(true, None, false, "v{version}".to_string()),
+            None,
+            vec![],
+            DescribeVersionOptions::new(None, Some("2.0.0-rc.1".to_string()), false, false, false),
+            DescribeOutputOptions::new(false, false, OutputFormat::Text),
+        );
+        let commit_summary_manager = MockCommitSummaryManager {};
+        let commit_metadata_ingress_manager = MockCommitMetadataManager {};
+        let version_ingress_manager = MockSemanticVersionIngressManager {};
+        let tag_egress_manager = MockTagEgressManager::new();
+        let output_manager = MockOutputManager::new();
+        let controller = DescribeController::new(
+            options,
+            &commit_summary_manager,
+            &commit_metadata_ingress_manager,
+            &version_ingress_manager,
+            &tag_egress_manager,
+            &output_manager,
+        );
+        let result = controller.describe();
+        assert!(matches!(result, ControllerExitCode::Ok));
+        assert_eq!(tag_egress_manager.label.borrow().as_ref(), "v2.0.0-rc.1");
+    }
+
+    #[test]
+    fn invalid_set_version_is_an_error() {
+        let options = DescribeOptions::new(
+            DescribePrereleaseOptions::new(false, "dev%d".to_string(), "dev%d".to_string())
+                .expect("hand-crafted options are correct"),
+            DescribeMetadataOptions::new(vec![]),
+            DescribeTriggerOptions::new(None, None, None),
+            DescribeTagOptions::new(true, None, false, "{version}".to_string()),
+            None,
+            vec![],
+            DescribeVersionOptions::new(None, Some("2.0".to_string()), false, false, false),
+            DescribeOutputOptions::new(false, false, OutputFormat::Text),
+        );
+        let commit_summary_manager = MockCommitSummaryManager {};
+        let commit_metadata_ingress_manager = MockCommitMetadataManager {};
+        let version_ingress_manager = MockSemanticVersionIngressManager {};
+        let tag_egress_manager = MockTagEgressManager::new();
+        let output_manager = MockOutputManager::new();
+        let controller = DescribeController::new(
+            options,
+            &commit_summary_manager,
+            &commit_metadata_ingress_manager,
+            &version_ingress_manager,
+            &tag_egress_manager,
+            &output_manager,
+        );
+        let result = controller.describe();
+        assert!(matches!(result, ControllerExitCode::Error(_)));
+        assert_eq!(tag_egress_manager.label.borrow().as_ref(), "");
+    }
+
+    #[test]
+    fn template_without_placeholder_is_an_error() {
+        let options = DescribeOptions::new(
+            DescribePrereleaseOptions::new(false, "dev%d".to_string(), "dev%d".to_string())
+                .expect("hand-crafted options are correct"),
+            DescribeMetadataOptions::new(vec![]),
+            DescribeTriggerOptions::new(None, None, None),
+            DescribeTagOptions::new(false, None, false, "v".to_string()),
+            None,
+            vec![],
+            DescribeVersionOptions::default(),
+            DescribeOutputOptions::new(false, false, OutputFormat::Text),
+        );
+        let commit_summary_manager = MockCommitSummaryManager {};
+        let commit_metadata_ingress_manager = MockCommitMetadataManager {};
+        let version_ingress_manager = MockSemanticVersionIngressManager {};
+        let tag_egress_manager = MockTagEgressManager::new();
+        let output_manager = MockOutputManager::new();
+        let controller = DescribeController::new(
+            options,
+            &commit_summary_manager,
+            &commit_metadata_ingress_manager,
+            &version_ingress_manager,
+            &tag_egress_manager,
+            &output_manager,
+        );
+        let result = controller.describe();
+        assert!(matches!(result, ControllerExitCode::Error(_)));
+    }
+
+    #[test]
+    fn json_output() {
+        let options = DescribeOptions::new(
+            DescribePrereleaseOptions::new(false, "dev%d".to_string(), "dev%d".to_string())
+                .expect("hand-crafted options are correct"),
+            DescribeMetadataOptions::new(vec![]),
+            DescribeTriggerOptions::new(None, None, None),
+            DescribeTagOptions::new(true, None, false, "{version}".to_string()),
+            None,
+            vec![],
+            DescribeVersionOptions::default(),
+            DescribeOutputOptions::new(true, false, OutputFormat::Json),
+        );
+        let commit_summary_manager = MockCommitSummaryManager {};
+        let commit_metadata_ingress_manager = MockCommitMetadataManager {};
+        let version_ingress_manager = MockSemanticVersionIngressManager {};
+        let tag_egress_manager = MockTagEgressManager::new();
+        let output_manager = MockOutputManager::new();
+        let controller = DescribeController::new(
+            options,
+            &commit_summary_manager,
+            &commit_metadata_ingress_manager,
+            &version_ingress_manager,
+            &tag_egress_manager,
+            &output_manager,
+        );
+        let result = controller.describe();
+        assert!(matches!(result, ControllerExitCode::Ok));
+        let output = output_manager.output_buffer.borrow();
+        assert_eq!(output.len(), 1);
+        let json: serde_json::Value =
+            serde_json::from_str(&output[0]).expect("The output is valid JSON");
+        assert_eq!(json["version"], "0.1.0");
+        assert_eq!(json["previous_version"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn explain_json_output() {
+        let options = DescribeOptions::new(
+            DescribePrereleaseOptions::new(false, "dev%d".to_string(), "dev%d".to_string())
+                .expect("hand-crafted options are correct"),
+            DescribeMetadataOptions::new(vec![]),
+            DescribeTriggerOptions::new(None, None, None),
+            DescribeTagOptions::new(false, None, false, "{version}".to_string()),
+            None,
+            vec![],
+            DescribeVersionOptions::default(),
+            DescribeOutputOptions::new(false, true, OutputFormat::Json),
+        );
+        let commit_summary_manager = MockCommitSummaryManager {};
+        let commit_metadata_ingress_manager = MockCommitMetadataManager {};
+        let version_ingress_manager = MockSemanticVersionIngressManager {};
+        let tag_egress_manager = MockTagEgressManager::new();
+        let output_manager = MockOutputManager::new();
+        let controller = DescribeController::new(
+            options,
+            &commit_summary_manager,
+            &commit_metadata_ingress_manager,
+            &version_ingress_manager,
+            &tag_egress_manager,
+            &output_manager,
+        );
+        let result = controller.describe();
+        assert!(matches!(result, ControllerExitCode::Ok));
+        let output = output_manager.output_buffer.borrow();
+        assert_eq!(output.len(), 1);
+        let json: serde_json::Value =
+            serde_json::from_str(&output[0]).expect("The output is valid JSON");
+        assert_eq!(json["commits"]["minor"][0], "feat: add a feature");
+        assert_eq!(
+            json["triggers"],
+            serde_json::json!({
+                "major": "breaking",
+                "minor": "type IN [ feat ]",
+                "patch": "type IN [ fix ]",
+            })
+        );
+    }
+
+    #[test]
+    fn explain_enabled() {
+        let options = DescribeOptions::new(
+            DescribePrereleaseOptions::new(false, "dev%d".to_string(), "dev%d".to_string())
+                .expect("hand-crafted options are correct"),
+            DescribeMetadataOptions::new(vec![]),
+            DescribeTriggerOptions::new(None, None, None),
+            DescribeTagOptions::new(false, None, false, "{version}".to_string()),
+            None,
+            vec![],
+            DescribeVersionOptions::default(),
+            DescribeOutputOptions::new(false, true, OutputFormat::Text),
+        );
+        let commit_summary_manager = MockCommitSummaryManager {};
+        let commit_metadata_ingress_manager = MockCommitMetadataManager {};
+        let version_ingress_manager = MockSemanticVersionIngressManager {};
+        let tag_egress_manager = MockTagEgressManager::new();
+        let output_manager = MockOutputManager::new();
+        let controller = DescribeController::new(
+            options,
+            &commit_summary_manager,
+            &commit_metadata_ingress_manager,
+            &version_ingress_manager,
+            &tag_egress_manager,
+            &output_manager,
+        );
+        let result = controller.describe();
+        assert!(matches!(result, ControllerExitCode::Ok));
+        assert_eq!(
+            output_manager.output_buffer.borrow().as_ref(),
+            [
+                "Commits since the first commit:",
+                "  minor  feat: add a feature  [minor trigger: type IN [ feat ]]",
+                "  none   test(api): add test for API",
+                "  none   refactor: refactor struct",
+                "Bump: minor (first release)",
+                "0.1.0",
+            ]
+        );
+    }
 }
@@ -7,6 +7,7 @@ use crate::{
         options::init::InitOptions,
         repository_impl::conventional_commit_egress_repository_impl::ConventionalCommitEgressRepositoryImpl,
     },
+    domain::commit_policy::CommitPolicy,
     usecase::{
         configuration::commit::{AllowEmptyFlag, CommitConfiguration},
         usecases::{create_conventional_commit::CreateConventionalCommitUseCase, usecase::UseCase},
@@ -50,7 +51,9 @@ impl<'a, 'b: 'a, 'c: 'a, 'd: 'a> InitController<'a> {
                 false,
                 "initialize empty repository".to_string(),
                 None,
+                vec![],
                 AllowEmptyFlag::Enabled,
+                CommitPolicy::default(),
             )
             .expect("Init commit configuration is hand-made");
             let commit_repository =
@@ -0,0 +1,297 @@
+use crate::{
+    application::{
+        manager::{
+            comment_char_ingress_manager::CommentCharIngressManager,
+            commit_message_ingress_manager::CommitMessageIngressManager,
+            commit_range_ingress_manager::CommitRangeIngressManager,
+            message_egress_manager::MessageEgressManager,
+        },
+        options::lint::LintOptions,
+        repository_impl::{
+            commit_message_ingress_repository_impl::CommitMessageIngressRepositoryImpl,
+            commit_range_ingress_repository_impl::CommitRangeIngressRepositoryImpl,
+        },
+    },
+    domain::commit_policy::CommitPolicy,
+    usecase::{
+        configuration::lint::{LintConfiguration, LintMessageConfiguration},
+        usecases::{
+            lint_commits::LintCommitsUseCase, lint_message::LintMessageUseCase, usecase::UseCase,
+        },
+    },
+};
+
+use super::exit_code::ControllerExitCode;
+
+pub struct LintController<'a> {
+    options: LintOptions,
+    commit_manager: &'a dyn CommitRangeIngressManager,
+    message_manager: &'a dyn CommitMessageIngressManager,
+    comment_char_manager: &'a dyn CommentCharIngressManager,
+    output_manager: &'a dyn MessageEgressManager,
+}
+
+impl<'a, 'b: 'a, 'c: 'a, 'd: 'a, 'e: 'a> LintController<'a> {
+    pub fn new(
+        options: LintOptions,
+        commit_manager: &'b dyn CommitRangeIngressManager,
+        message_manager: &'c dyn CommitMessageIngressManager,
+        comment_char_manager: &'d dyn CommentCharIngressManager,
+        output_manager: &'e dyn MessageEgressManager,
+    ) -> Self {
+        LintController {
+            options,
+            commit_manager,
+            message_manager,
+            comment_char_manager,
+            output_manager,
+        }
+    }
+
+    pub fn lint(&self) -> ControllerExitCode {
+        match self.options.message_file() {
+            Some(path) => self.lint_message(path),
+            None => self.lint_range(),
+        }
+    }
+
+    fn policy(&self) -> CommitPolicy {
+        CommitPolicy::new(
+            self.options.policy().allowed_types().cloned(),
+            self.options.policy().allowed_scopes().cloned(),
+        )
+    }
+
+    fn lint_message(&self, path: &str) -> ControllerExitCode {
+        let policy = if self.options.strict() {
+            self.policy()
+        } else {
+            CommitPolicy::new(None, None)
+        };
+        let configuration = LintMessageConfiguration::new(path.to_owned(), policy);
+        let message_repository = CommitMessageIngressRepositoryImpl::new(
+            self.message_manager,
+            self.comment_char_manager,
+        );
+        let usecase = LintMessageUseCase::new(configuration, &message_repository);
+        match usecase.execute() {
+            Ok(None) => ControllerExitCode::Ok,
+            Ok(Some(reason)) => {
+                self.output_manager
+                    .error(&format!("Invalid commit message: {}", reason));
+                ControllerExitCode::Error(1)
+            }
+            Err(e) => {
+                self.output_manager.error(&e.to_string());
+                ControllerExitCode::Error(1)
+            }
+        }
+    }
+
+    fn lint_range(&self) -> ControllerExitCode {
+        let configuration =
+            LintConfiguration::new(self.options.range().map(|it| it.to_owned()), self.policy());
+        let commit_repository = CommitRangeIngressRepositoryImpl::new(self.commit_manager);
+        let usecase = LintCommitsUseCase::new(configuration, &commit_repository);
+        match usecase.execute() {
+            Ok(issues) if issues.is_empty() => ControllerExitCode::Ok,
+            Ok(issues) => {
+                issues.iter().for_each(|it| {
+                    self.output_manager.error(&format!(
+                        "{} {}\n    {}",
+                        it.hash(),
+                        it.header(),
+                        it.reason()
+                    ))
+                });
+                self.output_manager
+                    .error(&format!("Found {} invalid commit(s)", issues.len()));
+                ControllerExitCode::Error(1)
+            }
+            Err(e) => {
+                self.output_manager.error(&e.to_string());
+                ControllerExitCode::Error(1)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use crate::{
+        application::{
+            controller::{exit_code::ControllerExitCode, lint::LintController},
+            manager::{
+                comment_char_ingress_manager::CommentCharIngressManager,
+                commit_message_ingress_manager::CommitMessageIngressManager,
+                commit_range_ingress_manager::CommitRangeIngressManager,
+                message_egress_manager::MessageEgressManager,
+            },
+            options::{commit::CommitPolicyOptions, lint::LintOptions},
+        },
+        usecase::type_aliases::AnyError,
+    };
+
+    struct MockCommitRangeIngressManager {
+        commits: Vec<(&'static str, &'static str)>,
+    }
+
+    impl CommitRangeIngressManager for MockCommitRangeIngressManager {
+        fn get_commits_in_range(
+            &self,
+            _range: Option<&str>,
+        ) -> Result<Box<dyn Iterator<Item = (String, String)>>, AnyError> {
+            Ok(Box::new(
+                self.commits
+                    .clone()
+                    .into_iter()
+                    .map(|(h, s)| (h.to_string(), s.to_string())),
+            ))
+        }
+    }
+
+    struct MockCommitMessageIngressManager {}
+
+    impl CommitMessageIngressManager for MockCommitMessageIngressManager {
+        fn read_message(&self, path: &str) -> Result<String, AnyError> {
+            Ok(path.to_string())
+        }
+    }
+
+    struct MockCommentCharIngressManager {}
+
+    impl CommentCharIngressManager for MockCommentCharIngressManager {
+        fn comment_char(&self) -> Result<String, AnyError> {
+            Ok("#".to_string())
+        }
+    }
+
+    struct MockOutputManager {
+        errors: RefCell<Vec<String>>,
+    }
+
+    impl MessageEgressManager for MockOutputManager {
+        fn output(&self, _message: &str) {}
+        fn error(&self, error: &str) {
+            self.errors.borrow_mut().push(error.to_owned());
+        }
+    }
+
+    #[test]
+    fn lint_ok() {
+        let commit_manager = MockCommitRangeIngressManager {
+            commits: vec![("aaaaaaa", "feat: test"), ("bbbbbbb", "fix(api): test")],
+        };
+        let output_manager = MockOutputManager {
+            errors: RefCell::new(Vec::new()),
+        };
+        let options = LintOptions::new(None, None, false, CommitPolicyOptions::new(None, None));
+        let controller = LintController::new(
+            options,
+            &commit_manager,
+            &MockCommitMessageIngressManager {},
+            &MockCommentCharIngressManager {},
+            &output_manager,
+        );
+        assert!(matches!(controller.lint(), ControllerExitCode::Ok));
+        assert!(output_manager.errors.borrow().is_empty());
+    }
+
+    #[test]
+    fn lint_reports_invalid_commits() {
+        let commit_manager = MockCommitRangeIngressManager {
+            commits: vec![("aaaaaaa", "feat: test"), ("bbbbbbb", "wip")],
+        };
+        let output_manager = MockOutputManager {
+            errors: RefCell::new(Vec::new()),
+        };
+        let options = LintOptions::new(
+            Some("main..HEAD".to_string()),
+            None,
+            false,
+            CommitPolicyOptions::new(None, None),
+        );
+        let controller = LintController::new(
+            options,
+            &commit_manager,
+            &MockCommitMessageIngressManager {},
+            &MockCommentCharIngressManager {},
+            &output_manager,
+        );
+        assert!(matches!(controller.lint(), ControllerExitCode::Error(1)));
+        assert_eq!(
+            output_manager.errors.borrow()[0],
+            "bbbbbbb wip\n    not a conventional commit"
+        );
+    }
+
+    #[test]
+    fn lint_message_file() {
+        let commit_manager = MockCommitRangeIngressManager { commits: vec![] };
+        let output_manager = MockOutputManager {
+            errors: RefCell::new(Vec::new()),
+        };
+        let valid_options = LintOptions::new(
+            None,
+            Some("fix: test\n\nBody".to_string()),
+            false,
+            CommitPolicyOptions::new(None, None),
+        );
+        let controller = LintController::new(
+            valid_options,
+            &commit_manager,
+            &MockCommitMessageIngressManager {},
+            &MockCommentCharIngressManager {},
+            &output_manager,
+        );
+        assert!(matches!(controller.lint(), ControllerExitCode::Ok));
+        let invalid_options = LintOptions::new(
+            None,
+            Some("Fix: test".to_string()),
+            false,
+            CommitPolicyOptions::new(None, None),
+        );
+        let controller = LintController::new(
+            invalid_options,
+            &commit_manager,
+            &MockCommitMessageIngressManager {},
+            &MockCommentCharIngressManager {},
+            &output_manager,
+        );
+        assert!(matches!(controller.lint(), ControllerExitCode::Error(1)));
+    }
+
+    #[test]
+    fn lint_message_file_enforces_declared_types_only_when_strict() {
+        let commit_manager = MockCommitRangeIngressManager { commits: vec![] };
+        let output_manager = MockOutputManager {
+            errors: RefCell::new(Vec::new()),
+        };
+        let options = |strict| {
+            LintOptions::new(
+                None,
+                Some("perf: test".to_string()),
+                strict,
+                CommitPolicyOptions::new(Some(vec!["feat".to_string(), "fix".to_string()]), None),
+            )
+        };
+        let controller = LintController::new(
+            options(false),
+            &commit_manager,
+            &MockCommitMessageIngressManager {},
+            &MockCommentCharIngressManager {},
+            &output_manager,
+        );
+        assert!(matches!(controller.lint(), ControllerExitCode::Ok));
+        let controller = LintController::new(
+            options(true),
+            &commit_manager,
+            &MockCommitMessageIngressManager {},
+            &MockCommentCharIngressManager {},
+            &output_manager,
+        );
+        assert!(matches!(controller.lint(), ControllerExitCode::Error(1)));
+    }
+}
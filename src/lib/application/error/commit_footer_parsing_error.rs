@@ -0,0 +1,65 @@
+use std::{error::Error, fmt::Display};
+
+use crate::domain::error::commit_footer_invariant_error::CommitFooterInvariantError;
+
+#[derive(Debug)]
+pub enum CommitFooterParsingError {
+    Format(MalformedCommitFooterError),
+    Invariant(CommitFooterInvariantError),
+}
+
+impl Display for CommitFooterParsingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "failed to parse commit footer: {}",
+            self.source().expect("source error is always present")
+        )
+    }
+}
+
+impl Error for CommitFooterParsingError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Format(err) => Some(err),
+            Self::Invariant(err) => Some(err),
+        }
+    }
+}
+
+impl From<MalformedCommitFooterError> for CommitFooterParsingError {
+    fn from(value: MalformedCommitFooterError) -> Self {
+        Self::Format(value)
+    }
+}
+
+impl From<CommitFooterInvariantError> for CommitFooterParsingError {
+    fn from(value: CommitFooterInvariantError) -> Self {
+        Self::Invariant(value)
+    }
+}
+
+#[derive(Debug)]
+pub struct MalformedCommitFooterError {
+    footer: String,
+}
+
+impl MalformedCommitFooterError {
+    pub fn new(footer: &str) -> MalformedCommitFooterError {
+        MalformedCommitFooterError {
+            footer: footer.to_owned(),
+        }
+    }
+}
+
+impl Display for MalformedCommitFooterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "'{}' is not in the form 'token: value' or 'token #value'",
+            self.footer
+        )
+    }
+}
+
+impl Error for MalformedCommitFooterError {}
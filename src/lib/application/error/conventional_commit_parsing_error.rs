@@ -0,0 +1,72 @@
+use std::{error::Error, fmt::Display};
+
+use crate::domain::error::{
+    commit_footer_invariant_error::CommitFooterInvariantError,
+    conventional_commit_error::ConventionalCommitError,
+};
+
+#[derive(Debug)]
+pub enum ConventionalCommitParsingError {
+    Header(NonConventionalHeaderError),
+    Commit(ConventionalCommitError),
+    Footer(CommitFooterInvariantError),
+}
+
+impl Display for ConventionalCommitParsingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "failed to parse conventional commit: {}",
+            self.source().expect("source error is always present")
+        )
+    }
+}
+
+impl Error for ConventionalCommitParsingError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Header(err) => Some(err),
+            Self::Commit(err) => Some(err),
+            Self::Footer(err) => Some(err),
+        }
+    }
+}
+
+impl From<NonConventionalHeaderError> for ConventionalCommitParsingError {
+    fn from(value: NonConventionalHeaderError) -> Self {
+        Self::Header(value)
+    }
+}
+
+impl From<ConventionalCommitError> for ConventionalCommitParsingError {
+    fn from(value: ConventionalCommitError) -> Self {
+        Self::Commit(value)
+    }
+}
+
+impl From<CommitFooterInvariantError> for ConventionalCommitParsingError {
+    fn from(value: CommitFooterInvariantError) -> Self {
+        Self::Footer(value)
+    }
+}
+
+#[derive(Debug)]
+pub struct NonConventionalHeaderError {
+    header: String,
+}
+
+impl NonConventionalHeaderError {
+    pub fn new(header: &str) -> NonConventionalHeaderError {
+        NonConventionalHeaderError {
+            header: header.to_owned(),
+        }
+    }
+}
+
+impl Display for NonConventionalHeaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}' is not a conventional commit header", self.header)
+    }
+}
+
+impl Error for NonConventionalHeaderError {}
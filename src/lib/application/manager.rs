@@ -1,5 +1,11 @@
 pub mod bounded_commit_summary_ingress_manager;
+pub mod changelog_file_egress_manager;
+pub mod changelog_file_ingress_manager;
+pub mod changelog_template_ingress_manager;
+pub mod comment_char_ingress_manager;
+pub mod commit_message_ingress_manager;
 pub mod commit_metadata_ingress_manager;
+pub mod commit_range_ingress_manager;
 pub mod conventional_commit_egress_manager;
 pub mod full_commit_summary_history_ingress_manager;
 pub mod git_tree_ingress_manager;
@@ -1,10 +1,15 @@
-use std::rc::Rc;
-
-use crate::{domain::semantic_version::SemanticVersion, usecase::type_aliases::AnyError};
+use crate::usecase::type_aliases::AnyError;
 
 pub trait BoundedCommitSummaryIngressManager {
+    /**
+     * Returns the hash and the full message of each commit reachable from the `to` reference (or
+     * HEAD) but not from the `from` reference, if any. When paths are given, only the commits
+     * which touch them are returned.
+     */
     fn get_commits_from(
         &self,
-        version: Rc<Option<SemanticVersion>>,
-    ) -> Result<Box<dyn DoubleEndedIterator<Item = String>>, AnyError>;
+        from: Option<&str>,
+        to: Option<&str>,
+        paths: &[String],
+    ) -> Result<Box<dyn DoubleEndedIterator<Item = (String, String)>>, AnyError>;
 }
@@ -0,0 +1,5 @@
+use crate::usecase::type_aliases::AnyError;
+
+pub trait ChangelogFileEgressManager {
+    fn write_changelog(&self, path: &str, text: &str) -> Result<(), AnyError>;
+}
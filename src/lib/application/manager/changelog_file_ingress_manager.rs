@@ -0,0 +1,8 @@
+use crate::usecase::type_aliases::AnyError;
+
+pub trait ChangelogFileIngressManager {
+    /**
+     * Returns the content of the changelog file, or None if it does not exist yet.
+     */
+    fn read_changelog(&self, path: &str) -> Result<Option<String>, AnyError>;
+}
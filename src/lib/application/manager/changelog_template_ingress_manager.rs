@@ -0,0 +1,5 @@
+use crate::usecase::type_aliases::AnyError;
+
+pub trait ChangelogTemplateIngressManager {
+    fn read_template(&self, path: &str) -> Result<String, AnyError>;
+}
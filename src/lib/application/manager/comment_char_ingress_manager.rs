@@ -0,0 +1,6 @@
+use crate::usecase::type_aliases::AnyError;
+
+pub trait CommentCharIngressManager {
+    /// The value of `core.commentChar`, which may be `auto`.
+    fn comment_char(&self) -> Result<String, AnyError>;
+}
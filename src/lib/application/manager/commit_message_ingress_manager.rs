@@ -0,0 +1,5 @@
+use crate::usecase::type_aliases::AnyError;
+
+pub trait CommitMessageIngressManager {
+    fn read_message(&self, path: &str) -> Result<String, AnyError>;
+}
@@ -0,0 +1,12 @@
+use crate::usecase::type_aliases::AnyError;
+
+pub trait CommitRangeIngressManager {
+    /**
+     * Returns the pairs (hash, summary) of the non-merge commits in the given revision range,
+     * or in the history of HEAD if no range is given.
+     */
+    fn get_commits_in_range(
+        &self,
+        range: Option<&str>,
+    ) -> Result<Box<dyn Iterator<Item = (String, String)>>, AnyError>;
+}
@@ -2,4 +2,6 @@ use crate::usecase::type_aliases::AnyError;
 
 pub trait GitInfoIngressManager {
     fn git_dir(&self) -> Result<String, AnyError>;
+    fn work_tree_dir(&self) -> Result<String, AnyError>;
+    fn hooks_dir(&self) -> Result<String, AnyError>;
 }
@@ -1,6 +1,13 @@
-use crate::usecase::type_aliases::AnyError;
+use crate::usecase::{tag_format::TagFormat, type_aliases::AnyError};
 
+/**
+ * The versions are read from the tags with the given format.
+ */
 pub trait VersionIngressManager {
-    fn last_version(&self) -> Result<Option<String>, AnyError>;
-    fn last_stable_version(&self) -> Result<Option<String>, AnyError>;
+    fn last_version(&self, tag_format: &TagFormat) -> Result<Option<String>, AnyError>;
+    fn last_stable_version(&self, tag_format: &TagFormat) -> Result<Option<String>, AnyError>;
+    /**
+     * Returns every version reachable from HEAD, with the date it was tagged.
+     */
+    fn all_versions(&self, tag_format: &TagFormat) -> Result<Vec<(String, String)>, AnyError>;
 }
@@ -3,3 +3,5 @@ pub mod commit;
 pub mod describe;
 pub mod init;
 pub mod license;
+pub mod lint;
+pub mod output_format;
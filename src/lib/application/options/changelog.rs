@@ -3,39 +3,197 @@ use crate::{
     usecase::type_aliases::AnyError,
 };
 
+use super::output_format::OutputFormat;
+
 pub const FORMAT_PLACEHOLDER: &str = "%s";
 
 #[derive(Debug)]
 pub struct ChangelogOptions {
-    generate_from_latest_version: bool,
+    range: ChangelogRangeOptions,
     format: ChangelogFormatOptions,
-    exclude_trigger: Option<String>,
+    content: ChangelogContentOptions,
+    template: Option<String>,
+    version: Option<String>,
+    write: Option<String>,
+    output_format: OutputFormat,
 }
 
 impl ChangelogOptions {
     pub fn new(
-        generate_from_latest_version: bool,
+        range: ChangelogRangeOptions,
         format: ChangelogFormatOptions,
-        exclude_trigger: Option<String>,
+        content: ChangelogContentOptions,
+        template: Option<String>,
+        version: Option<String>,
+        write: Option<String>,
+        output_format: OutputFormat,
     ) -> Self {
         ChangelogOptions {
-            generate_from_latest_version,
+            range,
             format,
-            exclude_trigger,
+            content,
+            template,
+            version,
+            write,
+            output_format,
         }
     }
 
-    pub fn generate_from_latest_version(&self) -> bool {
-        self.generate_from_latest_version
+    pub fn range(&self) -> &ChangelogRangeOptions {
+        &self.range
     }
 
     pub fn format(&self) -> &ChangelogFormatOptions {
         &self.format
     }
 
+    pub fn content(&self) -> &ChangelogContentOptions {
+        &self.content
+    }
+
+    pub fn template(&self) -> Option<&str> {
+        self.template.as_deref()
+    }
+
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    pub fn write(&self) -> Option<&str> {
+        self.write.as_deref()
+    }
+
+    pub fn output_format(&self) -> OutputFormat {
+        self.output_format
+    }
+}
+
+#[derive(Debug)]
+pub struct ChangelogRangeOptions {
+    generate_from_latest_version: bool,
+    all_versions: bool,
+    from: Option<String>,
+    to: Option<String>,
+    paths: Vec<String>,
+    tag_template: String,
+}
+
+impl ChangelogRangeOptions {
+    pub fn new(
+        generate_from_latest_version: bool,
+        all_versions: bool,
+        from: Option<String>,
+        to: Option<String>,
+        paths: Vec<String>,
+        tag_template: String,
+    ) -> Self {
+        ChangelogRangeOptions {
+            generate_from_latest_version,
+            all_versions,
+            from,
+            to,
+            paths,
+            tag_template,
+        }
+    }
+
+    pub fn generate_from_latest_version(&self) -> bool {
+        self.generate_from_latest_version
+    }
+
+    pub fn all_versions(&self) -> bool {
+        self.all_versions
+    }
+
+    pub fn from(&self) -> Option<&str> {
+        self.from.as_deref()
+    }
+
+    pub fn to(&self) -> Option<&str> {
+        self.to.as_deref()
+    }
+
+    pub fn paths(&self) -> &[String] {
+        &self.paths
+    }
+
+    pub fn tag_template(&self) -> &str {
+        &self.tag_template
+    }
+}
+
+/**
+ * How the commits are grouped: by type and then by scope, by scope and then by type, or not at all.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupBy {
+    Type,
+    Scope,
+    None,
+}
+
+#[derive(Debug)]
+pub struct ChangelogContentOptions {
+    exclude_trigger: Option<String>,
+    types: Vec<(String, Option<String>)>,
+    general_scope_last: bool,
+    commit_url: Option<String>,
+    issue_url: Option<String>,
+    breaking_section: bool,
+    group_by: GroupBy,
+}
+
+impl ChangelogContentOptions {
+    pub fn new(
+        exclude_trigger: Option<String>,
+        types: Vec<(String, Option<String>)>,
+        general_scope_last: bool,
+        commit_url: Option<String>,
+        issue_url: Option<String>,
+        breaking_section: bool,
+        group_by: GroupBy,
+    ) -> Self {
+        ChangelogContentOptions {
+            exclude_trigger,
+            types,
+            general_scope_last,
+            commit_url,
+            issue_url,
+            breaking_section,
+            group_by,
+        }
+    }
+
     pub fn exclude_trigger(&self) -> Option<&str> {
         self.exclude_trigger.as_deref()
     }
+
+    /**
+     * The types in the order they are presented with, along with their title, if any.
+     */
+    pub fn types(&self) -> &[(String, Option<String>)] {
+        &self.types
+    }
+
+    pub fn general_scope_last(&self) -> bool {
+        self.general_scope_last
+    }
+
+    pub fn commit_url(&self) -> Option<&str> {
+        self.commit_url.as_deref()
+    }
+
+    pub fn issue_url(&self) -> Option<&str> {
+        self.issue_url.as_deref()
+    }
+
+    pub fn breaking_section(&self) -> bool {
+        self.breaking_section
+    }
+
+    pub fn group_by(&self) -> GroupBy {
+        self.group_by
+    }
 }
 
 #[derive(Debug)]
@@ -46,6 +204,7 @@ pub struct ChangelogFormatOptions {
     list_format: String,
     item_format: String,
     breaking_format: String,
+    hash_format: Option<String>,
 }
 
 impl ChangelogFormatOptions {
@@ -56,6 +215,7 @@ impl ChangelogFormatOptions {
         list_format: String,
         item_format: String,
         breaking_format: String,
+        hash_format: Option<String>,
     ) -> Result<Self, AnyError> {
         Self::ensure_format_has_placeholder(&title_format, "title")?;
         Self::ensure_format_has_placeholder(&type_format, "type")?;
@@ -63,6 +223,9 @@ impl ChangelogFormatOptions {
         Self::ensure_format_has_placeholder(&list_format, "list")?;
         Self::ensure_format_has_placeholder(&item_format, "item")?;
         Self::ensure_format_has_placeholder(&breaking_format, "breaking")?;
+        if let Some(hash_format) = &hash_format {
+            Self::ensure_format_has_placeholder(hash_format, "hash")?;
+        }
         Ok(ChangelogFormatOptions {
             title_format,
             type_format,
@@ -70,6 +233,7 @@ impl ChangelogFormatOptions {
             list_format,
             item_format,
             breaking_format,
+            hash_format,
         })
     }
 
@@ -91,6 +255,9 @@ impl ChangelogFormatOptions {
     pub fn breaking(&self) -> &str {
         &self.breaking_format
     }
+    pub fn hash(&self) -> Option<&str> {
+        self.hash_format.as_deref()
+    }
 
     fn ensure_format_has_placeholder(
         format_string: &str,
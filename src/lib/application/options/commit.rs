@@ -9,29 +9,40 @@ pub struct CommitOptions {
     is_breaking: bool,
     summary: String,
     message: Option<String>,
+    footer_options: CommitFooterOptions,
     quiet: bool,
+    policy_options: CommitPolicyOptions,
 }
 
 impl CommitOptions {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         commit_type: String,
         scope: Option<String>,
         is_breaking: bool,
         summary: String,
         message: Option<String>,
+        footer_options: CommitFooterOptions,
         quiet: bool,
+        policy_options: CommitPolicyOptions,
     ) -> Result<CommitOptions, AnyError> {
         Self::check_non_empty(&commit_type, "commit type")?;
         Self::check_non_empty(&summary, "summary")?;
         Self::check_non_empty_if_present(scope.as_deref(), "scope")?;
         Self::check_non_empty_if_present(message.as_deref(), "message body")?;
+        Self::check_non_empty_if_present(
+            footer_options.breaking_description(),
+            "breaking change description",
+        )?;
         Ok(CommitOptions {
             commit_type,
             scope,
             is_breaking,
             summary,
             message,
+            footer_options,
             quiet,
+            policy_options,
         })
     }
 
@@ -50,9 +61,15 @@ impl CommitOptions {
     pub fn message(&self) -> Option<&str> {
         self.message.as_deref()
     }
+    pub fn footers(&self) -> &CommitFooterOptions {
+        &self.footer_options
+    }
     pub fn quiet(&self) -> bool {
         self.quiet
     }
+    pub fn policy(&self) -> &CommitPolicyOptions {
+        &self.policy_options
+    }
 
     fn check_non_empty(s: &str, what: &str) -> Result<(), CommitOptionsInvariantError> {
         if s.is_empty() {
@@ -76,3 +93,48 @@ impl CommitOptions {
         }
     }
 }
+
+pub struct CommitFooterOptions {
+    footers: Vec<String>,
+    breaking_description: Option<String>,
+}
+
+impl CommitFooterOptions {
+    pub fn new(footers: Vec<String>, breaking_description: Option<String>) -> CommitFooterOptions {
+        CommitFooterOptions {
+            footers,
+            breaking_description,
+        }
+    }
+
+    pub fn footers(&self) -> &[String] {
+        &self.footers
+    }
+    pub fn breaking_description(&self) -> Option<&str> {
+        self.breaking_description.as_deref()
+    }
+}
+
+pub struct CommitPolicyOptions {
+    allowed_types: Option<Vec<String>>,
+    allowed_scopes: Option<Vec<String>>,
+}
+
+impl CommitPolicyOptions {
+    pub fn new(
+        allowed_types: Option<Vec<String>>,
+        allowed_scopes: Option<Vec<String>>,
+    ) -> CommitPolicyOptions {
+        CommitPolicyOptions {
+            allowed_types,
+            allowed_scopes,
+        }
+    }
+
+    pub fn allowed_types(&self) -> Option<&Vec<String>> {
+        self.allowed_types.as_ref()
+    }
+    pub fn allowed_scopes(&self) -> Option<&Vec<String>> {
+        self.allowed_scopes.as_ref()
+    }
+}
@@ -3,40 +3,48 @@ use crate::{
     usecase::{metadata_spec::MetadataSpec, type_aliases::AnyError},
 };
 
+use super::output_format::OutputFormat;
+
 pub const PRERELEASE_NUM_PLACEHOLDER: &str = "%d";
 
 pub struct DescribeOptions {
     prerelease_options: DescribePrereleaseOptions,
-    diff: bool,
     metadata_options: DescribeMetadataOptions,
     trigger_options: DescribeTriggerOptions,
     tag_options: DescribeTagOptions,
+    package_options: Option<DescribePackageOptions>,
+    paths: Vec<String>,
+    version_options: DescribeVersionOptions,
+    output_options: DescribeOutputOptions,
 }
 
 impl DescribeOptions {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         prerelease_options: DescribePrereleaseOptions,
-        diff: bool,
         metadata_options: DescribeMetadataOptions,
         trigger_options: DescribeTriggerOptions,
         tag_options: DescribeTagOptions,
+        package_options: Option<DescribePackageOptions>,
+        paths: Vec<String>,
+        version_options: DescribeVersionOptions,
+        output_options: DescribeOutputOptions,
     ) -> DescribeOptions {
         DescribeOptions {
             prerelease_options,
-            diff,
             metadata_options,
             trigger_options,
             tag_options,
+            package_options,
+            paths,
+            version_options,
+            output_options,
         }
     }
 
     pub fn prerelease(&self) -> &DescribePrereleaseOptions {
         &self.prerelease_options
     }
-
-    pub fn diff(&self) -> bool {
-        self.diff
-    }
     pub fn metadata(&self) -> &DescribeMetadataOptions {
         &self.metadata_options
     }
@@ -46,6 +54,18 @@ impl DescribeOptions {
     pub fn tag(&self) -> &DescribeTagOptions {
         &self.tag_options
     }
+    pub fn package(&self) -> Option<&DescribePackageOptions> {
+        self.package_options.as_ref()
+    }
+    pub fn paths(&self) -> &[String] {
+        &self.paths
+    }
+    pub fn version(&self) -> &DescribeVersionOptions {
+        &self.version_options
+    }
+    pub fn output(&self) -> &DescribeOutputOptions {
+        &self.output_options
+    }
 }
 
 #[derive(Debug)]
@@ -144,14 +164,21 @@ pub struct DescribeTagOptions {
     create_tag: bool,
     tag_message: Option<String>,
     sign_tag: bool,
+    template: String,
 }
 
 impl DescribeTagOptions {
-    pub fn new(create_tag: bool, tag_message: Option<String>, sign_tag: bool) -> Self {
+    pub fn new(
+        create_tag: bool,
+        tag_message: Option<String>,
+        sign_tag: bool,
+        template: String,
+    ) -> Self {
         DescribeTagOptions {
             create_tag,
             tag_message,
             sign_tag,
+            template,
         }
     }
     pub fn enabled(&self) -> bool {
@@ -163,4 +190,108 @@ impl DescribeTagOptions {
     pub fn sign_enabled(&self) -> bool {
         self.sign_tag
     }
+    pub fn template(&self) -> &str {
+        &self.template
+    }
+}
+
+/**
+ * The package of a monorepo to describe: its commits are the ones with its name as scope or
+ * which touch its paths.
+ */
+#[derive(Debug)]
+pub struct DescribePackageOptions {
+    name: String,
+    paths: Vec<String>,
+}
+
+impl DescribePackageOptions {
+    pub fn new(name: String, paths: Vec<String>) -> Self {
+        DescribePackageOptions { name, paths }
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn paths(&self) -> &[String] {
+        &self.paths
+    }
+}
+
+/**
+ * How the new version is decided apart from the commits: the minimum change of the new version,
+ * the new version itself, the policy of the initial development and the graduation from it.
+ */
+#[derive(Debug, Default)]
+pub struct DescribeVersionOptions {
+    bump: Option<Bump>,
+    set_version: Option<String>,
+    initial_development: bool,
+    graduate: bool,
+    promote: bool,
+}
+
+impl DescribeVersionOptions {
+    pub fn new(
+        bump: Option<Bump>,
+        set_version: Option<String>,
+        initial_development: bool,
+        graduate: bool,
+        promote: bool,
+    ) -> Self {
+        DescribeVersionOptions {
+            bump,
+            set_version,
+            initial_development,
+            graduate,
+            promote,
+        }
+    }
+    pub fn bump(&self) -> Option<Bump> {
+        self.bump
+    }
+    pub fn set_version(&self) -> Option<&str> {
+        self.set_version.as_deref()
+    }
+    pub fn initial_development(&self) -> bool {
+        self.initial_development
+    }
+    pub fn graduate(&self) -> bool {
+        self.graduate
+    }
+    pub fn promote(&self) -> bool {
+        self.promote
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bump {
+    Major,
+    Minor,
+    Patch,
+}
+
+#[derive(Debug)]
+pub struct DescribeOutputOptions {
+    diff: bool,
+    explain: bool,
+    output_format: OutputFormat,
+}
+
+impl DescribeOutputOptions {
+    pub fn new(diff: bool, explain: bool, output_format: OutputFormat) -> Self {
+        DescribeOutputOptions {
+            diff,
+            explain,
+            output_format,
+        }
+    }
+    pub fn diff(&self) -> bool {
+        self.diff
+    }
+    pub fn explain(&self) -> bool {
+        self.explain
+    }
+    pub fn format(&self) -> OutputFormat {
+        self.output_format
+    }
 }
@@ -0,0 +1,41 @@
+use super::commit::CommitPolicyOptions;
+
+/**
+ * The declared types and scopes are always enforced on ranges, but on a message file only in
+ * strict mode, as `gb commit` does.
+ */
+pub struct LintOptions {
+    range: Option<String>,
+    message_file: Option<String>,
+    strict: bool,
+    policy_options: CommitPolicyOptions,
+}
+
+impl LintOptions {
+    pub fn new(
+        range: Option<String>,
+        message_file: Option<String>,
+        strict: bool,
+        policy_options: CommitPolicyOptions,
+    ) -> LintOptions {
+        LintOptions {
+            range,
+            message_file,
+            strict,
+            policy_options,
+        }
+    }
+
+    pub fn range(&self) -> Option<&str> {
+        self.range.as_deref()
+    }
+    pub fn message_file(&self) -> Option<&str> {
+        self.message_file.as_deref()
+    }
+    pub fn strict(&self) -> bool {
+        self.strict
+    }
+    pub fn policy(&self) -> &CommitPolicyOptions {
+        &self.policy_options
+    }
+}
@@ -1,4 +1,6 @@
+mod changelog_presenter;
 mod commit_presenter;
 mod semantic_version_presenter;
 mod treegraphline_presenter;
 mod trigger_presenter;
+pub mod version_description_presenter;
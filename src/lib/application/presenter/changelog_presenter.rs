@@ -0,0 +1,272 @@
+use std::fmt::Display;
+
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+
+use crate::usecase::changelog::{
+    Changelog, ChangelogBound, ChangelogCommit, ChangelogGroups, ChangelogIssue, ChangelogScope,
+    ChangelogScopeGroup, ChangelogType, ChangelogTypeGroup,
+};
+
+impl Display for ChangelogBound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChangelogBound::Version(v) => write!(f, "version {}", v),
+            ChangelogBound::Ref(r) => write!(f, "{}", r),
+        }
+    }
+}
+
+/**
+ * The bound as given by the user: versions are written without the tag template.
+ */
+fn bound_name(bound: &ChangelogBound) -> String {
+    match bound {
+        ChangelogBound::Version(v) => v.to_string(),
+        ChangelogBound::Ref(r) => r.clone(),
+    }
+}
+
+impl Serialize for Changelog {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Changelog", 8)?;
+        state.serialize_field("title", &self.title())?;
+        state.serialize_field("version", &self.version().map(|it| it.to_string()))?;
+        state.serialize_field("date", &self.date())?;
+        state.serialize_field(
+            "from_version",
+            &self.from().and_then(|it| match it {
+                ChangelogBound::Version(v) => Some(v.to_string()),
+                ChangelogBound::Ref(_) => None,
+            }),
+        )?;
+        state.serialize_field("from", &self.from().map(bound_name))?;
+        state.serialize_field("to", &self.to().map(bound_name))?;
+        state.serialize_field("breaking_changes", self.breaking_changes())?;
+        match self.groups() {
+            ChangelogGroups::ByType(types) => state.serialize_field("types", types)?,
+            ChangelogGroups::ByScope(scopes) => state.serialize_field("scopes", scopes)?,
+            ChangelogGroups::Ungrouped(commits) => state.serialize_field("commits", commits)?,
+        }
+        state.end()
+    }
+}
+
+impl Serialize for ChangelogType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ChangelogType", 3)?;
+        state.serialize_field("type", self.name())?;
+        state.serialize_field("title", self.title())?;
+        state.serialize_field("scopes", self.scopes())?;
+        state.end()
+    }
+}
+
+impl Serialize for ChangelogScope {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ChangelogScope", 2)?;
+        state.serialize_field("scope", &self.name())?;
+        state.serialize_field("commits", self.commits())?;
+        state.end()
+    }
+}
+
+impl Serialize for ChangelogScopeGroup {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ChangelogScopeGroup", 2)?;
+        state.serialize_field("scope", &self.name())?;
+        state.serialize_field("types", self.types())?;
+        state.end()
+    }
+}
+
+impl Serialize for ChangelogTypeGroup {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ChangelogTypeGroup", 3)?;
+        state.serialize_field("type", self.name())?;
+        state.serialize_field("title", self.title())?;
+        state.serialize_field("commits", self.commits())?;
+        state.end()
+    }
+}
+
+impl Serialize for ChangelogCommit {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ChangelogCommit", 7)?;
+        state.serialize_field("summary", self.summary())?;
+        state.serialize_field("breaking", &self.breaking())?;
+        state.serialize_field("breaking_note", &self.breaking_note())?;
+        state.serialize_field("hash", self.hash())?;
+        state.serialize_field("short_hash", self.short_hash())?;
+        state.serialize_field("url", &self.url())?;
+        state.serialize_field("issues", self.issues())?;
+        state.end()
+    }
+}
+
+impl Serialize for ChangelogIssue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ChangelogIssue", 2)?;
+        state.serialize_field("id", self.id())?;
+        state.serialize_field("url", &self.url())?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        domain::semantic_version::SemanticVersion,
+        usecase::changelog::{
+            Changelog, ChangelogBound, ChangelogCommit, ChangelogGroups, ChangelogIssue,
+            ChangelogScope, ChangelogScopeGroup, ChangelogType, ChangelogTypeGroup,
+        },
+    };
+
+    #[test]
+    fn changelog_json() {
+        let changelog = Changelog::new(
+            None,
+            None,
+            Some(ChangelogBound::Version(
+                SemanticVersion::new(1, 0, 0, None, None).expect("Hand-crafted version is correct"),
+            )),
+            Some(ChangelogBound::Ref("hotfix".to_string())),
+            vec![],
+            ChangelogGroups::ByType(vec![ChangelogType::new(
+                "feat".to_string(),
+                "Features".to_string(),
+                vec![
+                    ChangelogScope::new(
+                        Some("api".to_string()),
+                        vec![ChangelogCommit::new(
+                            "a1b2c3".to_string(),
+                            "add endpoint".to_string(),
+                            true,
+                            Some("use the new endpoint".to_string()),
+                            Some("https://example.com/commit/a1b2c3".to_string()),
+                            vec![ChangelogIssue::new(
+                                "12".to_string(),
+                                Some("https://example.com/issues/12".to_string()),
+                            )],
+                        )],
+                    ),
+                    ChangelogScope::new(
+                        None,
+                        vec![ChangelogCommit::new(
+                            "d4e5f6".to_string(),
+                            "add option".to_string(),
+                            false,
+                            None,
+                            None,
+                            vec![],
+                        )],
+                    ),
+                ],
+            )]),
+        );
+        let json = serde_json::to_value(&changelog).expect("Serialization cannot fail");
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "title": "Changes from version 1.0.0 to hotfix",
+                "version": null,
+                "date": null,
+                "from_version": "1.0.0",
+                "from": "1.0.0",
+                "to": "hotfix",
+                "breaking_changes": [],
+                "types": [
+                    {
+                        "type": "feat",
+                        "title": "Features",
+                        "scopes": [
+                            {
+                                "scope": "api",
+                                "commits": [
+                                    {
+                                        "summary": "add endpoint",
+                                        "breaking": true,
+                                        "breaking_note": "use the new endpoint",
+                                        "hash": "a1b2c3",
+                                        "short_hash": "a1b2c3",
+                                        "url": "https://example.com/commit/a1b2c3",
+                                        "issues": [
+                                            {"id": "12", "url": "https://example.com/issues/12"},
+                                        ],
+                                    },
+                                ],
+                            },
+                            {
+                                "scope": null,
+                                "commits": [
+                                    {
+                                        "summary": "add option",
+                                        "breaking": false,
+                                        "breaking_note": null,
+                                        "hash": "d4e5f6",
+                                        "short_hash": "d4e5f6",
+                                        "url": null,
+                                        "issues": [],
+                                    },
+                                ],
+                            },
+                        ],
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn changelog_grouped_by_scope_json() {
+        let changelog = Changelog::new(
+            None,
+            None,
+            None,
+            None,
+            vec![],
+            ChangelogGroups::ByScope(vec![ChangelogScopeGroup::new(
+                Some("api".to_string()),
+                vec![ChangelogTypeGroup::new(
+                    "feat".to_string(),
+                    "Features".to_string(),
+                    vec![ChangelogCommit::new(
+                        "a1b2c3".to_string(),
+                        "add endpoint".to_string(),
+                        false,
+                        None,
+                        None,
+                        vec![],
+                    )],
+                )],
+            )]),
+        );
+        let json = serde_json::to_value(&changelog).expect("Serialization cannot fail");
+        assert_eq!(
+            json["scopes"],
+            serde_json::json!([
+                {
+                    "scope": "api",
+                    "types": [
+                        {
+                            "type": "feat",
+                            "title": "Features",
+                            "commits": [
+                                {
+                                    "summary": "add endpoint",
+                                    "breaking": false,
+                                    "breaking_note": null,
+                                    "hash": "a1b2c3",
+                                    "short_hash": "a1b2c3",
+                                    "url": null,
+                                    "issues": [],
+                                },
+                            ],
+                        },
+                    ],
+                },
+            ])
+        );
+        assert!(json.get("types").is_none());
+    }
+}
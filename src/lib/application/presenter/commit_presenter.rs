@@ -3,30 +3,108 @@ use std::{fmt::Display, str::FromStr};
 use regex::Regex;
 
 use crate::{
-    application::error::commit_summary_parsing_error::{
-        CommitSummaryParsingError, FreeFormCommitSummaryError,
+    application::error::{
+        commit_footer_parsing_error::{CommitFooterParsingError, MalformedCommitFooterError},
+        commit_summary_parsing_error::{CommitSummaryParsingError, FreeFormCommitSummaryError},
+        conventional_commit_parsing_error::{
+            ConventionalCommitParsingError, NonConventionalHeaderError,
+        },
     },
     domain::{
-        commit_summary::CommitSummary, conventional_commit::ConventionalCommit,
+        commit_footer::CommitFooter, commit_summary::CommitSummary,
+        conventional_commit::ConventionalCommit,
         conventional_commit_summary::ConventionalCommitSummary,
     },
 };
 
 // Groups: 1 = type, 2 = scope with (), 3 = scope, 4 = breaking change, 5 = summary
 const CONVENTIONAL_COMMIT_PATTERN: &str = r"^(\w+)(\(([\w/-]+)\))?(!)?: (.+)$";
+// Groups: 1 = token, 2 = separator, 3 = value
+const FOOTER_PATTERN: &str = r"^(BREAKING CHANGE|[\w-]+)(: | #)(.*)$";
+
+/**
+ * The value of a footer in the form 'token #value' keeps the '#'.
+ */
+fn footer_from_captures(caps: &regex::Captures) -> (String, String) {
+    let value = if &caps[2] == " #" {
+        format!("#{}", &caps[3])
+    } else {
+        caps[3].to_owned()
+    };
+    (caps[1].to_owned(), value)
+}
+
+/**
+ * Splits the lines after the header into the body and the raw footers (token and value).
+ *
+ * The footers are the last paragraph of the message, if its first line is a footer;
+ * the lines which do not start with a token are continuations of the previous footer value.
+ */
+fn split_body_and_footers(lines: &[&str]) -> (Option<String>, Vec<(String, String)>) {
+    let footer_regex =
+        Regex::new(FOOTER_PATTERN).expect("The regex pattern is expected to be correct");
+    let mut paragraphs: Vec<Vec<&str>> = vec![];
+    let mut current: Vec<&str> = vec![];
+    for line in lines {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(current);
+                current = vec![];
+            }
+        } else {
+            current.push(line.trim_end());
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current);
+    }
+    let mut footers: Vec<(String, String)> = vec![];
+    if paragraphs
+        .last()
+        .is_some_and(|it| footer_regex.is_match(it[0]))
+    {
+        for line in paragraphs.pop().expect("Just checked its presence") {
+            match footer_regex.captures(line) {
+                Some(caps) => footers.push(footer_from_captures(&caps)),
+                None => {
+                    let last = footers
+                        .last_mut()
+                        .expect("The first line is always a footer");
+                    last.1 = format!("{}\n{}", last.1, line);
+                }
+            }
+        }
+    }
+    let body = paragraphs
+        .iter()
+        .map(|it| it.join("\n"))
+        .collect::<Vec<String>>()
+        .join("\n\n");
+    (if body.is_empty() { None } else { Some(body) }, footers)
+}
 
 impl FromStr for CommitSummary {
     type Err = CommitSummaryParsingError;
 
+    /**
+     * Parses the first line of the message; the other lines are only used
+     * to detect breaking footers.
+     */
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let regex = Regex::new(CONVENTIONAL_COMMIT_PATTERN)
             .expect("The regex pattern is expected to be correct");
-        let captures = regex.captures(s);
+        let lines: Vec<&str> = s.lines().collect();
+        let header = lines.first().copied().unwrap_or_default();
+        let captures = regex.captures(header);
         match captures {
             Some(caps) => {
                 let commit_type = caps.get(1).expect("Type should be expected").as_str();
                 let scope = caps.get(3).map(|it| it.as_str());
-                let breaking = caps.get(4).is_some();
+                let (_, footers) = split_body_and_footers(&lines[1..]);
+                let breaking = caps.get(4).is_some()
+                    || footers.into_iter().any(|(token, value)| {
+                        CommitFooter::new(token, value).is_ok_and(|it| it.is_breaking())
+                    });
                 let summary = caps.get(5).expect("summary is expected").as_str();
                 Ok(CommitSummary::Conventional(ConventionalCommitSummary::new(
                     commit_type.to_owned(),
@@ -36,19 +114,69 @@ impl FromStr for CommitSummary {
                 )?))
             }
             None => {
-                if s.is_empty() {
+                if header.is_empty() {
                     Err(
                         FreeFormCommitSummaryError::new("Free form commit message cannot be empty")
                             .into(),
                     )
                 } else {
-                    Ok(CommitSummary::FreeForm(s.to_owned()))
+                    Ok(CommitSummary::FreeForm(header.to_owned()))
                 }
             }
         }
     }
 }
 
+impl FromStr for CommitFooter {
+    type Err = CommitFooterParsingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let regex =
+            Regex::new(FOOTER_PATTERN).expect("The regex pattern is expected to be correct");
+        let caps = regex
+            .captures(s.trim())
+            .ok_or_else(|| MalformedCommitFooterError::new(s))?;
+        let (token, value) = footer_from_captures(&caps);
+        Ok(CommitFooter::new(token, value)?)
+    }
+}
+
+impl FromStr for ConventionalCommit {
+    type Err = ConventionalCommitParsingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let regex = Regex::new(CONVENTIONAL_COMMIT_PATTERN)
+            .expect("The regex pattern is expected to be correct");
+        let lines: Vec<&str> = s.lines().collect();
+        let header = lines.first().copied().unwrap_or_default();
+        let caps = regex
+            .captures(header)
+            .ok_or_else(|| NonConventionalHeaderError::new(header))?;
+        let (body, raw_footers) = split_body_and_footers(&lines[1..]);
+        let footers = raw_footers
+            .into_iter()
+            .map(|(token, value)| CommitFooter::new(token, value))
+            .collect::<Result<Vec<CommitFooter>, _>>()?;
+        Ok(ConventionalCommit::new(
+            caps[1].to_owned(),
+            caps.get(3).map(|it| it.as_str().to_owned()),
+            caps.get(4).is_some().into(),
+            caps[5].to_owned(),
+            body,
+            footers,
+        )?)
+    }
+}
+
+impl Display for CommitSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommitSummary::Conventional(c) => write!(f, "{}", c),
+            CommitSummary::FreeForm(s) => write!(f, "{}", s),
+        }
+    }
+}
+
 impl Display for ConventionalCommitSummary {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -65,14 +193,32 @@ impl Display for ConventionalCommitSummary {
     }
 }
 
+impl Display for CommitFooter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.token(), self.value())
+    }
+}
+
 impl Display for ConventionalCommit {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}{}",
+            "{}{}{}",
             self.summary(),
-            self.message()
-                .map_or_else(String::new, |it| format!("\n\n{}", it))
+            self.body()
+                .map_or_else(String::new, |it| format!("\n\n{}", it)),
+            if self.footers().is_empty() {
+                String::new()
+            } else {
+                format!(
+                    "\n\n{}",
+                    self.footers()
+                        .iter()
+                        .map(|it| it.to_string())
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                )
+            }
         )
     }
 }
@@ -81,7 +227,10 @@ impl Display for ConventionalCommit {
 mod tests {
     use std::str::FromStr;
 
+    use indoc::indoc;
+
     use crate::domain::{
+        commit_footer::CommitFooter,
         commit_summary::CommitSummary,
         conventional_commit::ConventionalCommit,
         conventional_commit_summary::{
@@ -233,6 +382,7 @@ mod tests {
             ConventionalCommitSummaryBreakingFlag::Enabled,
             "test format".to_string(),
             None,
+            vec![],
         )
         .expect("Hand-crafted conventional commit summary is correct");
         assert_eq!(&commit.to_string(), "feat(domain)!: test format");
@@ -246,6 +396,7 @@ mod tests {
             ConventionalCommitSummaryBreakingFlag::Enabled,
             "test format".to_string(),
             Some("Message body".to_string()),
+            vec![],
         )
         .expect("Hand-crafted conventional commit summary is correct");
         assert_eq!(
@@ -253,4 +404,108 @@ mod tests {
             "feat(domain)!: test format\n\nMessage body"
         );
     }
+
+    #[test]
+    fn breaking_footer_sets_summary_breaking() {
+        let message = "feat(api): remove endpoint\n\nThe endpoint is gone.\n\nBREAKING CHANGE: use the new endpoint";
+        let c = CommitSummary::from_str(message);
+        assert!(
+            matches!(c, Ok(CommitSummary::Conventional(conv)) if conv.breaking() && conv.summary() == "remove endpoint")
+        );
+    }
+
+    #[test]
+    fn free_form_summary_keeps_only_first_line() {
+        let c = CommitSummary::from_str("Update readme\n\nSome details");
+        assert!(matches!(c, Ok(CommitSummary::FreeForm(s)) if s == "Update readme"));
+    }
+
+    #[test]
+    fn full_conventional_commit_parsing() {
+        let message = indoc! {"
+            fix(cli): handle empty input
+
+            First paragraph
+            of the body.
+
+            Second paragraph.
+
+            Refs #123
+            Reviewed-by: Someone
+            BREAKING CHANGE: the input is now mandatory
+              and must not be empty
+        "};
+        let commit =
+            ConventionalCommit::from_str(message).expect("Hand-crafted commit message is correct");
+        assert!(commit.summary().breaking());
+        assert_eq!(commit.summary().scope(), Some("cli"));
+        assert_eq!(
+            commit.body(),
+            Some("First paragraph\nof the body.\n\nSecond paragraph.")
+        );
+        assert_eq!(
+            commit
+                .footers()
+                .iter()
+                .map(|it| (it.token(), it.value()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Refs", "#123"),
+                ("Reviewed-by", "Someone"),
+                (
+                    "BREAKING CHANGE",
+                    "the input is now mandatory\n  and must not be empty"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn full_conventional_commit_without_footers() {
+        let commit = ConventionalCommit::from_str("docs: update\n\nJust a body.")
+            .expect("Hand-crafted commit message is correct");
+        assert_eq!(commit.body(), Some("Just a body."));
+        assert!(commit.footers().is_empty());
+    }
+
+    #[test]
+    fn full_conventional_commit_non_conventional_header() {
+        assert!(ConventionalCommit::from_str("Update readme").is_err());
+    }
+
+    #[test]
+    fn full_conventional_commit_format_with_footers() {
+        let commit = ConventionalCommit::new(
+            "feat".to_string(),
+            None,
+            ConventionalCommitSummaryBreakingFlag::Disabled,
+            "test format".to_string(),
+            Some("Message body".to_string()),
+            vec![CommitFooter::new("Refs".to_string(), "#1".to_string())
+                .expect("Hand-crafted footer is correct")],
+        )
+        .expect("Hand-crafted conventional commit is correct");
+        assert_eq!(
+            &commit.to_string(),
+            "feat: test format\n\nMessage body\n\nRefs: #1"
+        );
+    }
+
+    #[test]
+    fn footer_parsing() {
+        let colon = CommitFooter::from_str("Co-authored-by: Some One <some@one.org>")
+            .expect("Hand-crafted footer is correct");
+        let hash = CommitFooter::from_str("Closes #42").expect("Hand-crafted footer is correct");
+        assert_eq!(
+            (colon.token(), colon.value()),
+            ("Co-authored-by", "Some One <some@one.org>")
+        );
+        assert_eq!((hash.token(), hash.value()), ("Closes", "#42"));
+    }
+
+    #[test]
+    fn footer_parsing_wrong() {
+        assert!(CommitFooter::from_str("no token here").is_err());
+        assert!(CommitFooter::from_str("Refs: ").is_err());
+    }
 }
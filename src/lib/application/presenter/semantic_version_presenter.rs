@@ -124,6 +124,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_dotted_prerelease_with_metadata() {
+        let s = "1.0.0-rc.1+build.5";
+        let v = SemanticVersion::from_str(s);
+        assert_eq!(
+            v.expect("The version should be parsable correctly"),
+            SemanticVersion::new(
+                1,
+                0,
+                0,
+                Some("rc.1".to_string()),
+                Some("build.5".to_string())
+            )
+            .expect("Hand-crafted version is always correct")
+        );
+    }
+
     #[test]
     fn try_parse_non_semantic_version() {
         let s = "1970-01-01";
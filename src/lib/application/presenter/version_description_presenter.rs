@@ -0,0 +1,179 @@
+use std::fmt::Display;
+
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+
+use crate::usecase::version_description::{Change, VersionDescription};
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Change::Major => "major",
+            Change::Minor => "minor",
+            Change::Patch => "patch",
+            Change::None => "none",
+        })
+    }
+}
+
+impl Serialize for Change {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl Serialize for VersionDescription {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("VersionDescription", 9)?;
+        serialize_description(&mut state, self)?;
+        state.end()
+    }
+}
+
+/**
+ * The description of the new version, with the trigger matched by the commits of each change.
+ */
+pub struct ExplainedVersionDescription<'a> {
+    description: &'a VersionDescription,
+    major_trigger: &'a str,
+    minor_trigger: &'a str,
+    patch_trigger: &'a str,
+}
+
+impl<'a> ExplainedVersionDescription<'a> {
+    pub fn new(
+        description: &'a VersionDescription,
+        major_trigger: &'a str,
+        minor_trigger: &'a str,
+        patch_trigger: &'a str,
+    ) -> Self {
+        ExplainedVersionDescription {
+            description,
+            major_trigger,
+            minor_trigger,
+            patch_trigger,
+        }
+    }
+}
+
+impl Serialize for ExplainedVersionDescription<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ExplainedVersionDescription", 10)?;
+        serialize_description(&mut state, self.description)?;
+        state.serialize_field(
+            "triggers",
+            &serde_json::json!({
+                "major": self.major_trigger,
+                "minor": self.minor_trigger,
+                "patch": self.patch_trigger,
+            }),
+        )?;
+        state.end()
+    }
+}
+
+fn serialize_description<S: SerializeStruct>(
+    state: &mut S,
+    description: &VersionDescription,
+) -> Result<(), S::Error> {
+    state.serialize_field(
+        "previous_version",
+        &description.old_version().map(|it| it.to_string()),
+    )?;
+    state.serialize_field("version", &description.new_version().to_string())?;
+    state.serialize_field("major", &description.new_version().major())?;
+    state.serialize_field("minor", &description.new_version().minor())?;
+    state.serialize_field("patch", &description.new_version().patch())?;
+    state.serialize_field("prerelease", &description.new_version().prerelease())?;
+    state.serialize_field("metadata", &description.new_version().metadata())?;
+    state.serialize_field("bump", &description.change())?;
+    state.serialize_field("commits", &TriggeringCommits(description))
+}
+
+/**
+ * The commits grouped by the change they trigger; those which trigger no change are omitted.
+ */
+struct TriggeringCommits<'a>(&'a VersionDescription);
+
+impl Serialize for TriggeringCommits<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("TriggeringCommits", 3)?;
+        for (key, change) in [
+            ("major", Change::Major),
+            ("minor", Change::Minor),
+            ("patch", Change::Patch),
+        ] {
+            state.serialize_field(
+                key,
+                &self
+                    .0
+                    .commits_with_change(change)
+                    .map(|it| it.to_string())
+                    .collect::<Vec<String>>(),
+            )?;
+        }
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        domain::{
+            commit_summary::CommitSummary,
+            conventional_commit_summary::{
+                ConventionalCommitSummary, ConventionalCommitSummaryBreakingFlag,
+            },
+            semantic_version::SemanticVersion,
+        },
+        usecase::version_description::{Change, VersionDescription},
+    };
+
+    #[test]
+    fn version_description_json() {
+        let description = VersionDescription::new(
+            SemanticVersion::new(1, 1, 0, Some("dev1".to_string()), None)
+                .expect("Hand-crafted version is correct"),
+            Some(
+                SemanticVersion::new(1, 0, 0, None, None).expect("Hand-crafted version is correct"),
+            ),
+            Change::Minor,
+            vec![
+                (
+                    CommitSummary::Conventional(
+                        ConventionalCommitSummary::new(
+                            "feat".to_string(),
+                            Some("api".to_string()),
+                            ConventionalCommitSummaryBreakingFlag::Disabled,
+                            "add endpoint".to_string(),
+                        )
+                        .expect("Hand-crafted commit is correct"),
+                    ),
+                    Change::Minor,
+                ),
+                (
+                    CommitSummary::FreeForm("Update readme".to_string()),
+                    Change::None,
+                ),
+            ],
+        );
+        let json = serde_json::to_value(&description).expect("Serialization cannot fail");
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "previous_version": "1.0.0",
+                "version": "1.1.0-dev1",
+                "major": 1,
+                "minor": 1,
+                "patch": 0,
+                "prerelease": "dev1",
+                "metadata": null,
+                "bump": "minor",
+                "commits": {
+                    "major": [],
+                    "minor": ["feat(api): add endpoint"],
+                    "patch": [],
+                },
+            })
+        );
+    }
+}
@@ -1,8 +1,10 @@
-use std::{rc::Rc, str::FromStr};
+use std::str::FromStr;
 
 use crate::{
     application::manager::bounded_commit_summary_ingress_manager::BoundedCommitSummaryIngressManager,
-    domain::{commit_summary::CommitSummary, semantic_version::SemanticVersion},
+    domain::{
+        commit::Commit, commit_summary::CommitSummary, conventional_commit::ConventionalCommit,
+    },
     usecase::{
         repository::bounded_commit_summary_ingress_repository::BoundedCommitSummaryIngressRepository,
         type_aliases::AnyError,
@@ -26,24 +28,31 @@ impl<'a, 'b: 'a> BoundedCommitSummaryIngressRepositoryImpl<'a> {
 impl BoundedCommitSummaryIngressRepository for BoundedCommitSummaryIngressRepositoryImpl<'_> {
     fn get_commits_from(
         &self,
-        version: Rc<Option<SemanticVersion>>,
-    ) -> Result<Box<dyn DoubleEndedIterator<Item = CommitSummary>>, AnyError> {
+        from: Option<&str>,
+        to: Option<&str>,
+        paths: &[String],
+    ) -> Result<Box<dyn DoubleEndedIterator<Item = Commit>>, AnyError> {
         let commit_list = self
             .bounded_commit_summary_ingress_manager
-            .get_commits_from(version)?;
-        Ok(Box::new(commit_list.map(|c| {
-            CommitSummary::from_str(&c).expect("Commit deserialization cannot fail")
+            .get_commits_from(from, to, paths)?;
+        Ok(Box::new(commit_list.map(|(hash, message)| {
+            // A header which looks conventional but breaks the invariants is kept as free-form
+            let summary = CommitSummary::from_str(&message).unwrap_or_else(|_| {
+                CommitSummary::FreeForm(message.lines().next().unwrap_or_default().to_owned())
+            });
+            let footers = ConventionalCommit::from_str(&message)
+                .map(|it| it.footers().to_vec())
+                .unwrap_or_default();
+            Commit::new(hash, summary, footers)
         })))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::rc::Rc;
-
     use crate::{
         application::manager::bounded_commit_summary_ingress_manager::BoundedCommitSummaryIngressManager,
-        domain::{commit_summary::CommitSummary, semantic_version::SemanticVersion},
+        domain::{commit::Commit, commit_summary::CommitSummary},
         usecase::{
             repository::bounded_commit_summary_ingress_repository::BoundedCommitSummaryIngressRepository,
             type_aliases::AnyError,
@@ -57,12 +66,18 @@ mod tests {
     impl BoundedCommitSummaryIngressManager for MockCommitRetriever {
         fn get_commits_from(
             &self,
-            _version: Rc<Option<SemanticVersion>>,
-        ) -> Result<Box<dyn DoubleEndedIterator<Item = String>>, AnyError> {
+            _from: Option<&str>,
+            _to: Option<&str>,
+            _paths: &[String],
+        ) -> Result<Box<dyn DoubleEndedIterator<Item = (String, String)>>, AnyError> {
             Ok(Box::new(
-                vec!["test freeform", "feat: im conventional"]
-                    .into_iter()
-                    .map(|it| it.to_string()),
+                vec![
+                    ("abc1234", "test freeform"),
+                    ("def5678", "feat: im conventional\n\nRefs: #12"),
+                    ("0123abc", "Feat: im not conventional"),
+                ]
+                .into_iter()
+                .map(|(hash, message)| (hash.to_string(), message.to_string())),
             ))
         }
     }
@@ -71,11 +86,23 @@ mod tests {
     fn get_commits_from_basic() {
         let mock_commit_retriever = MockCommitRetriever {};
         let repository = BoundedCommitSummaryIngressRepositoryImpl::new(&mock_commit_retriever);
-        let commit_list = repository.get_commits_from(None.into());
+        let commit_list = repository.get_commits_from(None, None, &[]);
         assert!(commit_list.is_ok());
-        assert!(commit_list
-            .expect("Just asserted its OK-ness")
-            .all(|it| matches!(it, CommitSummary::Conventional(..))
-                || matches!(it, CommitSummary::FreeForm(..))))
+        let commit_list: Vec<Commit> = commit_list.expect("Just asserted its OK-ness").collect();
+        assert_eq!(
+            commit_list
+                .iter()
+                .map(|it| it.hash())
+                .collect::<Vec<&str>>(),
+            vec!["abc1234", "def5678", "0123abc"]
+        );
+        assert!(matches!(
+            commit_list[1].summary(),
+            CommitSummary::Conventional(..)
+        ));
+        assert_eq!(commit_list[1].referenced_issues(), vec!["12"]);
+        assert!(
+            matches!(commit_list[2].summary(), CommitSummary::FreeForm(s) if s == "Feat: im not conventional")
+        );
     }
 }
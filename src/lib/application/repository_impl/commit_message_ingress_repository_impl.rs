@@ -0,0 +1,234 @@
+use std::str::FromStr;
+
+use crate::{
+    application::manager::{
+        comment_char_ingress_manager::CommentCharIngressManager,
+        commit_message_ingress_manager::CommitMessageIngressManager,
+    },
+    domain::{commit_summary::CommitSummary, conventional_commit::ConventionalCommit},
+    usecase::{
+        parsed_commit::ParsedCommit,
+        repository::commit_message_ingress_repository::CommitMessageIngressRepository,
+        type_aliases::AnyError,
+    },
+};
+
+const DEFAULT_COMMENT_PREFIX: &str = "#";
+const AUTO_COMMENT_CHAR: &str = "auto";
+/// The characters git chooses from when `core.commentChar` is `auto`.
+const AUTO_COMMENT_CANDIDATES: &str = "#;@!$%^&|:";
+const SCISSORS: &str = "------------------------ >8 ------------------------";
+
+pub struct CommitMessageIngressRepositoryImpl<'a> {
+    commit_message_ingress_manager: &'a dyn CommitMessageIngressManager,
+    comment_char_ingress_manager: &'a dyn CommentCharIngressManager,
+}
+
+impl<'a, 'b: 'a, 'c: 'a> CommitMessageIngressRepositoryImpl<'a> {
+    pub fn new(
+        commit_message_ingress_manager: &'b dyn CommitMessageIngressManager,
+        comment_char_ingress_manager: &'c dyn CommentCharIngressManager,
+    ) -> Self {
+        CommitMessageIngressRepositoryImpl {
+            commit_message_ingress_manager,
+            comment_char_ingress_manager,
+        }
+    }
+
+    /**
+     * The prefix of the comments in the message. With `core.commentChar=auto`, git picks a
+     * character which starts no line of the message and puts its comments after the message, so
+     * the prefix is found on the scissors line or on the last commented line.
+     */
+    fn comment_prefix(&self, message: &str) -> Result<String, AnyError> {
+        let comment_char = self.comment_char_ingress_manager.comment_char()?;
+        if comment_char != AUTO_COMMENT_CHAR {
+            return Ok(comment_char);
+        }
+        let is_candidate = |it: &&str| {
+            it.chars()
+                .next()
+                .is_some_and(|first| AUTO_COMMENT_CANDIDATES.contains(first))
+        };
+        Ok(message
+            .lines()
+            .find(|it| is_candidate(it) && it[1..] == format!(" {}", SCISSORS))
+            .or_else(|| message.lines().rev().find(is_candidate))
+            .map_or(DEFAULT_COMMENT_PREFIX.to_string(), |it| it[..1].to_string()))
+    }
+
+    /// Removes the lines git would strip from the message before committing.
+    fn clean_message(&self, message: &str, comment_prefix: &str) -> Vec<String> {
+        let scissors_line = format!("{} {}", comment_prefix, SCISSORS);
+        let lines: Vec<String> = message
+            .lines()
+            .take_while(|it| *it != scissors_line)
+            .filter(|it| !it.starts_with(comment_prefix))
+            .map(|it| it.trim_end().to_string())
+            .skip_while(|it| it.is_empty())
+            .collect();
+        let last_non_empty = lines
+            .iter()
+            .rposition(|it| !it.is_empty())
+            .map_or(0, |it| it + 1);
+        lines[..last_non_empty].to_vec()
+    }
+}
+
+impl CommitMessageIngressRepository for CommitMessageIngressRepositoryImpl<'_> {
+    fn get_message(&self, path: &str) -> Result<ParsedCommit, AnyError> {
+        let raw_message = self.commit_message_ingress_manager.read_message(path)?;
+        let comment_prefix = self.comment_prefix(&raw_message)?;
+        let lines = self.clean_message(&raw_message, &comment_prefix);
+        let header = lines.first().map_or("", |it| it.as_str());
+        let summary = if lines.get(1).is_some_and(|it| !it.is_empty()) {
+            Err("the summary must be followed by an empty line".to_string())
+        } else {
+            let message = lines.join("\n");
+            CommitSummary::from_str(&message)
+                .map_err(|e| e.to_string())
+                .and_then(|it| match it {
+                    CommitSummary::Conventional(_) => ConventionalCommit::from_str(&message)
+                        .map(|_| it)
+                        .map_err(|e| e.to_string()),
+                    CommitSummary::FreeForm(_) => Ok(it),
+                })
+        };
+        Ok(ParsedCommit::new("", header, summary))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::{
+        application::manager::{
+            comment_char_ingress_manager::CommentCharIngressManager,
+            commit_message_ingress_manager::CommitMessageIngressManager,
+        },
+        domain::commit_summary::CommitSummary,
+        usecase::{
+            repository::commit_message_ingress_repository::CommitMessageIngressRepository,
+            type_aliases::AnyError,
+        },
+    };
+
+    use super::CommitMessageIngressRepositoryImpl;
+
+    struct MockCommentCharIngressManager {
+        comment_char: &'static str,
+    }
+
+    impl CommentCharIngressManager for MockCommentCharIngressManager {
+        fn comment_char(&self) -> Result<String, AnyError> {
+            Ok(self.comment_char.to_string())
+        }
+    }
+
+    const HASH_COMMENT_CHAR: MockCommentCharIngressManager =
+        MockCommentCharIngressManager { comment_char: "#" };
+
+    struct MockCommitMessageIngressManager {}
+
+    impl CommitMessageIngressManager for MockCommitMessageIngressManager {
+        fn read_message(&self, path: &str) -> Result<String, AnyError> {
+            Ok(match path {
+                "full" => indoc! {"
+
+                    feat(api): add endpoint
+                    # Please enter the commit message for your changes.
+
+                    Body of the commit.
+
+                    # ------------------------ >8 ------------------------
+                    diff --git a/file b/file
+                "},
+                "custom-comment-char" => indoc! {"
+                    fix: handle issue
+                    ; Please enter the commit message for your changes.
+
+                    #123 is fixed.
+
+                    ; ------------------------ >8 ------------------------
+                    diff --git a/file b/file
+                    @@ -1 +1 @@
+                    -BREAKING CHANGE: not a footer
+                "},
+                "breaking-footer" => indoc! {"
+                    feat(api): remove endpoint
+
+                    BREAKING CHANGE: use the new endpoint
+                "},
+                "no-empty-line" => "feat: add endpoint\nBody of the commit.\n",
+                _ => "# only comments\n",
+            }
+            .to_string())
+        }
+    }
+
+    #[test]
+    fn comments_and_diff_are_ignored() {
+        let manager = MockCommitMessageIngressManager {};
+        let repository = CommitMessageIngressRepositoryImpl::new(&manager, &HASH_COMMENT_CHAR);
+        let message = repository
+            .get_message("full")
+            .expect("Mock manager never fails");
+        assert_eq!(message.header(), "feat(api): add endpoint");
+        assert!(matches!(
+            message.summary(),
+            Ok(CommitSummary::Conventional(..))
+        ));
+    }
+
+    #[test]
+    fn configured_comment_char_is_used() {
+        let manager = MockCommitMessageIngressManager {};
+        for comment_char in [";", "auto"] {
+            let comment_char_manager = MockCommentCharIngressManager { comment_char };
+            let repository =
+                CommitMessageIngressRepositoryImpl::new(&manager, &comment_char_manager);
+            let message = repository
+                .get_message("custom-comment-char")
+                .expect("Mock manager never fails");
+            assert_eq!(message.header(), "fix: handle issue");
+            assert!(matches!(
+                message.summary(),
+                Ok(CommitSummary::Conventional(summary)) if !summary.breaking()
+            ));
+        }
+    }
+
+    #[test]
+    fn summary_must_be_followed_by_empty_line() {
+        let manager = MockCommitMessageIngressManager {};
+        let repository = CommitMessageIngressRepositoryImpl::new(&manager, &HASH_COMMENT_CHAR);
+        let message = repository
+            .get_message("no-empty-line")
+            .expect("Mock manager never fails");
+        assert!(message.summary().is_err());
+    }
+
+    #[test]
+    fn empty_message_is_invalid() {
+        let manager = MockCommitMessageIngressManager {};
+        let repository = CommitMessageIngressRepositoryImpl::new(&manager, &HASH_COMMENT_CHAR);
+        let message = repository
+            .get_message("empty")
+            .expect("Mock manager never fails");
+        assert!(message.summary().is_err());
+    }
+
+    #[test]
+    fn breaking_footer_is_detected() {
+        let manager = MockCommitMessageIngressManager {};
+        let repository = CommitMessageIngressRepositoryImpl::new(&manager, &HASH_COMMENT_CHAR);
+        let message = repository
+            .get_message("breaking-footer")
+            .expect("Mock manager never fails");
+        assert!(matches!(
+            message.summary(),
+            Ok(CommitSummary::Conventional(summary)) if summary.breaking()
+        ));
+    }
+}
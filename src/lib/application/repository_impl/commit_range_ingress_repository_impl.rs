@@ -0,0 +1,93 @@
+use std::str::FromStr;
+
+use crate::{
+    application::manager::commit_range_ingress_manager::CommitRangeIngressManager,
+    domain::commit_summary::CommitSummary,
+    usecase::{
+        parsed_commit::ParsedCommit,
+        repository::commit_range_ingress_repository::CommitRangeIngressRepository,
+        type_aliases::AnyError,
+    },
+};
+
+pub struct CommitRangeIngressRepositoryImpl<'a> {
+    commit_range_ingress_manager: &'a dyn CommitRangeIngressManager,
+}
+
+impl<'a, 'b: 'a> CommitRangeIngressRepositoryImpl<'a> {
+    pub fn new(commit_range_ingress_manager: &'b dyn CommitRangeIngressManager) -> Self {
+        CommitRangeIngressRepositoryImpl {
+            commit_range_ingress_manager,
+        }
+    }
+}
+
+impl CommitRangeIngressRepository for CommitRangeIngressRepositoryImpl<'_> {
+    fn get_commits_in_range(
+        &self,
+        range: Option<&str>,
+    ) -> Result<Box<dyn Iterator<Item = ParsedCommit>>, AnyError> {
+        let commit_list = self
+            .commit_range_ingress_manager
+            .get_commits_in_range(range)?;
+        Ok(Box::new(commit_list.map(|(hash, header)| {
+            let summary = CommitSummary::from_str(&header).map_err(|e| e.to_string());
+            ParsedCommit::new(&hash, &header, summary)
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        application::manager::commit_range_ingress_manager::CommitRangeIngressManager,
+        domain::commit_summary::CommitSummary,
+        usecase::{
+            repository::commit_range_ingress_repository::CommitRangeIngressRepository,
+            type_aliases::AnyError,
+        },
+    };
+
+    use super::CommitRangeIngressRepositoryImpl;
+
+    struct MockCommitRangeIngressManager {}
+
+    impl CommitRangeIngressManager for MockCommitRangeIngressManager {
+        fn get_commits_in_range(
+            &self,
+            _range: Option<&str>,
+        ) -> Result<Box<dyn Iterator<Item = (String, String)>>, AnyError> {
+            Ok(Box::new(
+                vec![
+                    ("aaaaaaa", "feat: im conventional"),
+                    ("bbbbbbb", "test freeform"),
+                    ("ccccccc", "Feat: wrong type"),
+                ]
+                .into_iter()
+                .map(|(h, s)| (h.to_string(), s.to_string())),
+            ))
+        }
+    }
+
+    #[test]
+    fn get_commits_in_range_basic() {
+        let manager = MockCommitRangeIngressManager {};
+        let repository = CommitRangeIngressRepositoryImpl::new(&manager);
+        let commits: Vec<_> = repository
+            .get_commits_in_range(None)
+            .expect("Mock manager never fails")
+            .collect();
+        assert_eq!(commits.len(), 3);
+        assert_eq!(commits[0].hash(), "aaaaaaa");
+        assert!(matches!(
+            commits[0].summary(),
+            Ok(CommitSummary::Conventional(..))
+        ));
+        assert!(matches!(
+            commits[1].summary(),
+            Ok(CommitSummary::FreeForm(..))
+        ));
+        assert!(commits[2].summary().is_err());
+        assert_eq!(commits[2].header(), "Feat: wrong type");
+    }
+}
@@ -92,6 +92,7 @@ mod tests {
             ConventionalCommitSummaryBreakingFlag::Disabled,
             "test".to_string(),
             None,
+            vec![],
         )
         .expect("Hand-crafted conventional commit summary is always correct");
         let commit_manager = MockCommitManager { fail: false };
@@ -108,6 +109,7 @@ mod tests {
             ConventionalCommitSummaryBreakingFlag::Disabled,
             "test".to_string(),
             None,
+            vec![],
         )
         .expect("Hand-crafted conventional commit summary is always correct");
         let commit_manager = MockCommitManager { fail: true };
@@ -124,6 +126,7 @@ mod tests {
             ConventionalCommitSummaryBreakingFlag::Disabled,
             "test".to_string(),
             None,
+            vec![],
         )
         .expect("Hand-crafted conventional commit summary is always correct");
         let commit_manager = MockCommitManager { fail: false };
@@ -140,6 +143,7 @@ mod tests {
             ConventionalCommitSummaryBreakingFlag::Disabled,
             "test".to_string(),
             None,
+            vec![],
         )
         .expect("Hand-crafted conventional commit summary is always correct");
         let commit_manager = MockCommitManager { fail: true };
@@ -5,7 +5,7 @@ use crate::{
     domain::semantic_version::SemanticVersion,
     usecase::{
         repository::semantic_version_ingress_repository::SemanticVersionIngressRepository,
-        type_aliases::AnyError,
+        tag_format::TagFormat, type_aliases::AnyError,
     },
 };
 
@@ -22,8 +22,11 @@ impl<'a, 'b: 'a> SemanticVersionIngressRepositoryImpl<'a> {
 }
 
 impl SemanticVersionIngressRepository for SemanticVersionIngressRepositoryImpl<'_> {
-    fn last_version(&self) -> Result<Rc<Option<SemanticVersion>>, AnyError> {
-        let version = self.version_ingress_manager.last_version()?;
+    fn last_version(
+        &self,
+        tag_format: &TagFormat,
+    ) -> Result<Rc<Option<SemanticVersion>>, AnyError> {
+        let version = self.version_ingress_manager.last_version(tag_format)?;
         Ok(match version {
             Some(s) => Some(SemanticVersion::from_str(&s)?),
             None => None,
@@ -31,14 +34,33 @@ impl SemanticVersionIngressRepository for SemanticVersionIngressRepositoryImpl<'
         .into())
     }
 
-    fn last_stable_version(&self) -> Result<Rc<Option<SemanticVersion>>, AnyError> {
-        let version = self.version_ingress_manager.last_stable_version()?;
+    fn last_stable_version(
+        &self,
+        tag_format: &TagFormat,
+    ) -> Result<Rc<Option<SemanticVersion>>, AnyError> {
+        let version = self
+            .version_ingress_manager
+            .last_stable_version(tag_format)?;
         Ok(match version {
             Some(v) => Some(SemanticVersion::from_str(&v)?),
             None => None,
         }
         .into())
     }
+
+    fn all_versions(
+        &self,
+        tag_format: &TagFormat,
+    ) -> Result<Vec<(SemanticVersion, String)>, AnyError> {
+        let mut versions = self
+            .version_ingress_manager
+            .all_versions(tag_format)?
+            .into_iter()
+            .map(|(version, date)| Ok((SemanticVersion::from_str(&version)?, date)))
+            .collect::<Result<Vec<(SemanticVersion, String)>, AnyError>>()?;
+        versions.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(versions)
+    }
 }
 
 #[cfg(test)]
@@ -51,44 +73,59 @@ mod tests {
         domain::semantic_version::SemanticVersion,
         usecase::{
             repository::semantic_version_ingress_repository::SemanticVersionIngressRepository,
-            type_aliases::AnyError,
+            tag_format::TagFormat, type_aliases::AnyError,
         },
     };
 
     struct MockEmptyVersionRetriever {}
 
     impl VersionIngressManager for MockEmptyVersionRetriever {
-        fn last_version(&self) -> Result<Option<String>, AnyError> {
+        fn last_version(&self, _tag_format: &TagFormat) -> Result<Option<String>, AnyError> {
             Ok(None)
         }
 
-        fn last_stable_version(&self) -> Result<Option<String>, AnyError> {
+        fn last_stable_version(&self, _tag_format: &TagFormat) -> Result<Option<String>, AnyError> {
             Ok(None)
         }
+
+        fn all_versions(&self, _tag_format: &TagFormat) -> Result<Vec<(String, String)>, AnyError> {
+            Ok(vec![])
+        }
     }
 
     struct MockFullVersionRetriever {}
 
     impl VersionIngressManager for MockFullVersionRetriever {
-        fn last_version(&self) -> Result<Option<String>, AnyError> {
+        fn last_version(&self, _tag_format: &TagFormat) -> Result<Option<String>, AnyError> {
             Ok(Some(String::from("0.1.0-dev1")))
         }
 
-        fn last_stable_version(&self) -> Result<Option<String>, AnyError> {
+        fn last_stable_version(&self, _tag_format: &TagFormat) -> Result<Option<String>, AnyError> {
             Ok(Some(String::from("0.1.0")))
         }
+
+        fn all_versions(&self, _tag_format: &TagFormat) -> Result<Vec<(String, String)>, AnyError> {
+            Ok(vec![
+                (String::from("0.1.0"), String::from("2024-02-01")),
+                (String::from("0.1.0-dev1"), String::from("2024-01-01")),
+            ])
+        }
     }
 
     struct MockWrongVersionRetriever {}
 
     impl VersionIngressManager for MockWrongVersionRetriever {
-        fn last_version(&self) -> Result<Option<String>, AnyError> {
+        fn last_version(&self, _tag_format: &TagFormat) -> Result<Option<String>, AnyError> {
             Ok(Some(String::from("22.04")))
         }
 
-        fn last_stable_version(&self) -> Result<Option<String>, AnyError> {
+        fn last_stable_version(&self, _tag_format: &TagFormat) -> Result<Option<String>, AnyError> {
             Ok(Some(String::from("22-04-12")))
         }
+
+        fn all_versions(&self, _tag_format: &TagFormat) -> Result<Vec<(String, String)>, AnyError> {
+            Ok(vec![(String::from("22.04"), String::from("2022-04-12"))])
+        }
     }
 
     #[test]
@@ -97,20 +134,22 @@ mod tests {
         let expected = SemanticVersion::new(0, 1, 0, Some("dev1".to_string()), None)
             .expect("Hand-crafted version is always correct");
         assert!(repository
-            .last_version()
+            .last_version(&TagFormat::default())
             .is_ok_and(|it| it.as_ref().clone().is_some_and(|v| v == expected)));
     }
 
     #[test]
     fn last_version_empty() {
         let repository = SemanticVersionIngressRepositoryImpl::new(&MockEmptyVersionRetriever {});
-        assert!(repository.last_version().is_ok_and(|it| it.is_none()));
+        assert!(repository
+            .last_version(&TagFormat::default())
+            .is_ok_and(|it| it.is_none()));
     }
 
     #[test]
     fn last_version_wrong() {
         let repository = SemanticVersionIngressRepositoryImpl::new(&MockWrongVersionRetriever {});
-        assert!(repository.last_version().is_err());
+        assert!(repository.last_version(&TagFormat::default()).is_err());
     }
 
     #[test]
@@ -119,7 +158,7 @@ mod tests {
         let expected = SemanticVersion::new(0, 1, 0, None, None)
             .expect("Hand-crafted version is always correct");
         assert!(repository
-            .last_stable_version()
+            .last_stable_version(&TagFormat::default())
             .is_ok_and(|it| it.as_ref().clone().is_some_and(|v| v == expected)));
     }
 
@@ -127,13 +166,47 @@ mod tests {
     fn last_stable_version_empty() {
         let repository = SemanticVersionIngressRepositoryImpl::new(&MockEmptyVersionRetriever {});
         assert!(repository
-            .last_stable_version()
+            .last_stable_version(&TagFormat::default())
             .is_ok_and(|it| it.is_none()));
     }
 
     #[test]
     fn last_stable_version_wrong() {
         let repository = SemanticVersionIngressRepositoryImpl::new(&MockWrongVersionRetriever {});
-        assert!(repository.last_stable_version().is_err());
+        assert!(repository
+            .last_stable_version(&TagFormat::default())
+            .is_err());
+    }
+
+    #[test]
+    fn all_versions_sorted() {
+        let repository = SemanticVersionIngressRepositoryImpl::new(&MockFullVersionRetriever {});
+        let versions = repository
+            .all_versions(&TagFormat::default())
+            .expect("Hand-crafted versions are correct");
+        assert_eq!(
+            versions
+                .iter()
+                .map(|(v, d)| (v.to_string(), d.as_str()))
+                .collect::<Vec<(String, &str)>>(),
+            vec![
+                ("0.1.0-dev1".to_string(), "2024-01-01"),
+                ("0.1.0".to_string(), "2024-02-01"),
+            ]
+        );
+    }
+
+    #[test]
+    fn all_versions_wrong() {
+        let repository = SemanticVersionIngressRepositoryImpl::new(&MockWrongVersionRetriever {});
+        assert!(repository.all_versions(&TagFormat::default()).is_err());
+    }
+
+    #[test]
+    fn all_versions_empty() {
+        let repository = SemanticVersionIngressRepositoryImpl::new(&MockEmptyVersionRetriever {});
+        assert!(repository
+            .all_versions(&TagFormat::default())
+            .is_ok_and(|it| it.is_empty()));
     }
 }
@@ -1,7 +1,10 @@
 use crate::{
     application::manager::tag_egress_manager::TagEgressManager,
     domain::semantic_version::SemanticVersion,
-    usecase::{repository::tag_egress_repository::TagEgressRepository, type_aliases::AnyError},
+    usecase::{
+        repository::tag_egress_repository::TagEgressRepository, tag_format::TagFormat,
+        type_aliases::AnyError,
+    },
 };
 
 pub struct TagEgressRepositoryImpl<'a> {
@@ -18,11 +21,12 @@ impl TagEgressRepository for TagEgressRepositoryImpl<'_> {
     fn create_tag(
         &self,
         version: &SemanticVersion,
+        tag_format: &TagFormat,
         message: Option<&str>,
         sign: bool,
     ) -> Result<(), AnyError> {
         self.tag_egress_manager
-            .create_tag(&version.to_string(), message, sign)
+            .create_tag(&tag_format.tag(version), message, sign)
     }
 }
 
@@ -36,7 +40,11 @@ mod tests {
             repository_impl::tag_egress_repository_impl::TagEgressRepositoryImpl,
         },
         domain::semantic_version::SemanticVersion,
-        usecase::{repository::tag_egress_repository::TagEgressRepository, type_aliases::AnyError},
+        usecase::{
+            repository::tag_egress_repository::TagEgressRepository,
+            tag_format::{TagFormat, DEFAULT_PACKAGE_TEMPLATE},
+            type_aliases::AnyError,
+        },
     };
 
     struct MockTagEgressManager {
@@ -62,8 +70,23 @@ mod tests {
             label: RefCell::new("".into()),
         };
         let repository = TagEgressRepositoryImpl::new(&manager);
-        let result = repository.create_tag(&version, None, false);
+        let result = repository.create_tag(&version, &TagFormat::default(), None, false);
         assert!(result.is_ok());
         assert_eq!(manager.label.borrow().as_ref(), version.to_string());
     }
+
+    #[test]
+    fn received_label_is_package_tag() {
+        let version = SemanticVersion::new(1, 4, 0, None, None)
+            .expect("Hand-crafted version is always correct");
+        let manager = MockTagEgressManager {
+            label: RefCell::new("".into()),
+        };
+        let repository = TagEgressRepositoryImpl::new(&manager);
+        let tag_format = TagFormat::for_package("api", DEFAULT_PACKAGE_TEMPLATE)
+            .expect("The template has a placeholder");
+        let result = repository.create_tag(&version, &tag_format, None, false);
+        assert!(result.is_ok());
+        assert_eq!(manager.label.borrow().as_ref(), "api/v1.4.0");
+    }
 }
@@ -1,3 +1,6 @@
+pub mod commit;
+pub mod commit_footer;
+pub mod commit_policy;
 pub mod commit_summary;
 pub mod constant;
 pub mod conventional_commit;
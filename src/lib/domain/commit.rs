@@ -0,0 +1,125 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use super::{commit_footer::CommitFooter, commit_summary::CommitSummary};
+
+lazy_static! {
+    static ref ISSUE_PATTERN: Regex = Regex::new(r"#(\d+)\b").unwrap();
+}
+
+/*
+A commit of the history, identified by its hash.
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Commit {
+    hash: String,
+    summary: CommitSummary,
+    footers: Vec<CommitFooter>,
+}
+
+impl Commit {
+    pub fn new(hash: String, summary: CommitSummary, footers: Vec<CommitFooter>) -> Self {
+        Commit {
+            hash,
+            summary,
+            footers,
+        }
+    }
+
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+
+    pub fn summary(&self) -> &CommitSummary {
+        &self.summary
+    }
+
+    /**
+     * The description of the breaking change, from the 'BREAKING CHANGE' footer.
+     */
+    pub fn breaking_note(&self) -> Option<&str> {
+        self.footers
+            .iter()
+            .find(|it| it.is_breaking())
+            .map(|it| it.value())
+    }
+
+    /**
+     * The numbers of the issues referenced as '#<number>' in the summary or in the footers,
+     * in order of appearance and without duplicates.
+     */
+    pub fn referenced_issues(&self) -> Vec<String> {
+        let summary = match &self.summary {
+            CommitSummary::Conventional(it) => it.summary(),
+            CommitSummary::FreeForm(it) => it,
+        };
+        let mut issues: Vec<String> = vec![];
+        std::iter::once(summary)
+            .chain(self.footers.iter().map(|it| it.value()))
+            .flat_map(|it| ISSUE_PATTERN.captures_iter(it))
+            .for_each(|it| {
+                if !issues.iter().any(|issue| issue == &it[1]) {
+                    issues.push(it[1].to_owned());
+                }
+            });
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::{
+        commit_footer::CommitFooter, commit_summary::CommitSummary,
+        conventional_commit_summary::ConventionalCommitSummary,
+    };
+
+    use super::Commit;
+
+    #[test]
+    fn referenced_issues_from_summary_and_footers() {
+        let commit = Commit::new(
+            String::new(),
+            CommitSummary::Conventional(
+                ConventionalCommitSummary::new(
+                    "fix".to_string(),
+                    None,
+                    false.into(),
+                    "handle empty input (#12)".to_string(),
+                )
+                .expect("Hand-crafted summary is correct"),
+            ),
+            vec![
+                CommitFooter::new("Refs".to_string(), "#34, #12".to_string())
+                    .expect("Hand-crafted footer is correct"),
+                CommitFooter::new("Reviewed-by".to_string(), "someone".to_string())
+                    .expect("Hand-crafted footer is correct"),
+            ],
+        );
+        assert_eq!(commit.referenced_issues(), vec!["12", "34"]);
+    }
+
+    #[test]
+    fn referenced_issues_free_form() {
+        let commit = Commit::new(
+            String::new(),
+            CommitSummary::FreeForm("Merge pull request #7".to_string()),
+            vec![],
+        );
+        assert_eq!(commit.referenced_issues(), vec!["7"]);
+    }
+
+    #[test]
+    fn breaking_note_from_footer() {
+        let commit = Commit::new(
+            String::new(),
+            CommitSummary::FreeForm("Remove endpoint".to_string()),
+            vec![
+                CommitFooter::new("Refs".to_string(), "#7".to_string())
+                    .expect("Hand-crafted footer is correct"),
+                CommitFooter::new("BREAKING-CHANGE".to_string(), "use /v2".to_string())
+                    .expect("Hand-crafted footer is correct"),
+            ],
+        );
+        assert_eq!(commit.breaking_note(), Some("use /v2"));
+    }
+}
@@ -0,0 +1,76 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use super::error::commit_footer_invariant_error::CommitFooterInvariantError;
+
+lazy_static! {
+    static ref TOKEN_PATTERN: Regex = Regex::new(r"^(BREAKING CHANGE|[\w-]+)$").unwrap();
+}
+
+const BREAKING_TOKENS: [&str; 2] = ["BREAKING CHANGE", "BREAKING-CHANGE"];
+
+/*
+A footer of a conventional commit, in the form of a git trailer.
+
+The token cannot contain whitespaces, with the exception of 'BREAKING CHANGE'.
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitFooter {
+    token: String,
+    value: String,
+}
+
+impl CommitFooter {
+    pub fn new(token: String, value: String) -> Result<Self, CommitFooterInvariantError> {
+        if !TOKEN_PATTERN.is_match(&token) {
+            return Err(CommitFooterInvariantError::Token(token));
+        }
+        if value.trim().is_empty() {
+            return Err(CommitFooterInvariantError::Value(token));
+        }
+        Ok(CommitFooter { token, value })
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn is_breaking(&self) -> bool {
+        BREAKING_TOKENS.contains(&self.token.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CommitFooter;
+
+    #[test]
+    fn footer_ok() {
+        let footer = CommitFooter::new("Refs".to_string(), "#123".to_string());
+        assert!(footer.is_ok_and(|it| !it.is_breaking()));
+    }
+
+    #[test]
+    fn breaking_footers() {
+        let spaced = CommitFooter::new("BREAKING CHANGE".to_string(), "removed api".to_string());
+        let dashed = CommitFooter::new("BREAKING-CHANGE".to_string(), "removed api".to_string());
+        assert!(spaced.is_ok_and(|it| it.is_breaking()));
+        assert!(dashed.is_ok_and(|it| it.is_breaking()));
+    }
+
+    #[test]
+    fn token_with_whitespace_is_refused() {
+        let footer = CommitFooter::new("Reviewed by".to_string(), "someone".to_string());
+        assert!(footer.is_err());
+    }
+
+    #[test]
+    fn empty_value_is_refused() {
+        let footer = CommitFooter::new("Refs".to_string(), " ".to_string());
+        assert!(footer.is_err());
+    }
+}
@@ -0,0 +1,137 @@
+use super::error::commit_policy_violation_error::CommitPolicyViolationError;
+
+/*
+Policy on the types and scopes a commit can use.
+
+When a list is not present, any value is accepted.
+When a value is refused, the closest allowed value is suggested.
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CommitPolicy {
+    allowed_types: Option<Vec<String>>,
+    allowed_scopes: Option<Vec<String>>,
+}
+
+impl CommitPolicy {
+    pub fn new(allowed_types: Option<Vec<String>>, allowed_scopes: Option<Vec<String>>) -> Self {
+        CommitPolicy {
+            allowed_types,
+            allowed_scopes,
+        }
+    }
+
+    pub fn check(&self, typ: &str, scope: Option<&str>) -> Result<(), CommitPolicyViolationError> {
+        if let Some(types) = &self.allowed_types {
+            if !types.iter().any(|it| it == typ) {
+                return Err(CommitPolicyViolationError::UnknownType {
+                    value: typ.to_owned(),
+                    suggestion: closest(typ, types),
+                });
+            }
+        }
+        if let (Some(scopes), Some(scope)) = (&self.allowed_scopes, scope) {
+            if !scopes.iter().any(|it| it == scope) {
+                return Err(CommitPolicyViolationError::UnknownScope {
+                    value: scope.to_owned(),
+                    suggestion: closest(scope, scopes),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+fn closest(value: &str, candidates: &[String]) -> Option<String> {
+    candidates
+        .iter()
+        .min_by_key(|it| edit_distance(value, it))
+        .cloned()
+}
+
+/// Optimal string alignment distance, so that swapped characters count as a single edit.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut d = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::{
+        commit_policy::{edit_distance, CommitPolicy},
+        error::commit_policy_violation_error::CommitPolicyViolationError,
+    };
+
+    fn strict_policy() -> CommitPolicy {
+        CommitPolicy::new(
+            Some(vec!["feat".to_owned(), "fix".to_owned(), "docs".to_owned()]),
+            Some(vec!["api".to_owned(), "cli".to_owned()]),
+        )
+    }
+
+    #[test]
+    fn edit_distance_counts_transpositions_once() {
+        assert_eq!(edit_distance("fix", "fix"), 0);
+        assert_eq!(edit_distance("fxi", "fix"), 1);
+        assert_eq!(edit_distance("feat", "fest"), 1);
+        assert_eq!(edit_distance("", "api"), 3);
+    }
+
+    #[test]
+    fn unrestricted_policy_accepts_anything() {
+        let policy = CommitPolicy::default();
+        assert!(policy.check("whatever", Some("anything")).is_ok());
+    }
+
+    #[test]
+    fn declared_values_are_accepted() {
+        assert!(strict_policy().check("fix", Some("cli")).is_ok());
+        assert!(strict_policy().check("docs", None).is_ok());
+    }
+
+    #[test]
+    fn unknown_type_suggests_closest() {
+        assert_eq!(
+            strict_policy().check("fetaure", None),
+            Err(CommitPolicyViolationError::UnknownType {
+                value: "fetaure".to_owned(),
+                suggestion: Some("feat".to_owned()),
+            })
+        );
+    }
+
+    #[test]
+    fn unknown_scope_suggests_closest() {
+        assert_eq!(
+            strict_policy().check("feat", Some("apis")),
+            Err(CommitPolicyViolationError::UnknownScope {
+                value: "apis".to_owned(),
+                suggestion: Some("api".to_owned()),
+            })
+        );
+    }
+
+    #[test]
+    fn scopes_are_not_restricted_without_list() {
+        let policy = CommitPolicy::new(Some(vec!["feat".to_owned()]), None);
+        assert!(policy.check("feat", Some("anything")).is_ok());
+    }
+}
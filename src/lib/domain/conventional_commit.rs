@@ -1,4 +1,5 @@
 use super::{
+    commit_footer::CommitFooter,
     conventional_commit_summary::{
         ConventionalCommitSummary, ConventionalCommitSummaryBreakingFlag,
     },
@@ -8,15 +9,18 @@ use super::{
 /**
 A [conventional commit](https://www.conventionalcommits.org/en/v1.0.0/).
 
-Other than a [ConventionalCommitSummary], it also contains the optional message.
+Other than a [ConventionalCommitSummary], it also contains the optional body and the footers.
 
-This message should contain all the lines but the first,
+The body should contain all the lines between the first and the footers,
 so no duplicate information is present.
+
+A breaking footer (i.e. 'BREAKING CHANGE') marks the summary as breaking.
 */
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ConventionalCommit {
     summary: ConventionalCommitSummary,
-    message: Option<String>,
+    body: Option<String>,
+    footers: Vec<CommitFooter>,
 }
 
 impl ConventionalCommit {
@@ -25,11 +29,18 @@ impl ConventionalCommit {
         scope: Option<String>,
         breaking: ConventionalCommitSummaryBreakingFlag,
         summary: String,
-        message: Option<String>,
+        body: Option<String>,
+        footers: Vec<CommitFooter>,
     ) -> Result<Self, ConventionalCommitError> {
+        let breaking = if footers.iter().any(|it| it.is_breaking()) {
+            ConventionalCommitSummaryBreakingFlag::Enabled
+        } else {
+            breaking
+        };
         Ok(ConventionalCommit {
             summary: ConventionalCommitSummary::new(typ, scope, breaking, summary)?,
-            message,
+            body,
+            footers,
         })
     }
 
@@ -37,7 +48,56 @@ impl ConventionalCommit {
         &self.summary
     }
 
-    pub fn message(&self) -> Option<&str> {
-        self.message.as_deref()
+    pub fn body(&self) -> Option<&str> {
+        self.body.as_deref()
+    }
+
+    pub fn footers(&self) -> &[CommitFooter] {
+        &self.footers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::{
+        commit_footer::CommitFooter,
+        conventional_commit_summary::ConventionalCommitSummaryBreakingFlag,
+    };
+
+    use super::ConventionalCommit;
+
+    #[test]
+    fn breaking_footer_sets_breaking_flag() {
+        let commit = ConventionalCommit::new(
+            "feat".to_string(),
+            None,
+            ConventionalCommitSummaryBreakingFlag::Disabled,
+            "test".to_string(),
+            None,
+            vec![
+                CommitFooter::new("Refs".to_string(), "#1".to_string())
+                    .expect("Hand-crafted footer is always correct"),
+                CommitFooter::new("BREAKING CHANGE".to_string(), "removed api".to_string())
+                    .expect("Hand-crafted footer is always correct"),
+            ],
+        )
+        .expect("Hand-crafted commit is always correct");
+        assert!(commit.summary().breaking());
+    }
+
+    #[test]
+    fn non_breaking_footers_keep_breaking_flag() {
+        let commit = ConventionalCommit::new(
+            "feat".to_string(),
+            None,
+            ConventionalCommitSummaryBreakingFlag::Disabled,
+            "test".to_string(),
+            Some("Body".to_string()),
+            vec![CommitFooter::new("Refs".to_string(), "#1".to_string())
+                .expect("Hand-crafted footer is always correct")],
+        )
+        .expect("Hand-crafted commit is always correct");
+        assert!(!commit.summary().breaking());
+        assert_eq!(commit.body(), Some("Body"));
     }
 }
@@ -1,3 +1,5 @@
+pub mod commit_footer_invariant_error;
+pub mod commit_policy_violation_error;
 pub mod conventional_commit_error;
 pub mod conventional_commit_summary_invariant_error;
 pub mod semantic_version_invariant_error;
@@ -0,0 +1,18 @@
+use std::{error::Error, fmt::Display};
+
+#[derive(Debug)]
+pub enum CommitFooterInvariantError {
+    Token(String),
+    Value(String),
+}
+
+impl Display for CommitFooterInvariantError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Token(t) => write!(f, "invalid footer token '{}'", t),
+            Self::Value(t) => write!(f, "footer '{}' must have a value", t),
+        }
+    }
+}
+
+impl Error for CommitFooterInvariantError {}
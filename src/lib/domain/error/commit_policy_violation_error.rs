@@ -0,0 +1,29 @@
+use std::{error::Error, fmt::Display};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CommitPolicyViolationError {
+    UnknownType {
+        value: String,
+        suggestion: Option<String>,
+    },
+    UnknownScope {
+        value: String,
+        suggestion: Option<String>,
+    },
+}
+
+impl Display for CommitPolicyViolationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (what, value, suggestion) = match self {
+            Self::UnknownType { value, suggestion } => ("type", value, suggestion),
+            Self::UnknownScope { value, suggestion } => ("scope", value, suggestion),
+        };
+        write!(f, "{} '{}' is not allowed", what, value)?;
+        if let Some(s) = suggestion {
+            write!(f, " (did you mean '{}'?)", s)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for CommitPolicyViolationError {}
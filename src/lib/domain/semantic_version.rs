@@ -57,14 +57,12 @@ impl SemanticVersion {
         prerelease: Option<String>,
     ) -> Result<Option<String>, InvalidPrereleaseStringError> {
         match prerelease {
+            // Numeric identifiers of a prerelease must not have leading zeros
             Some(wrong)
-                if wrong.is_empty()
-                    || wrong.chars().any(|it| {
-                        !(it.is_ascii_digit()
-                            || it.is_ascii_lowercase()
-                            || it.is_ascii_uppercase()
-                            || it == '-')
-                    }) =>
+                if !Self::are_valid_identifiers(&wrong)
+                    || wrong
+                        .split('.')
+                        .any(|it| it.len() > 1 && is_numeric(it) && it.starts_with('0')) =>
             {
                 Err(InvalidPrereleaseStringError::new(wrong))
             }
@@ -77,21 +75,49 @@ impl SemanticVersion {
         metadata: Option<String>,
     ) -> Result<Option<String>, InvalidMetadataStringError> {
         match metadata {
-            Some(wrong)
-                if wrong.is_empty()
-                    || wrong.chars().any(|it| {
-                        !(it.is_ascii_digit()
-                            || it.is_ascii_lowercase()
-                            || it.is_ascii_uppercase()
-                            || it == '-')
-                    }) =>
-            {
+            Some(wrong) if !Self::are_valid_identifiers(&wrong) => {
                 Err(InvalidMetadataStringError::new(wrong))
             }
             None => Ok(None),
             Some(s) => Ok(Some(s)),
         }
     }
+
+    /**
+     * Prerelease and metadata are non-empty dot-separated identifiers made of ASCII alphanumerics
+     * and hyphens.
+     */
+    fn are_valid_identifiers(s: &str) -> bool {
+        s.split('.').all(|identifier| {
+            !identifier.is_empty()
+                && identifier
+                    .chars()
+                    .all(|it| it.is_ascii_alphanumeric() || it == '-')
+        })
+    }
+}
+
+fn is_numeric(identifier: &str) -> bool {
+    identifier.chars().all(|it| it.is_ascii_digit())
+}
+
+/**
+ * Prereleases are compared identifier by identifier: numeric identifiers compare as numbers and
+ * come before alphanumeric ones, which compare lexically. When all the identifiers are equal,
+ * the prerelease with more identifiers is the greater.
+ */
+fn compare_prereleases(p1: &str, p2: &str) -> Ordering {
+    p1.split('.')
+        .zip(p2.split('.'))
+        .map(|(i1, i2)| match (is_numeric(i1), is_numeric(i2)) {
+            // Numeric identifiers have no leading zeros, so the longer is the greater
+            (true, true) => i1.len().cmp(&i2.len()).then_with(|| i1.cmp(i2)),
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => i1.cmp(i2),
+        })
+        .find(|it| *it != Ordering::Equal)
+        .unwrap_or_else(|| p1.split('.').count().cmp(&p2.split('.').count()))
 }
 
 impl Ord for SemanticVersion {
@@ -103,7 +129,7 @@ impl Ord for SemanticVersion {
                 let patch_cmp_result = self.patch.cmp(&other.patch());
                 if patch_cmp_result == Ordering::Equal {
                     match (&self.prerelease, &other.prerelease) {
-                        (Some(p1), Some(p2)) => p1.cmp(p2),
+                        (Some(p1), Some(p2)) => compare_prereleases(p1, p2),
                         (Some(_), None) => Ordering::Less,
                         (None, Some(_)) => Ordering::Greater,
                         (None, None) => Ordering::Equal,
@@ -224,6 +250,42 @@ mod tests {
         assert!(v2 < v1);
     }
 
+    #[test]
+    fn numeric_prerelease_identifiers_are_ordered_as_numbers() {
+        let v1 = SemanticVersion::new(1, 0, 0, Some("rc.2".to_string()), None)
+            .expect("Hand-crafted version must be correct");
+        let v2 = SemanticVersion::new(1, 0, 0, Some("rc.10".to_string()), None)
+            .expect("Hand-crafted version must be correct");
+        assert!(v1 < v2);
+    }
+
+    #[test]
+    fn prereleases_follow_semver_precedence() {
+        let ordered = [
+            "alpha",
+            "alpha.1",
+            "alpha.beta",
+            "beta",
+            "beta.2",
+            "beta.11",
+            "rc.1",
+        ]
+        .map(|it| {
+            SemanticVersion::new(1, 0, 0, Some(it.to_string()), None)
+                .expect("Hand-crafted version must be correct")
+        });
+        assert!(ordered.windows(2).all(|it| it[0] < it[1]));
+    }
+
+    #[test]
+    fn numeric_prerelease_is_less_than_alphanumeric() {
+        let v1 = SemanticVersion::new(1, 0, 0, Some("1".to_string()), None)
+            .expect("Hand-crafted version must be correct");
+        let v2 = SemanticVersion::new(1, 0, 0, Some("a".to_string()), None)
+            .expect("Hand-crafted version must be correct");
+        assert!(v1 < v2);
+    }
+
     // Invariant tests
     #[test]
     fn prerelease_invariant_if_empty() {
@@ -248,6 +310,30 @@ mod tests {
         let v = SemanticVersion::new(0, 1, 0, Some("dev1".to_string()), None);
         assert!(v.is_ok());
     }
+    #[test]
+    fn prerelease_invariant_with_dots() {
+        let v = SemanticVersion::new(0, 1, 0, Some("rc.1".to_string()), None);
+        assert!(v.is_ok());
+    }
+
+    #[test]
+    fn prerelease_invariant_with_empty_identifier() {
+        let v = SemanticVersion::new(0, 1, 0, Some("rc..1".to_string()), None);
+        assert!(matches!(
+            v,
+            Err(SemanticVersionInvariantError::InvalidPrerelease(_))
+        ));
+    }
+
+    #[test]
+    fn prerelease_invariant_with_leading_zero() {
+        let v = SemanticVersion::new(0, 1, 0, Some("rc.01".to_string()), None);
+        assert!(matches!(
+            v,
+            Err(SemanticVersionInvariantError::InvalidPrerelease(_))
+        ));
+    }
+
     #[test]
     fn metadata_invariant_if_empty() {
         let v = SemanticVersion::new(0, 1, 0, None, Some("".to_string()));
@@ -271,4 +357,10 @@ mod tests {
         let v = SemanticVersion::new(0, 1, 0, None, Some("sha-date".to_string()));
         assert!(v.is_ok());
     }
+
+    #[test]
+    fn metadata_invariant_with_dots() {
+        let v = SemanticVersion::new(0, 1, 0, None, Some("build.005".to_string()));
+        assert!(v.is_ok());
+    }
 }
@@ -1,3 +1,4 @@
+mod config;
 pub mod error;
 mod helper;
 mod interface;
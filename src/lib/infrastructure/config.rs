@@ -0,0 +1,5 @@
+pub mod changelog;
+pub mod commit;
+pub mod describe;
+pub mod gitbox_config;
+pub mod tree;
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_TITLE_FORMAT: &str = "# %s";
+const DEFAULT_TYPE_FORMAT: &str = "= %s";
+const DEFAULT_SCOPE_FORMAT: &str = "- %s";
+const DEFAULT_LIST_FORMAT: &str = "%s";
+const DEFAULT_ITEM_FORMAT: &str = "* %s";
+const DEFAULT_BREAKING_FORMAT: &str = "!!! %s ";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ChangelogConfig {
+    pub from_latest_version: bool,
+    pub title_format: String,
+    pub type_format: String,
+    pub scope_format: String,
+    pub list_format: String,
+    pub item_format: String,
+    pub breaking_format: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_trigger: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issue_url: Option<String>,
+    pub breaking_section: bool,
+    pub group_by: GroupByConfig,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    pub general_scope: GeneralScopePosition,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub types: Vec<ChangelogTypeConfig>,
+}
+
+impl Default for ChangelogConfig {
+    fn default() -> Self {
+        ChangelogConfig {
+            from_latest_version: false,
+            title_format: DEFAULT_TITLE_FORMAT.to_owned(),
+            type_format: DEFAULT_TYPE_FORMAT.to_owned(),
+            scope_format: DEFAULT_SCOPE_FORMAT.to_owned(),
+            list_format: DEFAULT_LIST_FORMAT.to_owned(),
+            item_format: DEFAULT_ITEM_FORMAT.to_owned(),
+            breaking_format: DEFAULT_BREAKING_FORMAT.to_owned(),
+            hash_format: None,
+            exclude_trigger: None,
+            commit_url: None,
+            issue_url: None,
+            breaking_section: false,
+            group_by: GroupByConfig::Type,
+            template: None,
+            general_scope: GeneralScopePosition::First,
+            types: Vec::new(),
+        }
+    }
+}
+
+/**
+ * Where the commits without scope are placed among the scopes of a type.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GeneralScopePosition {
+    First,
+    Last,
+}
+
+/**
+ * How the commits are grouped in the changelog.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupByConfig {
+    Type,
+    Scope,
+    None,
+}
+
+/**
+ * A commit type shown in the changelog, in the order they are declared.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ChangelogTypeConfig {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
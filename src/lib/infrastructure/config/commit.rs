@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::conventional_commit_summary::ConventionalCommitSummary, usecase::type_aliases::AnyError,
+};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct CommitConfig {
+    pub quiet: bool,
+    pub strict: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub types: Vec<CommitEntryConfig>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub scopes: Vec<CommitEntryConfig>,
+}
+
+impl CommitConfig {
+    /**
+     * The names of the declared types, if any.
+     */
+    pub fn declared_types(&self) -> Option<Vec<String>> {
+        Self::names(&self.types)
+    }
+
+    /**
+     * The names of the declared scopes, if any.
+     */
+    pub fn declared_scopes(&self) -> Option<Vec<String>> {
+        Self::names(&self.scopes)
+    }
+
+    pub fn check(&self) -> Result<(), AnyError> {
+        if let Some(t) = self
+            .types
+            .iter()
+            .find(|it| !ConventionalCommitSummary::type_pattern().is_match(&it.name))
+        {
+            return Err(format!("declared type '{}' is not a valid commit type", t.name).into());
+        }
+        if let Some(s) = self
+            .scopes
+            .iter()
+            .find(|it| !ConventionalCommitSummary::scope_pattern().is_match(&it.name))
+        {
+            return Err(format!("declared scope '{}' is not a valid commit scope", s.name).into());
+        }
+        Ok(())
+    }
+
+    fn names(entries: &[CommitEntryConfig]) -> Option<Vec<String>> {
+        if entries.is_empty() {
+            None
+        } else {
+            Some(entries.iter().map(|it| it.name.clone()).collect())
+        }
+    }
+}
+
+/**
+ * A commit type or scope declared in the configuration file.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CommitEntryConfig {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
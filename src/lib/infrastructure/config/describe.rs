@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_PRERELEASE_PATTERN: &str = "%d";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct DescribeConfig {
+    pub prerelease_pattern: String,
+    pub diff: bool,
+    pub metadata: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub major_trigger: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minor_trigger: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patch_trigger: Option<String>,
+    pub sign_tag: bool,
+    pub initial_development: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub packages: Vec<DescribePackageConfig>,
+}
+
+impl Default for DescribeConfig {
+    fn default() -> Self {
+        DescribeConfig {
+            prerelease_pattern: DEFAULT_PRERELEASE_PATTERN.to_owned(),
+            diff: false,
+            metadata: Vec::new(),
+            major_trigger: None,
+            minor_trigger: None,
+            patch_trigger: None,
+            sign_tag: false,
+            initial_development: false,
+            packages: Vec::new(),
+        }
+    }
+}
+
+/**
+ * A package of the repository, with the paths its commits touch.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DescribePackageConfig {
+    pub name: String,
+    pub paths: Vec<String>,
+}
@@ -0,0 +1,195 @@
+use serde::{Deserialize, Serialize};
+
+use crate::usecase::type_aliases::AnyError;
+
+use super::{
+    changelog::ChangelogConfig, commit::CommitConfig, describe::DescribeConfig, tree::TreeConfig,
+};
+
+/**
+ * The repository-level configuration. Every missing value falls back to the default
+ * behaviour of the corresponding subcommand, so an empty file is a valid configuration.
+ */
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct GitboxConfig {
+    /// How versions are written in tag names, shared by every subcommand.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_template: Option<String>,
+    pub changelog: ChangelogConfig,
+    pub commit: CommitConfig,
+    pub describe: DescribeConfig,
+    pub tree: TreeConfig,
+}
+
+impl GitboxConfig {
+    pub fn from_toml(content: &str) -> Result<Self, AnyError> {
+        let config: GitboxConfig = toml::from_str(content)?;
+        config.commit.check()?;
+        Ok(config)
+    }
+
+    pub fn to_toml(&self) -> Result<String, AnyError> {
+        Ok(toml::to_string(self)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::infrastructure::config::{
+        changelog::{ChangelogConfig, ChangelogTypeConfig, GeneralScopePosition},
+        commit::{CommitConfig, CommitEntryConfig},
+        describe::DescribeConfig,
+        gitbox_config::GitboxConfig,
+    };
+
+    #[test]
+    fn empty_file_gives_default_configuration() {
+        let config = GitboxConfig::from_toml("").expect("An empty file is a valid configuration");
+        assert_eq!(config, GitboxConfig::default());
+    }
+
+    #[test]
+    fn partial_sections_keep_defaults_for_missing_values() {
+        let config = GitboxConfig::from_toml(indoc! {r###"
+            [changelog]
+            type-format = "## %s"
+
+            [describe]
+            major-trigger = "breaking or type IN [ release ]"
+            metadata = ["sha"]
+        "###})
+        .expect("Hand-crafted configuration is always correct");
+        assert_eq!(
+            config.changelog,
+            ChangelogConfig {
+                type_format: "## %s".to_owned(),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            config.describe,
+            DescribeConfig {
+                major_trigger: Some("breaking or type IN [ release ]".to_owned()),
+                metadata: vec!["sha".to_owned()],
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn tag_template_is_a_top_level_key() {
+        let config = GitboxConfig::from_toml(indoc! {r###"
+            tag-template = "v{version}"
+
+            [describe]
+            sign-tag = true
+        "###})
+        .expect("Hand-crafted configuration is always correct");
+        assert_eq!(config.tag_template, Some("v{version}".to_owned()));
+        assert!(config.describe.sign_tag);
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        let config = GitboxConfig::from_toml(indoc! {r###"
+            [changelog]
+            title = "# %s"
+        "###});
+        assert!(config.is_err());
+    }
+
+    #[test]
+    fn declared_types_and_scopes_keep_their_order() {
+        let config = GitboxConfig::from_toml(indoc! {r###"
+            [[commit.types]]
+            name = "feat"
+            description = "A new feature"
+
+            [[commit.types]]
+            name = "fix"
+
+            [[commit.scopes]]
+            name = "api"
+            description = "The public API"
+        "###})
+        .expect("Hand-crafted configuration is always correct");
+        assert_eq!(
+            config.commit,
+            CommitConfig {
+                quiet: false,
+                strict: false,
+                types: vec![
+                    CommitEntryConfig {
+                        name: "feat".to_owned(),
+                        description: Some("A new feature".to_owned()),
+                    },
+                    CommitEntryConfig {
+                        name: "fix".to_owned(),
+                        description: None,
+                    },
+                ],
+                scopes: vec![CommitEntryConfig {
+                    name: "api".to_owned(),
+                    description: Some("The public API".to_owned()),
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn changelog_types_keep_their_order() {
+        let config = GitboxConfig::from_toml(indoc! {r###"
+            [changelog]
+            general-scope = "last"
+
+            [[changelog.types]]
+            name = "perf"
+            title = "Performance"
+
+            [[changelog.types]]
+            name = "feat"
+        "###})
+        .expect("Hand-crafted configuration is always correct");
+        assert_eq!(
+            config.changelog,
+            ChangelogConfig {
+                general_scope: GeneralScopePosition::Last,
+                types: vec![
+                    ChangelogTypeConfig {
+                        name: "perf".to_owned(),
+                        title: Some("Performance".to_owned()),
+                    },
+                    ChangelogTypeConfig {
+                        name: "feat".to_owned(),
+                        title: None,
+                    },
+                ],
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_declared_types_are_rejected() {
+        let config = GitboxConfig::from_toml(indoc! {r###"
+            [[commit.types]]
+            name = "Feat"
+        "###});
+        assert!(config.is_err());
+    }
+
+    #[test]
+    fn serialized_configuration_can_be_read_back() {
+        let config = GitboxConfig::default();
+        let serialized = config
+            .to_toml()
+            .expect("Default configuration can always be serialized");
+        assert_eq!(
+            GitboxConfig::from_toml(&serialized).expect("Serialized configuration is valid"),
+            config
+        );
+    }
+}
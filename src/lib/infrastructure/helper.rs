@@ -1,4 +1,5 @@
 pub mod commit_prompt_helper;
+pub mod config_ingress_helper;
 pub mod gitextra_egress_helper;
 pub mod gitextra_ingress_helper;
 pub mod license_prompt_helper;
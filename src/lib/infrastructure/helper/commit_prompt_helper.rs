@@ -1,8 +1,12 @@
+use std::str::FromStr;
+
 use regex::Regex;
 use requestty::{prompt_one, Answer, Question};
 
 use crate::{
-    domain::conventional_commit_summary::ConventionalCommitSummary, usecase::type_aliases::AnyError,
+    domain::{commit_footer::CommitFooter, conventional_commit_summary::ConventionalCommitSummary},
+    infrastructure::config::commit::{CommitConfig, CommitEntryConfig},
+    usecase::type_aliases::AnyError,
 };
 
 use super::{
@@ -12,60 +16,78 @@ use super::{
 pub struct CommitPromptHelper<'a> {
     gitextra_read_manager: &'a dyn GitExtraIngressHelper,
     gitextra_append_manager: &'a dyn GitExtraEgressHelper,
+    config: &'a CommitConfig,
+    strict: bool,
 }
 
-impl<'a, 'b: 'a, 'c: 'a> CommitPromptHelper<'a> {
+impl<'a, 'b: 'a, 'c: 'a, 'd: 'a> CommitPromptHelper<'a> {
     pub fn new(
         gitextra_read_manager: &'b dyn GitExtraIngressHelper,
         gitextra_append_manager: &'c dyn GitExtraEgressHelper,
+        config: &'d CommitConfig,
+        strict: bool,
     ) -> Self {
         CommitPromptHelper {
             gitextra_read_manager,
             gitextra_append_manager,
+            config,
+            strict,
         }
     }
 
     pub fn ask_type(&self) -> Result<String, AnyError> {
-        let available_types = self.gitextra_read_manager.get_types()?;
-        let raw_select = Question::raw_select("commit_type")
+        let available_types =
+            self.available_entries(&self.config.types, self.gitextra_read_manager.get_types()?);
+        let can_create = !self.strict || self.config.types.is_empty();
+        let mut raw_select = Question::raw_select("commit_type")
             .message("Choose the commit type:")
-            .choices(&available_types)
-            .choice("Create new type")
-            .build();
+            .choices(self.entry_labels(&available_types));
+        if can_create {
+            raw_select = raw_select.choice("Create new type");
+        }
+        let raw_select = raw_select.build();
         let answer = prompt_one(raw_select);
         let answer_index = match answer {
             Ok(Answer::ListItem(a)) => a.index,
             Ok(_) => panic!("Obtained a non ListItem from a raw_select"),
             Err(e) => return Err(Box::new(e)),
         };
-        if answer_index == available_types.len() {
+        if can_create && answer_index == available_types.len() {
             self.ask_new_type()
         } else {
-            Ok(available_types[answer_index].clone())
+            Ok(available_types[answer_index].name.clone())
         }
     }
 
     pub fn ask_scope(&self) -> Result<Option<String>, AnyError> {
-        let available_scopes = self.gitextra_read_manager.get_scopes()?;
-        let raw_select = Question::raw_select("scope")
+        let available_scopes = self.available_entries(
+            &self.config.scopes,
+            self.gitextra_read_manager.get_scopes()?,
+        );
+        let can_create = !self.strict || self.config.scopes.is_empty();
+        let mut raw_select = Question::raw_select("scope")
             .message("Choose the scope:")
             .choice("None")
-            .choices(&available_scopes)
-            .choice("Create new scope")
-            .build();
+            .choices(self.entry_labels(&available_scopes));
+        if can_create {
+            raw_select = raw_select.choice("Create new scope");
+        }
+        let raw_select = raw_select.build();
         let answer = prompt_one(raw_select);
         let answer_index = match answer {
             Ok(Answer::ListItem(a)) => a.index,
             Ok(_) => panic!("Obtained a non ListItem from a raw_select"),
             Err(e) => return Err(Box::new(e)),
         };
-        Ok(if answer_index == available_scopes.len() + 1 {
-            Some(self.ask_new_scope()?)
-        } else if answer_index == 0 {
-            None
-        } else {
-            Some(available_scopes[answer_index - 1].clone())
-        })
+        Ok(
+            if can_create && answer_index == available_scopes.len() + 1 {
+                Some(self.ask_new_scope()?)
+            } else if answer_index == 0 {
+                None
+            } else {
+                Some(available_scopes[answer_index - 1].name.clone())
+            },
+        )
     }
 
     pub fn ask_breaking(&self) -> Result<bool, AnyError> {
@@ -115,6 +137,87 @@ impl<'a, 'b: 'a, 'c: 'a> CommitPromptHelper<'a> {
         }
     }
 
+    pub fn ask_breaking_description(&self) -> Result<Option<String>, AnyError> {
+        let answer = prompt_one(
+            Question::input("breaking_description")
+                .message("Describe the breaking change (leave empty to skip):")
+                .build(),
+        );
+        match answer {
+            Ok(Answer::String(s)) => Ok(if s.is_empty() { None } else { Some(s) }),
+            Ok(_) => panic!("Obtained a non String from an input"),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /**
+     * Asks footers one at a time, until an empty answer is given.
+     */
+    pub fn ask_footers(&self) -> Result<Vec<String>, AnyError> {
+        let mut footers = vec![];
+        loop {
+            let answer = prompt_one(
+                Question::input("footer")
+                    .message("Footer, e.g. 'Refs: #123' (leave empty to finish):")
+                    .validate(|s, _| {
+                        if s.is_empty() {
+                            Ok(())
+                        } else {
+                            CommitFooter::from_str(s)
+                                .map(|_| ())
+                                .map_err(|e| e.to_string())
+                        }
+                    })
+                    .build(),
+            );
+            match answer {
+                Ok(Answer::String(s)) if s.is_empty() => return Ok(footers),
+                Ok(Answer::String(s)) => footers.push(s),
+                Ok(_) => panic!("Obtained a non String from an input"),
+                Err(e) => return Err(Box::new(e)),
+            }
+        }
+    }
+
+    /**
+     * Declared entries come first, in the order of the configuration file, followed by the
+     * values stored in the git extra folder which are not declared.
+     * In strict mode, only the declared entries are available.
+     */
+    fn available_entries(
+        &self,
+        declared: &[CommitEntryConfig],
+        known: Vec<String>,
+    ) -> Vec<CommitEntryConfig> {
+        if self.strict && !declared.is_empty() {
+            return declared.to_vec();
+        }
+        declared
+            .iter()
+            .cloned()
+            .chain(
+                known
+                    .into_iter()
+                    .filter(|it| !declared.iter().any(|d| &d.name == it))
+                    .map(|it| CommitEntryConfig {
+                        name: it,
+                        description: None,
+                    }),
+            )
+            .collect()
+    }
+
+    fn entry_labels(&self, entries: &[CommitEntryConfig]) -> Vec<String> {
+        let width = entries.iter().map(|it| it.name.len()).max().unwrap_or(0);
+        entries
+            .iter()
+            .map(|it| match &it.description {
+                Some(d) => format!("{:width$}  {}", it.name, d, width = width),
+                None => it.name.clone(),
+            })
+            .collect()
+    }
+
     fn ask_new_type(&self) -> Result<String, AnyError> {
         let new_type = self.ask_new_input("type", ConventionalCommitSummary::type_pattern())?;
         self.gitextra_append_manager.append_type(&new_type)?;
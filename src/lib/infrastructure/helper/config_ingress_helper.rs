@@ -0,0 +1,5 @@
+use crate::{infrastructure::config::gitbox_config::GitboxConfig, usecase::type_aliases::AnyError};
+
+pub trait ConfigIngressHelper {
+    fn load_config(&self) -> Result<GitboxConfig, AnyError>;
+}
@@ -1,5 +1,8 @@
+pub mod config_file_manager_impl;
+pub mod file_reader;
 pub mod file_writer;
 pub mod git_cli;
+pub mod git_hook_manager_impl;
 pub mod gitextra_manager_impl;
 pub mod license_download_ingress_manager_impl;
 pub mod message_egress_manager_impl;
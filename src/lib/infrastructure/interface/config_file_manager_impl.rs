@@ -0,0 +1,34 @@
+use std::{fs::read_to_string, path::Path};
+
+use crate::{
+    application::manager::gitinfo_ingress_manager::GitInfoIngressManager,
+    infrastructure::{
+        config::gitbox_config::GitboxConfig, helper::config_ingress_helper::ConfigIngressHelper,
+    },
+    usecase::type_aliases::AnyError,
+};
+
+const CONFIG_FILE_PATH: &str = ".gitbox.toml";
+
+pub struct ConfigFileManagerImpl<'a> {
+    gitinfo_manager: &'a dyn GitInfoIngressManager,
+}
+
+impl<'b: 'a, 'a> ConfigFileManagerImpl<'a> {
+    pub fn new(gitinfo_manager: &'b dyn GitInfoIngressManager) -> Self {
+        ConfigFileManagerImpl { gitinfo_manager }
+    }
+}
+
+impl ConfigIngressHelper for ConfigFileManagerImpl<'_> {
+    fn load_config(&self) -> Result<GitboxConfig, AnyError> {
+        let path = Path::new(&self.gitinfo_manager.work_tree_dir()?).join(CONFIG_FILE_PATH);
+        match path.try_exists() {
+            Ok(true) => GitboxConfig::from_toml(&read_to_string(&path)?).map_err(|e| {
+                format!("invalid configuration file '{}': {}", path.display(), e).into()
+            }),
+            Ok(false) => Ok(GitboxConfig::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
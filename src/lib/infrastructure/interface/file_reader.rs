@@ -0,0 +1,40 @@
+use std::{fs::read_to_string, io::ErrorKind};
+
+use crate::{
+    application::manager::{
+        changelog_file_ingress_manager::ChangelogFileIngressManager,
+        changelog_template_ingress_manager::ChangelogTemplateIngressManager,
+        commit_message_ingress_manager::CommitMessageIngressManager,
+    },
+    usecase::type_aliases::AnyError,
+};
+
+pub struct FileReader {}
+
+impl FileReader {
+    pub fn new() -> Self {
+        FileReader {}
+    }
+}
+
+impl CommitMessageIngressManager for FileReader {
+    fn read_message(&self, path: &str) -> Result<String, AnyError> {
+        Ok(read_to_string(path)?)
+    }
+}
+
+impl ChangelogTemplateIngressManager for FileReader {
+    fn read_template(&self, path: &str) -> Result<String, AnyError> {
+        Ok(read_to_string(path)?)
+    }
+}
+
+impl ChangelogFileIngressManager for FileReader {
+    fn read_changelog(&self, path: &str) -> Result<Option<String>, AnyError> {
+        match read_to_string(path) {
+            Ok(text) => Ok(Some(text)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+}
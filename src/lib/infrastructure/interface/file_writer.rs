@@ -1,7 +1,10 @@
 use std::{fs::create_dir_all, io::Write, path::Path};
 
 use crate::{
-    application::manager::license_text_egress_manager::LicenseTextEgressManager,
+    application::manager::{
+        changelog_file_egress_manager::ChangelogFileEgressManager,
+        license_text_egress_manager::LicenseTextEgressManager,
+    },
     usecase::type_aliases::AnyError,
 };
 
@@ -28,3 +31,9 @@ impl LicenseTextEgressManager for FileWriter {
         Ok(())
     }
 }
+
+impl ChangelogFileEgressManager for FileWriter {
+    fn write_changelog(&self, path: &str, text: &str) -> Result<(), AnyError> {
+        Ok(std::fs::write(path, text)?)
+    }
+}
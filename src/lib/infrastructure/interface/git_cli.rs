@@ -1,10 +1,12 @@
-use std::{process::Command, rc::Rc, str::FromStr};
+use std::{process::Command, str::FromStr};
 
 use crate::{
     application::{
         manager::{
             bounded_commit_summary_ingress_manager::BoundedCommitSummaryIngressManager,
+            comment_char_ingress_manager::CommentCharIngressManager,
             commit_metadata_ingress_manager::CommitMetadataIngressManager,
+            commit_range_ingress_manager::CommitRangeIngressManager,
             full_commit_summary_history_ingress_manager::FullCommitSummaryHistoryIngressManager,
             gitinfo_ingress_manager::GitInfoIngressManager,
             version_ingress_manager::VersionIngressManager,
@@ -19,7 +21,7 @@ use crate::{
     infrastructure::error::{
         command_execution_error::CommandExecutionError, generic_cli_error::CliError,
     },
-    usecase::{metadata_spec::MetadataSpec, type_aliases::AnyError},
+    usecase::{metadata_spec::MetadataSpec, tag_format::TagFormat, type_aliases::AnyError},
 };
 
 pub struct GitCli {}
@@ -57,67 +59,142 @@ impl GitCli {
         }
     }
 
+    /**
+     * The versions of the tags reachable from HEAD. Tags which are not semantic versions (for
+     * example, created by other tools) are ignored.
+     */
+    fn merged_versions(&self, tag_format: &TagFormat) -> Result<Vec<SemanticVersion>, AnyError> {
+        let pattern = tag_format.pattern();
+        let output = self.run_git_command(
+            vec!["--no-pager", "tag", "--list", &pattern, "--merged"].into_iter(),
+        )?;
+        Ok(output
+            .lines()
+            .filter_map(|it| tag_format.version(it.trim()))
+            .filter_map(|it| SemanticVersion::from_str(it).ok())
+            .collect())
+    }
+
     fn split_and_clean_commits(&self, list: String) -> Vec<String> {
         list.split('\n')
             .filter(|it| !it.is_empty())
             .map(|it| it.to_string())
             .collect()
     }
+
+    fn split_and_clean_messages(&self, list: String) -> Vec<String> {
+        list.split('\0')
+            .map(|it| it.trim())
+            .filter(|it| !it.is_empty())
+            .map(|it| it.to_string())
+            .collect()
+    }
 }
 
 impl FullCommitSummaryHistoryIngressManager for GitCli {
     fn get_all_commits(&self) -> Result<Box<dyn DoubleEndedIterator<Item = String>>, AnyError> {
         let log_list =
-            self.run_git_command(vec!["log", "--pretty=format:%s", "--all"].into_iter())?;
-        Ok(Box::new(self.split_and_clean_commits(log_list).into_iter()))
+            self.run_git_command(vec!["log", "-z", "--pretty=format:%B", "--all"].into_iter())?;
+        Ok(Box::new(
+            self.split_and_clean_messages(log_list).into_iter(),
+        ))
     }
 }
 
 impl BoundedCommitSummaryIngressManager for GitCli {
     fn get_commits_from(
         &self,
-        version: Rc<Option<SemanticVersion>>,
-    ) -> Result<Box<dyn DoubleEndedIterator<Item = String>>, AnyError> {
-        let mut args = vec!["log", "--pretty=format:%s"];
-        let mut _s = String::new();
-        if let Some(value) = version.as_ref() {
-            _s = format!("^{}", value);
-            args.push(&_s);
-            args.push("HEAD");
+        from: Option<&str>,
+        to: Option<&str>,
+        paths: &[String],
+    ) -> Result<Box<dyn DoubleEndedIterator<Item = (String, String)>>, AnyError> {
+        let from = from.map(|it| format!("^{}", it));
+        // Paths are globs relative to the root of the repository, wherever gitbox is run from.
+        let pathspecs: Vec<String> = paths
+            .iter()
+            .map(|it| format!(":(top,glob){}", it))
+            .collect();
+        let mut args = vec!["log", "-z", "--pretty=format:%H%n%B"];
+        args.extend(from.as_deref());
+        args.push(to.unwrap_or("HEAD"));
+        if !pathspecs.is_empty() {
+            args.push("--");
+            args.extend(pathspecs.iter().map(String::as_str));
         }
         let log_list = self.run_git_command(args.into_iter())?;
-        Ok(Box::new(self.split_and_clean_commits(log_list).into_iter()))
+        Ok(Box::new(
+            self.split_and_clean_messages(log_list)
+                .into_iter()
+                .map(|it| match it.split_once('\n') {
+                    Some((hash, message)) => (hash.to_string(), message.trim().to_string()),
+                    None => (it, String::new()),
+                }),
+        ))
+    }
+}
+
+impl CommitRangeIngressManager for GitCli {
+    fn get_commits_in_range(
+        &self,
+        range: Option<&str>,
+    ) -> Result<Box<dyn Iterator<Item = (String, String)>>, AnyError> {
+        let args = vec![
+            "log",
+            "--no-merges",
+            "--pretty=format:%h %s",
+            range.unwrap_or("HEAD"),
+            "--",
+        ];
+        let log_list = self.run_git_command(args.into_iter())?;
+        Ok(Box::new(
+            self.split_and_clean_commits(log_list)
+                .into_iter()
+                .map(|it| match it.split_once(' ') {
+                    Some((hash, summary)) => (hash.to_string(), summary.to_string()),
+                    None => (it, String::new()),
+                }),
+        ))
     }
 }
 
 impl VersionIngressManager for GitCli {
-    fn last_version(&self) -> Result<Option<String>, AnyError> {
-        let output = self.run_git_command(vec!["describe", "--tags", "--abbrev=0"].into_iter());
-        match output {
-            Ok(v) => Ok(Some(SemanticVersion::from_str(v.trim())?.to_string())),
-            Err(e) if e.to_string().contains("No names found") => Ok(None),
-            Err(e) => Err(e),
-        }
+    fn last_version(&self, tag_format: &TagFormat) -> Result<Option<String>, AnyError> {
+        Ok(self
+            .merged_versions(tag_format)?
+            .into_iter()
+            .max()
+            .map(|it| it.to_string()))
     }
 
-    fn last_stable_version(&self) -> Result<Option<String>, AnyError> {
-        let output =
-            self.run_git_command(vec!["--no-pager", "tag", "--list", "--merged"].into_iter());
-        match output {
-            Ok(v) => {
-                if v.trim().is_empty() {
-                    Ok(None)
-                } else {
-                    Ok(v.trim()
-                        .split('\n')
-                        .filter_map(|it| SemanticVersion::from_str(it).ok())
-                        .filter(|it| it.prerelease().is_none())
-                        .max()
-                        .map(|it| it.to_string()))
-                }
-            }
-            Err(e) => Err(e),
-        }
+    fn last_stable_version(&self, tag_format: &TagFormat) -> Result<Option<String>, AnyError> {
+        Ok(self
+            .merged_versions(tag_format)?
+            .into_iter()
+            .filter(|it| it.prerelease().is_none())
+            .max()
+            .map(|it| it.to_string()))
+    }
+
+    fn all_versions(&self, tag_format: &TagFormat) -> Result<Vec<(String, String)>, AnyError> {
+        let pattern = tag_format.pattern();
+        let output = self.run_git_command(
+            vec![
+                "--no-pager",
+                "tag",
+                "--list",
+                &pattern,
+                "--format=%(refname:short)%09%(creatordate:short)",
+                "--merged",
+            ]
+            .into_iter(),
+        )?;
+        Ok(output
+            .lines()
+            .filter_map(|it| it.split_once('\t'))
+            .filter_map(|(tag, date)| tag_format.version(tag).map(|it| (it, date)))
+            .filter(|(version, _)| SemanticVersion::from_str(version).is_ok())
+            .map(|(version, date)| (version.to_owned(), date.to_owned()))
+            .collect())
     }
 }
 
@@ -125,6 +202,24 @@ impl GitInfoIngressManager for GitCli {
     fn git_dir(&self) -> Result<String, AnyError> {
         self.run_git_command(vec!["rev-parse", "--absolute-git-dir"].into_iter())
     }
+
+    fn work_tree_dir(&self) -> Result<String, AnyError> {
+        self.run_git_command(vec!["rev-parse", "--show-toplevel"].into_iter())
+    }
+
+    fn hooks_dir(&self) -> Result<String, AnyError> {
+        self.run_git_command(
+            vec!["rev-parse", "--path-format=absolute", "--git-path", "hooks"].into_iter(),
+        )
+    }
+}
+
+impl CommentCharIngressManager for GitCli {
+    fn comment_char(&self) -> Result<String, AnyError> {
+        self.run_git_command(
+            vec!["config", "--get", "--default", "#", "core.commentChar"].into_iter(),
+        )
+    }
 }
 
 impl ConventionalCommitEgressManager for GitCli {
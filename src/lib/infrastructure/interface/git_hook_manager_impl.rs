@@ -0,0 +1,103 @@
+use std::{
+    fs::{create_dir_all, read_to_string, remove_file},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    application::manager::gitinfo_ingress_manager::GitInfoIngressManager,
+    infrastructure::error::generic_cli_error::CliError, usecase::type_aliases::AnyError,
+};
+
+const COMMIT_MSG_HOOK_NAME: &str = "commit-msg";
+const HOOK_MARKER: &str = "# Installed by gitbox";
+/**
+ * The hook runs the executable which installed it, since git clients (e.g. IDEs) often run with
+ * a PATH which does not contain gb. If that executable has been moved, gb is looked up on the
+ * PATH instead.
+ */
+const COMMIT_MSG_HOOK_TEMPLATE: &str = r#"#!/bin/sh
+# Installed by gitbox: validate the commit message as 'gb commit' would.
+GB={executable}
+if [ ! -x "$GB" ]; then
+    GB=$(command -v gb) || {
+        echo "commit-msg hook: gitbox was not found at "{executable}" nor on the PATH, reinstall the hook with 'gb hook install'" >&2
+        exit 1
+    }
+fi
+exec "$GB" lint --message-file "$1"
+"#;
+
+pub enum HookStatus {
+    Installed,
+    NotInstalled,
+    Foreign,
+}
+
+pub struct GitHookManagerImpl<'a> {
+    gitinfo_manager: &'a dyn GitInfoIngressManager,
+}
+
+impl<'b: 'a, 'a> GitHookManagerImpl<'a> {
+    pub fn new(gitinfo_manager: &'b dyn GitInfoIngressManager) -> Self {
+        GitHookManagerImpl { gitinfo_manager }
+    }
+
+    pub fn hook_path(&self) -> Result<PathBuf, AnyError> {
+        Ok(Path::new(&self.gitinfo_manager.hooks_dir()?).join(COMMIT_MSG_HOOK_NAME))
+    }
+
+    pub fn status(&self) -> Result<HookStatus, AnyError> {
+        let path = self.hook_path()?;
+        if !path.try_exists()? {
+            Ok(HookStatus::NotInstalled)
+        } else if read_to_string(&path)?.contains(HOOK_MARKER) {
+            Ok(HookStatus::Installed)
+        } else {
+            Ok(HookStatus::Foreign)
+        }
+    }
+
+    pub fn install(&self, force: bool) -> Result<(), AnyError> {
+        if matches!(self.status()?, HookStatus::Foreign) && !force {
+            return Err(Box::new(CliError::new(
+                "a commit-msg hook not managed by gitbox already exists (use '--force' to overwrite it)",
+            )));
+        }
+        let path = self.hook_path()?;
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent)?;
+        }
+        let mut file = std::fs::File::options()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&path)?;
+        write!(&mut file, "{}", commit_msg_hook_content()?)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))?;
+        }
+        Ok(())
+    }
+
+    pub fn uninstall(&self) -> Result<(), AnyError> {
+        match self.status()? {
+            HookStatus::Installed => Ok(remove_file(self.hook_path()?)?),
+            HookStatus::NotInstalled => Ok(()),
+            HookStatus::Foreign => Err(Box::new(CliError::new(
+                "the commit-msg hook is not managed by gitbox, it will not be removed",
+            ))),
+        }
+    }
+}
+
+fn commit_msg_hook_content() -> Result<String, AnyError> {
+    let executable = std::env::current_exe()?;
+    let executable = executable.to_str().ok_or_else(|| {
+        CliError::new("the path of the gb executable is not valid UTF-8, the hook cannot run it")
+    })?;
+    let quoted = format!("'{}'", executable.replace('\'', r"'\''"));
+    Ok(COMMIT_MSG_HOOK_TEMPLATE.replace("{executable}", &quoted))
+}
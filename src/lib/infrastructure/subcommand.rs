@@ -1,3 +1,54 @@
 pub trait Subcommand {
     fn execute(&self) -> i32;
 }
+
+/**
+ * The value of a flag given with `--<flag>` or `--no-<flag>`, if any; when it is `None`, the
+ * value of the configuration file applies.
+ */
+pub fn flag_option(enabled: bool, disabled: bool) -> Option<bool> {
+    match (enabled, disabled) {
+        (false, false) => None,
+        (false, true) => Some(false),
+        (true, false) => Some(true),
+        (true, true) => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::flag_option;
+
+    #[derive(Parser, Debug)]
+    struct FlagCommand {
+        #[arg(long, overrides_with = "no_diff")]
+        diff: bool,
+        #[arg(long = "no-diff", overrides_with = "diff")]
+        no_diff: bool,
+    }
+
+    fn resolve(args: &[&str], config: bool) -> bool {
+        let command = FlagCommand::parse_from([&["test"], args].concat());
+        flag_option(command.diff, command.no_diff).unwrap_or(config)
+    }
+
+    #[test]
+    fn configuration_applies_without_flags() {
+        assert!(resolve(&[], true));
+        assert!(!resolve(&[], false));
+    }
+
+    #[test]
+    fn flags_take_precedence_over_configuration() {
+        assert!(!resolve(&["--no-diff"], true));
+        assert!(resolve(&["--diff"], false));
+    }
+
+    #[test]
+    fn last_flag_wins() {
+        assert!(!resolve(&["--diff", "--no-diff"], true));
+        assert!(resolve(&["--no-diff", "--diff"], false));
+    }
+}
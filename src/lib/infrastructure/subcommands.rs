@@ -2,17 +2,21 @@ use clap::Subcommand;
 
 use self::{
     changelog::ChangelogSubCommand, commit::CommitSubCommand, complete::CompleteSubCommand,
-    describe::DescribeSubCommand, grammar::GrammarSubCommand, init::InitSubCommand,
-    license::LicenseSubCommand, refresh_extra::RefreshExtraSubcommand, tree::TreeSubCommand,
+    config::ConfigSubCommand, describe::DescribeSubCommand, grammar::GrammarSubCommand,
+    hook::HookSubCommand, init::InitSubCommand, license::LicenseSubCommand, lint::LintSubCommand,
+    refresh_extra::RefreshExtraSubcommand, tree::TreeSubCommand,
 };
 
 mod changelog;
 mod commit;
 mod complete;
+mod config;
 mod describe;
 mod grammar;
+mod hook;
 mod init;
 mod license;
+mod lint;
 mod refresh_extra;
 mod tree;
 
@@ -21,9 +25,12 @@ pub enum Commands {
     Changelog(ChangelogSubCommand),
     Commit(CommitSubCommand),
     Complete(CompleteSubCommand),
+    Config(ConfigSubCommand),
     Describe(DescribeSubCommand),
+    Hook(HookSubCommand),
     Init(InitSubCommand),
     License(LicenseSubCommand),
+    Lint(LintSubCommand),
     RefreshExtra(RefreshExtraSubcommand),
     Tree(TreeSubCommand),
     // HIDDEN COMMANDS
@@ -1,16 +1,29 @@
-use clap::Args;
+use clap::{builder::PossibleValue, Args, ValueEnum};
 
 use crate::{
     application::{
         controller::{changelog::ChangelogController, exit_code::ControllerExitCode},
         manager::gitinfo_ingress_manager::GitInfoIngressManager,
         manager::message_egress_manager::MessageEgressManager,
-        options::changelog::{ChangelogFormatOptions, ChangelogOptions},
+        options::{
+            changelog::{
+                ChangelogContentOptions, ChangelogFormatOptions, ChangelogOptions,
+                ChangelogRangeOptions, GroupBy,
+            },
+            output_format::OutputFormat,
+        },
     },
     infrastructure::{
-        interface::{git_cli::GitCli, message_egress_manager_impl::MessageEgressManagerImpl},
-        subcommand::Subcommand,
+        config::changelog::{GeneralScopePosition, GroupByConfig},
+        helper::config_ingress_helper::ConfigIngressHelper,
+        interface::{
+            config_file_manager_impl::ConfigFileManagerImpl, file_reader::FileReader,
+            file_writer::FileWriter, git_cli::GitCli,
+            message_egress_manager_impl::MessageEgressManagerImpl,
+        },
+        subcommand::{flag_option, Subcommand},
     },
+    usecase::tag_format::VERSION_PLACEHOLDER,
 };
 
 #[derive(Args, Debug)]
@@ -18,63 +31,160 @@ use crate::{
 pub struct ChangelogSubCommand {
     #[arg(
         long,
-        help = "If set, the changelog will be generated with changes since the last version rather than the last stable release"
+        help = "If set, the changelog will be generated with changes since the last version rather than the last stable release",
+        overrides_with = "no_from_latest_version"
     )]
     from_latest_version: bool,
+    #[arg(
+        long = "no-from-latest-version",
+        help = "Generate the changelog since the last stable release, overriding the configuration file",
+        overrides_with = "from_latest_version"
+    )]
+    no_from_latest_version: bool,
+    #[arg(
+        long,
+        help = "Generate a section for each version, from the newest to the oldest",
+        conflicts_with_all = ["from_latest_version", "write"]
+    )]
+    all_versions: bool,
+    #[arg(
+        long,
+        help = "Start the changelog after the given version or git reference, instead of the last release",
+        conflicts_with_all = ["from_latest_version", "all_versions"]
+    )]
+    from: Option<String>,
+    #[arg(
+        long,
+        help = "End the changelog at the given version or git reference, instead of HEAD",
+        conflicts_with = "all_versions"
+    )]
+    to: Option<String>,
+    #[arg(
+        long = "path",
+        help = "Include only the commits which touch the given path, as a glob relative to the root of the repository (can be used multiple times)"
+    )]
+    paths: Vec<String>,
+    #[arg(
+        long,
+        help = "Set how versions are written in tag names. The version placeholder is '{version}' [default: '{version}']"
+    )]
+    tag_template: Option<String>,
     #[arg(
         short = 'T',
         long,
-        help = "Set the title format. The content placeholder is '%s'",
-        default_value("# %s"),
+        help = "Set the title format. The content placeholder is '%s' [default: '# %s']",
         allow_hyphen_values(true)
     )]
-    title_format: String,
+    title_format: Option<String>,
     #[arg(
         short = 't',
         long,
-        help = "Set the type format. The content placeholder is '%s'",
-        default_value("= %s"),
+        help = "Set the type format. The content placeholder is '%s' [default: '= %s']",
         allow_hyphen_values(true)
     )]
-    type_format: String,
+    type_format: Option<String>,
     #[arg(
         short = 's',
         long,
-        help = "Set the scope format. The content placeholder is '%s'",
-        default_value("- %s"),
+        help = "Set the scope format. The content placeholder is '%s' [default: '- %s']",
         allow_hyphen_values(true)
     )]
-    scope_format: String,
+    scope_format: Option<String>,
     #[arg(
         short = 'l',
         long,
-        help = "Set the list format. The content placeholder is '%s'",
-        default_value("%s"),
+        help = "Set the list format. The content placeholder is '%s' [default: '%s']",
         allow_hyphen_values(true)
     )]
-    list_format: String,
+    list_format: Option<String>,
     #[arg(
         short = 'i',
         long,
-        help = "Set the list item format. The content placeholder is '%s'",
-        default_value("* %s"),
+        help = "Set the list item format. The content placeholder is '%s' [default: '* %s']",
         allow_hyphen_values(true)
     )]
-    item_format: String,
+    item_format: Option<String>,
     #[arg(
         short = 'b',
         long,
-        help = "Set the breaking commit format. The content placeholder is '%s'",
-        default_value("!!! %s "),
+        help = "Set the breaking commit format. The content placeholder is '%s' [default: '!!! %s ']",
         allow_hyphen_values(true)
     )]
-    breaking_format: String,
+    breaking_format: Option<String>,
+    #[arg(
+        long,
+        help = "Append the abbreviated hash of the commit to each item with the given format. The content placeholder is '%s'",
+        allow_hyphen_values(true)
+    )]
+    hash_format: Option<String>,
 
     #[arg(
         long,
         help = "Set the trigger to use to exclude commits from the changelog. For more informations about the grammar, run 'help grammar'"
     )]
     exclude_trigger: Option<String>,
+
+    #[arg(
+        long,
+        help = "Set the URL of a commit, where '{hash}' is replaced by the hash of the commit. It requires a template or the JSON output"
+    )]
+    commit_url: Option<String>,
+
+    #[arg(
+        long,
+        help = "Set the URL of an issue, where '{issue}' is replaced by the number of an issue referenced as '#<number>' in the summary or in the footers. It requires a template or the JSON output"
+    )]
+    issue_url: Option<String>,
+
+    #[arg(
+        long,
+        help = "List the breaking changes in a section of their own at the top of the changelog",
+        overrides_with = "no_breaking_section"
+    )]
+    breaking_section: bool,
+    #[arg(
+        long = "no-breaking-section",
+        help = "Do not list the breaking changes in a section of their own, overriding the configuration file",
+        overrides_with = "breaking_section"
+    )]
+    no_breaking_section: bool,
+
+    #[arg(
+        long,
+        help = "Group the commits by type and then by scope, by scope and then by type, or not at all [default: type]",
+        value_parser = clap::builder::EnumValueParser::<GroupBy>::new()
+    )]
+    group_by: Option<GroupBy>,
+
+    #[arg(
+        long,
+        help = "Render the changelog with a template instead of the format options. It can be a built-in template (markdown, asciidoc, html) or the path of a template file. The template of the configuration file is ignored when a format option is given",
+        conflicts_with_all = ["title_format", "type_format", "scope_format", "list_format", "item_format", "breaking_format", "hash_format"]
+    )]
+    template: Option<String>,
+
+    #[arg(
+        long,
+        help = "Title the changelog with the version the changes are released with"
+    )]
+    version: Option<String>,
+
+    #[arg(
+        long,
+        help = "Write the changelog as the section of the version at the top of the given file, replacing the existing section of the same version",
+        requires = "version",
+        conflicts_with = "output"
+    )]
+    write: Option<String>,
+
+    #[arg(
+        short,
+        long,
+        help = "Set the output format; the format options are ignored when the output is 'json'",
+        default_value = "text",
+        value_parser = clap::builder::EnumValueParser::<OutputFormat>::new()
+    )]
+    output: OutputFormat,
 }
 
 impl Subcommand for ChangelogSubCommand {
@@ -86,22 +196,93 @@ impl Subcommand for ChangelogSubCommand {
             output_manager.error("changelog subcommand cannot be called outside of a git dir");
             return 1;
         }
+        let (config, tag_template) = match ConfigFileManagerImpl::new(&git_cli).load_config() {
+            Ok(c) => (c.changelog, c.tag_template),
+            Err(e) => {
+                output_manager.error(&format!("Failed to load configuration: {}", e));
+                return 1;
+            }
+        };
+        // The format options on the command line take precedence over the template of the
+        // configuration file
+        let template = self.template.clone().or(if self.has_format_options() {
+            None
+        } else {
+            config.template
+        });
+        // The links are rendered only by templates and in the JSON output
+        let links_rendered = template.is_some() || self.output == OutputFormat::Json;
+        if (self.commit_url.is_some() || self.issue_url.is_some()) && !links_rendered {
+            output_manager.error(
+                "--commit-url and --issue-url require a template or the JSON output (--output json)",
+            );
+            return 1;
+        }
+        if (config.commit_url.is_some() || config.issue_url.is_some()) && !links_rendered {
+            output_manager.error(
+                "warning: the commit-url and issue-url of the configuration file are ignored without a template or the JSON output (--output json)",
+            );
+        }
         match ChangelogFormatOptions::new(
-            self.title_format.clone(),
-            self.type_format.clone(),
-            self.scope_format.clone(),
-            self.list_format.clone(),
-            self.item_format.clone(),
-            self.breaking_format.clone(),
+            self.title_format.clone().unwrap_or(config.title_format),
+            self.type_format.clone().unwrap_or(config.type_format),
+            self.scope_format.clone().unwrap_or(config.scope_format),
+            self.list_format.clone().unwrap_or(config.list_format),
+            self.item_format.clone().unwrap_or(config.item_format),
+            self.breaking_format
+                .clone()
+                .unwrap_or(config.breaking_format),
+            self.hash_format.clone().or(config.hash_format),
         ) {
             Ok(format_options) => {
                 let options = ChangelogOptions::new(
-                    self.from_latest_version,
+                    ChangelogRangeOptions::new(
+                        flag_option(self.from_latest_version, self.no_from_latest_version)
+                            .unwrap_or(config.from_latest_version),
+                        self.all_versions,
+                        self.from.clone(),
+                        self.to.clone(),
+                        self.paths.clone(),
+                        self.tag_template
+                            .clone()
+                            .or(tag_template)
+                            .unwrap_or(VERSION_PLACEHOLDER.to_owned()),
+                    ),
                     format_options,
-                    self.exclude_trigger.clone(),
+                    ChangelogContentOptions::new(
+                        self.exclude_trigger.clone().or(config.exclude_trigger),
+                        config
+                            .types
+                            .into_iter()
+                            .map(|it| (it.name, it.title))
+                            .collect(),
+                        config.general_scope == GeneralScopePosition::Last,
+                        self.commit_url.clone().or(config.commit_url),
+                        self.issue_url.clone().or(config.issue_url),
+                        flag_option(self.breaking_section, self.no_breaking_section)
+                            .unwrap_or(config.breaking_section),
+                        self.group_by.unwrap_or(match config.group_by {
+                            GroupByConfig::Type => GroupBy::Type,
+                            GroupByConfig::Scope => GroupBy::Scope,
+                            GroupByConfig::None => GroupBy::None,
+                        }),
+                    ),
+                    template,
+                    self.version.clone(),
+                    self.write.clone(),
+                    self.output,
+                );
+                let file_reader = FileReader::new();
+                let file_writer = FileWriter::new();
+                let controller = ChangelogController::new(
+                    options,
+                    &git_cli,
+                    &git_cli,
+                    &file_reader,
+                    &file_reader,
+                    &file_writer,
+                    &output_manager,
                 );
-                let controller =
-                    ChangelogController::new(options, &git_cli, &git_cli, &output_manager);
                 match controller.changelog() {
                     ControllerExitCode::Ok => 0,
                     ControllerExitCode::Error(i) => i,
@@ -114,3 +295,33 @@ impl Subcommand for ChangelogSubCommand {
         }
     }
 }
+
+impl ChangelogSubCommand {
+    fn has_format_options(&self) -> bool {
+        [
+            &self.title_format,
+            &self.type_format,
+            &self.scope_format,
+            &self.list_format,
+            &self.item_format,
+            &self.breaking_format,
+            &self.hash_format,
+        ]
+        .iter()
+        .any(|it| it.is_some())
+    }
+}
+
+impl ValueEnum for GroupBy {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::Type, Self::Scope, Self::None]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        match self {
+            Self::Type => Some(PossibleValue::new("type")),
+            Self::Scope => Some(PossibleValue::new("scope")),
+            Self::None => Some(PossibleValue::new("none")),
+        }
+    }
+}
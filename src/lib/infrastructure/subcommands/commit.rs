@@ -7,15 +7,19 @@ use crate::{
             gitinfo_ingress_manager::GitInfoIngressManager,
             message_egress_manager::MessageEgressManager,
         },
-        options::commit::CommitOptions,
+        options::commit::{CommitFooterOptions, CommitOptions, CommitPolicyOptions},
     },
     infrastructure::{
-        helper::commit_prompt_helper::CommitPromptHelper,
+        config::commit::CommitConfig,
+        helper::{
+            commit_prompt_helper::CommitPromptHelper, config_ingress_helper::ConfigIngressHelper,
+        },
         interface::{
-            git_cli::GitCli, gitextra_manager_impl::GitExtraManagerImpl,
+            config_file_manager_impl::ConfigFileManagerImpl, git_cli::GitCli,
+            gitextra_manager_impl::GitExtraManagerImpl,
             message_egress_manager_impl::MessageEgressManagerImpl,
         },
-        subcommand::Subcommand,
+        subcommand::{flag_option, Subcommand},
     },
     usecase::type_aliases::AnyError,
 };
@@ -48,8 +52,53 @@ pub struct CommitSubCommand {
     #[arg(short = 'm', long, help = "Set the body of the commit")]
     message: Option<String>,
 
-    #[arg(short, long, help = "Suppress the print of the complete message")]
+    #[arg(
+        long = "footer",
+        help = "Add a footer to the commit, in the form 'token: value' or 'token #value' (can be repeated)"
+    )]
+    footers: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Describe the breaking change; it implies --breaking",
+        conflicts_with = "is_not_breaking",
+        value_parser = NonEmptyStringValueParser::new()
+    )]
+    breaking_description: Option<String>,
+
+    #[arg(
+        long = "no-footers",
+        help = "Do not ask for footers when asking for the body",
+        conflicts_with = "footers"
+    )]
+    no_footers: bool,
+
+    #[arg(
+        short,
+        long,
+        help = "Suppress the print of the complete message",
+        overrides_with = "no_quiet"
+    )]
     quiet: bool,
+    #[arg(
+        long = "no-quiet",
+        help = "Print the complete message, overriding the configuration file",
+        overrides_with = "quiet"
+    )]
+    no_quiet: bool,
+
+    #[arg(
+        long,
+        help = "Only allow the types and scopes declared in the configuration file",
+        overrides_with = "no_strict"
+    )]
+    strict: bool,
+    #[arg(
+        long = "no-strict",
+        help = "Allow any type and scope, overriding the configuration file",
+        overrides_with = "strict"
+    )]
+    no_strict: bool,
 }
 
 impl Subcommand for CommitSubCommand {
@@ -61,9 +110,21 @@ impl Subcommand for CommitSubCommand {
             output_manager.error("commit subcommand can only be run inside a git project.");
             return 1;
         }
+        let config = match ConfigFileManagerImpl::new(&git_cli).load_config() {
+            Ok(c) => c.commit,
+            Err(e) => {
+                output_manager.error(&format!("Failed to load configuration: {}", e));
+                return 1;
+            }
+        };
         let gitextra_manager = GitExtraManagerImpl::new(&git_cli);
-        let prompt_manager = CommitPromptHelper::new(&gitextra_manager, &gitextra_manager);
-        let options = match self.ask_missing_fields(prompt_manager) {
+        let prompt_manager = CommitPromptHelper::new(
+            &gitextra_manager,
+            &gitextra_manager,
+            &config,
+            self.is_strict(&config),
+        );
+        let options = match self.ask_missing_fields(prompt_manager, &config) {
             Ok(o) => o,
             Err(e) => {
                 output_manager.error(&e.to_string());
@@ -80,17 +141,17 @@ impl Subcommand for CommitSubCommand {
 
 impl CommitSubCommand {
     fn breaking_option(&self) -> Option<bool> {
-        match (self.is_breaking, self.is_not_breaking) {
-            (false, false) => None,
-            (false, true) => Some(false),
-            (true, false) => Some(true),
-            (true, true) => unreachable!(),
-        }
+        flag_option(self.is_breaking, self.is_not_breaking)
+    }
+
+    fn is_strict(&self, config: &CommitConfig) -> bool {
+        flag_option(self.strict, self.no_strict).unwrap_or(config.strict)
     }
 
     fn ask_missing_fields(
         &self,
         prompt_manager: CommitPromptHelper,
+        config: &CommitConfig,
     ) -> Result<CommitOptions, AnyError> {
         let temp_type = match self.commit_type.clone() {
             Some(t) => t,
@@ -102,6 +163,7 @@ impl CommitSubCommand {
             None => prompt_manager.ask_scope()?,
         };
         let temp_breaking = match self.breaking_option() {
+            _ if self.breaking_description.is_some() => true,
             Some(b) => b,
             None => prompt_manager.ask_breaking()?,
         };
@@ -114,13 +176,31 @@ impl CommitSubCommand {
             Some(_) => None,
             None => prompt_manager.ask_body()?,
         };
+        // Footers are asked together with the body, so a fully specified commit is never prompted
+        let ask_footers = self.message.is_none() && self.footers.is_empty() && !self.no_footers;
+        let temp_breaking_description = match self.breaking_description.clone() {
+            Some(d) => Some(d),
+            None if temp_breaking && ask_footers => prompt_manager.ask_breaking_description()?,
+            None => None,
+        };
+        let temp_footers = if ask_footers {
+            prompt_manager.ask_footers()?
+        } else {
+            self.footers.clone()
+        };
         CommitOptions::new(
             temp_type,
             temp_scope,
             temp_breaking,
             temp_summary,
             temp_message,
-            self.quiet,
+            CommitFooterOptions::new(temp_footers, temp_breaking_description),
+            flag_option(self.quiet, self.no_quiet).unwrap_or(config.quiet),
+            if self.is_strict(config) {
+                CommitPolicyOptions::new(config.declared_types(), config.declared_scopes())
+            } else {
+                CommitPolicyOptions::new(None, None)
+            },
         )
     }
 }
@@ -0,0 +1,60 @@
+use clap::{Args, Subcommand as ClapSubcommand};
+
+use crate::{
+    application::manager::{
+        gitinfo_ingress_manager::GitInfoIngressManager,
+        message_egress_manager::MessageEgressManager,
+    },
+    infrastructure::{
+        helper::config_ingress_helper::ConfigIngressHelper,
+        interface::{
+            config_file_manager_impl::ConfigFileManagerImpl, git_cli::GitCli,
+            message_egress_manager_impl::MessageEgressManagerImpl,
+        },
+        subcommand::Subcommand,
+    },
+};
+
+#[derive(Args, Debug)]
+#[command(about = "Inspect the repository configuration file")]
+pub struct ConfigSubCommand {
+    #[command(subcommand)]
+    action: ConfigAction,
+}
+
+#[derive(ClapSubcommand, Debug)]
+enum ConfigAction {
+    #[command(
+        about = "Print the configuration file merged with the default values of every option"
+    )]
+    Show,
+}
+
+impl Subcommand for ConfigSubCommand {
+    fn execute(&self) -> i32 {
+        let git_cli = GitCli::new();
+        let output_manager = MessageEgressManagerImpl::new();
+        if let Err(e) = git_cli.git_dir() {
+            output_manager.error(&format!("Failed to retrieve git dir: {}", e));
+            output_manager.error("config subcommand can only be run inside a git project");
+            return 1;
+        }
+        match self.action {
+            ConfigAction::Show => {
+                match ConfigFileManagerImpl::new(&git_cli)
+                    .load_config()
+                    .and_then(|it| it.to_toml())
+                {
+                    Ok(s) => {
+                        output_manager.output(s.trim_end());
+                        0
+                    }
+                    Err(e) => {
+                        output_manager.error(&format!("Failed to load configuration: {}", e));
+                        1
+                    }
+                }
+            }
+        }
+    }
+}
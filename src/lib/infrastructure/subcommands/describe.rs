@@ -7,16 +7,27 @@ use crate::{
             gitinfo_ingress_manager::GitInfoIngressManager,
             message_egress_manager::MessageEgressManager,
         },
-        options::describe::{
-            DescribeMetadataOptions, DescribeOptions, DescribePrereleaseOptions,
-            DescribeTagOptions, DescribeTriggerOptions,
+        options::{
+            describe::{
+                Bump, DescribeMetadataOptions, DescribeOptions, DescribeOutputOptions,
+                DescribePackageOptions, DescribePrereleaseOptions, DescribeTagOptions,
+                DescribeTriggerOptions, DescribeVersionOptions, PRERELEASE_NUM_PLACEHOLDER,
+            },
+            output_format::OutputFormat,
         },
     },
     infrastructure::{
-        interface::{git_cli::GitCli, message_egress_manager_impl::MessageEgressManagerImpl},
-        subcommand::Subcommand,
+        helper::config_ingress_helper::ConfigIngressHelper,
+        interface::{
+            config_file_manager_impl::ConfigFileManagerImpl, git_cli::GitCli,
+            message_egress_manager_impl::MessageEgressManagerImpl,
+        },
+        subcommand::{flag_option, Subcommand},
+    },
+    usecase::{
+        metadata_spec::MetadataSpec,
+        tag_format::{DEFAULT_PACKAGE_TEMPLATE, VERSION_PLACEHOLDER},
     },
-    usecase::metadata_spec::MetadataSpec,
 };
 
 #[derive(Args, Debug)]
@@ -27,24 +38,37 @@ pub struct DescribeSubCommand {
 
     #[arg(
         long,
-        help = "Set the pattern for the new prerelease. A number can be used with the placeholder '%d'",
-        requires("prerelease"),
-        default_value = "%d"
+        help = "Set the pattern for the new prerelease. A number can be used with the placeholder '%d' [default: '%d']",
+        requires("prerelease")
     )]
-    prerelease_pattern: String,
+    prerelease_pattern: Option<String>,
     #[arg(
         long,
         help = "Set the pattern of the old prerelease. Uses the same placeholder as '--prerelease-pattern'. Use this option when changing prerelease pattern. Defaults to the prerelease pattern",
         requires("prerelease")
     )]
     old_prerelease_pattern: Option<String>,
+    #[arg(
+        long,
+        help = "Describe a prerelease of the given channel, e.g. 'beta' for '1.2.0-beta.1'. The numbering restarts when the channel changes",
+        requires("prerelease"),
+        conflicts_with_all(["prerelease_pattern", "old_prerelease_pattern"])
+    )]
+    channel: Option<String>,
 
     #[arg(
         short,
         long,
-        help = "Print the last version (if possible) in addition to the new version"
+        help = "Print the last version (if possible) in addition to the new version",
+        overrides_with = "no_diff"
     )]
     diff: bool,
+    #[arg(
+        long = "no-diff",
+        help = "Do not print the last version, overriding the configuration file",
+        overrides_with = "diff"
+    )]
+    no_diff: bool,
 
     #[arg(short, long, help = "Add a metadata to include in the new version (can be used multiple times)", value_parser = clap::builder::EnumValueParser::<MetadataSpec>::new())]
     metadata: Vec<MetadataSpec>,
@@ -79,9 +103,85 @@ pub struct DescribeSubCommand {
         short = 's',
         long,
         help = "If set, the created tag is signed",
-        requires("create_tag")
+        requires("create_tag"),
+        overrides_with = "no_sign_tag"
     )]
     sign_tag: bool,
+    #[arg(
+        long = "no-sign-tag",
+        help = "Do not sign the created tag, overriding the configuration file",
+        overrides_with = "sign_tag"
+    )]
+    no_sign_tag: bool,
+    #[arg(
+        long,
+        help = "Set how versions are written in tag names, both when reading and when creating tags. The version placeholder is '{version}'. The tags of a package are prefixed with '<package>/' [default: '{version}', 'v{version}' with --package]"
+    )]
+    tag_template: Option<String>,
+
+    #[arg(
+        long,
+        help = "Describe the version of a package of the repository, tagged as '<package>/v<version>' unless a tag template is set. Only the commits with the package as scope or which touch its paths are considered"
+    )]
+    package: Option<String>,
+
+    #[arg(
+        long = "path",
+        help = "Consider only the commits which touch the given path, as a glob relative to the root of the repository (can be used multiple times)"
+    )]
+    paths: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Force at least the given change, whatever the commits are",
+        value_parser = clap::builder::EnumValueParser::<Bump>::new()
+    )]
+    bump: Option<Bump>,
+    #[arg(
+        long,
+        help = "Set the new version instead of calculating it. It must come after the last stable version",
+        conflicts_with_all(["bump", "prerelease", "metadata"])
+    )]
+    set_version: Option<String>,
+    #[arg(
+        long,
+        help = "While the major version is 0, bump the minor for breaking changes and the patch for features",
+        overrides_with = "no_initial_development"
+    )]
+    initial_development: bool,
+    #[arg(
+        long = "no-initial-development",
+        help = "Bump the major for breaking changes even while the major version is 0, overriding the configuration file",
+        overrides_with = "initial_development"
+    )]
+    no_initial_development: bool,
+    #[arg(
+        long,
+        help = "Release the first major version (1.0.0), ending the initial development",
+        conflicts_with_all(["bump", "set_version"])
+    )]
+    graduate: bool,
+    #[arg(
+        long,
+        help = "Release the stable version of the last prerelease, e.g. 1.2.0 from 1.2.0-rc.2",
+        conflicts_with_all(["bump", "set_version", "graduate", "prerelease"])
+    )]
+    promote: bool,
+
+    #[arg(
+        long,
+        help = "Print every commit since the last version, with the change it triggers and the matching trigger"
+    )]
+    explain: bool,
+
+    #[arg(
+        short,
+        long,
+        help = "Set the output format",
+        default_value = "text",
+        value_parser = clap::builder::EnumValueParser::<OutputFormat>::new()
+    )]
+    output: OutputFormat,
 }
 
 impl Subcommand for DescribeSubCommand {
@@ -93,27 +193,86 @@ impl Subcommand for DescribeSubCommand {
             output_manager.error("describe subcommand can only be run inside a git project");
             return 1;
         }
+        let (config, tag_template) = match ConfigFileManagerImpl::new(&git_cli).load_config() {
+            Ok(c) => (c.describe, c.tag_template),
+            Err(e) => {
+                output_manager.error(&format!("Failed to load configuration: {}", e));
+                return 1;
+            }
+        };
+        let metadata = if self.metadata.is_empty() {
+            match config
+                .metadata
+                .iter()
+                .map(|it| MetadataSpec::from_str(it, true))
+                .collect::<Result<Vec<MetadataSpec>, String>>()
+            {
+                Ok(m) => m,
+                Err(e) => {
+                    output_manager.error(&format!("Failed to load configuration: {}", e));
+                    return 1;
+                }
+            }
+        } else {
+            self.metadata.clone()
+        };
+        let prerelease_pattern = match &self.channel {
+            Some(channel) => format!("{}.{}", channel, PRERELEASE_NUM_PLACEHOLDER),
+            None => self
+                .prerelease_pattern
+                .clone()
+                .unwrap_or(config.prerelease_pattern),
+        };
         match DescribePrereleaseOptions::new(
             self.prerelease,
-            self.prerelease_pattern.clone(),
+            prerelease_pattern.clone(),
             self.old_prerelease_pattern
                 .clone()
-                .unwrap_or(self.prerelease_pattern.clone()),
+                .unwrap_or(prerelease_pattern),
         ) {
             Ok(prerelease_options) => {
                 let options = DescribeOptions::new(
                     prerelease_options,
-                    self.diff,
-                    DescribeMetadataOptions::new(self.metadata.clone()),
+                    DescribeMetadataOptions::new(metadata),
                     DescribeTriggerOptions::new(
-                        self.major_trigger.clone(),
-                        self.minor_trigger.clone(),
-                        self.patch_trigger.clone(),
+                        self.major_trigger.clone().or(config.major_trigger),
+                        self.minor_trigger.clone().or(config.minor_trigger),
+                        self.patch_trigger.clone().or(config.patch_trigger),
                     ),
                     DescribeTagOptions::new(
                         self.create_tag,
                         self.tag_message.clone(),
-                        self.sign_tag,
+                        flag_option(self.sign_tag, self.no_sign_tag).unwrap_or(config.sign_tag),
+                        self.tag_template
+                            .clone()
+                            .or(tag_template)
+                            .unwrap_or_else(|| match self.package {
+                                Some(_) => DEFAULT_PACKAGE_TEMPLATE.to_owned(),
+                                None => VERSION_PLACEHOLDER.to_owned(),
+                            }),
+                    ),
+                    self.package.as_ref().map(|package| {
+                        // An undeclared package is expected to live in the directory with its name
+                        let paths = config
+                            .packages
+                            .into_iter()
+                            .find(|it| &it.name == package)
+                            .map_or_else(|| vec![package.clone()], |it| it.paths);
+                        DescribePackageOptions::new(package.clone(), paths)
+                    }),
+                    self.paths.clone(),
+                    DescribeVersionOptions::new(
+                        self.bump,
+                        self.set_version.clone(),
+                        flag_option(self.initial_development, self.no_initial_development)
+                            .unwrap_or(config.initial_development),
+                        self.graduate,
+                        self.promote,
+                    ),
+                    DescribeOutputOptions::new(
+                        flag_option(self.diff, self.no_diff).unwrap_or(config.diff),
+                        self.explain,
+                        self.output,
                     ),
                 );
                 let controller = DescribeController::new(
@@ -149,3 +308,30 @@ impl ValueEnum for MetadataSpec {
         }
     }
 }
+
+impl ValueEnum for OutputFormat {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::Text, Self::Json]
+    }
+
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        match self {
+            Self::Text => Some(PossibleValue::new("text")),
+            Self::Json => Some(PossibleValue::new("json")),
+        }
+    }
+}
+
+impl ValueEnum for Bump {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::Major, Self::Minor, Self::Patch]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        match self {
+            Self::Major => Some(PossibleValue::new("major")),
+            Self::Minor => Some(PossibleValue::new("minor")),
+            Self::Patch => Some(PossibleValue::new("patch")),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use clap::{Args, Subcommand as ClapSubcommand};
+
+use crate::{
+    application::manager::{
+        gitinfo_ingress_manager::GitInfoIngressManager,
+        message_egress_manager::MessageEgressManager,
+    },
+    infrastructure::{
+        interface::{
+            git_cli::GitCli,
+            git_hook_manager_impl::{GitHookManagerImpl, HookStatus},
+            message_egress_manager_impl::MessageEgressManagerImpl,
+        },
+        subcommand::Subcommand,
+    },
+    usecase::type_aliases::AnyError,
+};
+
+#[derive(Args, Debug)]
+#[command(about = "Manage the commit-msg hook which validates commit messages")]
+pub struct HookSubCommand {
+    #[command(subcommand)]
+    action: HookAction,
+}
+
+#[derive(ClapSubcommand, Debug)]
+enum HookAction {
+    #[command(about = "Install the commit-msg hook")]
+    Install {
+        #[arg(long, help = "Overwrite a commit-msg hook not installed by gitbox")]
+        force: bool,
+    },
+    #[command(about = "Remove the commit-msg hook")]
+    Uninstall,
+    #[command(about = "Print whether the commit-msg hook is installed")]
+    Status,
+}
+
+impl Subcommand for HookSubCommand {
+    fn execute(&self) -> i32 {
+        let git_cli = GitCli::new();
+        let output_manager = MessageEgressManagerImpl::new();
+        if let Err(e) = git_cli.git_dir() {
+            output_manager.error(&format!("Failed to retrieve git dir: {}", e));
+            output_manager.error("hook subcommand can only be run inside a git project");
+            return 1;
+        }
+        let hook_manager = GitHookManagerImpl::new(&git_cli);
+        match self.run_action(&hook_manager) {
+            Ok(message) => {
+                output_manager.output(&message);
+                0
+            }
+            Err(e) => {
+                output_manager.error(&format!("Failed to manage the commit-msg hook: {}", e));
+                1
+            }
+        }
+    }
+}
+
+impl HookSubCommand {
+    fn run_action(&self, hook_manager: &GitHookManagerImpl) -> Result<String, AnyError> {
+        let path = hook_manager.hook_path()?;
+        match self.action {
+            HookAction::Install { force } => {
+                hook_manager.install(force)?;
+                Ok(format!("commit-msg hook installed in '{}'", path.display()))
+            }
+            HookAction::Uninstall => {
+                hook_manager.uninstall()?;
+                Ok("commit-msg hook removed".to_string())
+            }
+            HookAction::Status => Ok(match hook_manager.status()? {
+                HookStatus::Installed => format!("installed ('{}')", path.display()),
+                HookStatus::NotInstalled => "not installed".to_string(),
+                HookStatus::Foreign => {
+                    format!(
+                        "a commit-msg hook not managed by gitbox is present ('{}')",
+                        path.display()
+                    )
+                }
+            }),
+        }
+    }
+}
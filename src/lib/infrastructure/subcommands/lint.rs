@@ -0,0 +1,85 @@
+use clap::Args;
+
+use crate::{
+    application::{
+        controller::{exit_code::ControllerExitCode, lint::LintController},
+        manager::{
+            gitinfo_ingress_manager::GitInfoIngressManager,
+            message_egress_manager::MessageEgressManager,
+        },
+        options::{commit::CommitPolicyOptions, lint::LintOptions},
+    },
+    infrastructure::{
+        helper::config_ingress_helper::ConfigIngressHelper,
+        interface::{
+            config_file_manager_impl::ConfigFileManagerImpl, file_reader::FileReader,
+            git_cli::GitCli, message_egress_manager_impl::MessageEgressManagerImpl,
+        },
+        subcommand::{flag_option, Subcommand},
+    },
+};
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Check that commits follow the conventional commit specification",
+    after_help = "Commits whose type or scope is not among the ones declared in the configuration file are reported too; in a message file, only in strict mode. Merge commits are ignored."
+)]
+pub struct LintSubCommand {
+    #[arg(
+        help = "The revision range to check (e.g. 'origin/main..HEAD'). Defaults to the whole history of HEAD"
+    )]
+    range: Option<String>,
+
+    #[arg(
+        long,
+        help = "Check the commit message in the given file instead of existing commits (used by the commit-msg hook)",
+        conflicts_with = "range"
+    )]
+    message_file: Option<String>,
+
+    #[arg(
+        long,
+        help = "Only allow the types and scopes declared in the configuration file in the message file, as 'gb commit --strict'",
+        requires = "message_file",
+        overrides_with = "no_strict"
+    )]
+    strict: bool,
+    #[arg(
+        long = "no-strict",
+        help = "Allow any type and scope in the message file, overriding the configuration file",
+        overrides_with = "strict"
+    )]
+    no_strict: bool,
+}
+
+impl Subcommand for LintSubCommand {
+    fn execute(&self) -> i32 {
+        let git_cli = GitCli::new();
+        let output_manager = MessageEgressManagerImpl::new();
+        if let Err(e) = git_cli.git_dir() {
+            output_manager.error(&format!("Failed to retrieve git dir: {}", e));
+            output_manager.error("lint subcommand can only be run inside a git project");
+            return 1;
+        }
+        let config = match ConfigFileManagerImpl::new(&git_cli).load_config() {
+            Ok(c) => c.commit,
+            Err(e) => {
+                output_manager.error(&format!("Failed to load configuration: {}", e));
+                return 1;
+            }
+        };
+        let options = LintOptions::new(
+            self.range.clone(),
+            self.message_file.clone(),
+            flag_option(self.strict, self.no_strict).unwrap_or(config.strict),
+            CommitPolicyOptions::new(config.declared_types(), config.declared_scopes()),
+        );
+        let file_reader = FileReader::new();
+        let controller =
+            LintController::new(options, &git_cli, &file_reader, &git_cli, &output_manager);
+        match controller.lint() {
+            ControllerExitCode::Ok => 0,
+            ControllerExitCode::Error(i) => i,
+        }
+    }
+}
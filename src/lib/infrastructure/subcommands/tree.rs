@@ -1,10 +1,20 @@
 use clap::Args;
 
 use crate::{
-    application::controller::{exit_code::ControllerExitCode, tree::TreeController},
+    application::{
+        controller::{exit_code::ControllerExitCode, tree::TreeController},
+        manager::{
+            gitinfo_ingress_manager::GitInfoIngressManager,
+            message_egress_manager::MessageEgressManager,
+        },
+    },
     infrastructure::{
-        interface::{git_cli::GitCli, message_egress_manager_impl::MessageEgressManagerImpl},
-        subcommand::Subcommand,
+        helper::config_ingress_helper::ConfigIngressHelper,
+        interface::{
+            config_file_manager_impl::ConfigFileManagerImpl, git_cli::GitCli,
+            message_egress_manager_impl::MessageEgressManagerImpl,
+        },
+        subcommand::{flag_option, Subcommand},
     },
 };
 
@@ -14,20 +24,44 @@ pub struct TreeSubCommand {
     #[arg(
         long,
         default_value = "false",
-        help = "Set whether to use the default color behaviour with pipes and redirections"
+        help = "Set whether to use the default color behaviour with pipes and redirections",
+        overrides_with = "no_use_default_color_behaviour"
     )]
     use_default_color_behaviour: bool,
+    #[arg(
+        long = "no-use-default-color-behaviour",
+        help = "Always use colors, overriding the configuration file",
+        overrides_with = "use_default_color_behaviour"
+    )]
+    no_use_default_color_behaviour: bool,
 }
 
 impl Subcommand for TreeSubCommand {
     fn execute(&self) -> i32 {
-        if self.use_default_color_behaviour {
+        let git_cli = GitCli::new();
+        let message_egress_manager = MessageEgressManagerImpl::new();
+        if let Err(e) = git_cli.git_dir() {
+            message_egress_manager.error(&format!("Failed to retrieve git dir: {}", e));
+            message_egress_manager.error("tree subcommand can only be run inside a git project");
+            return 1;
+        }
+        let config = match ConfigFileManagerImpl::new(&git_cli).load_config() {
+            Ok(c) => c.tree,
+            Err(e) => {
+                message_egress_manager.error(&format!("Failed to load configuration: {}", e));
+                return 1;
+            }
+        };
+        if flag_option(
+            self.use_default_color_behaviour,
+            self.no_use_default_color_behaviour,
+        )
+        .unwrap_or(config.use_default_color_behaviour)
+        {
             colored::control::unset_override();
         } else {
             colored::control::set_override(true);
         }
-        let git_cli = GitCli::new();
-        let message_egress_manager = MessageEgressManagerImpl::new();
         let controller = TreeController::new(&git_cli, &message_egress_manager);
         match controller.commit_tree() {
             ControllerExitCode::Ok => 0,
@@ -32,9 +32,12 @@ pub fn run() {
             0
         }
         Commands::Commit(c) => c.execute(),
+        Commands::Config(c) => c.execute(),
         Commands::Describe(c) => c.execute(),
+        Commands::Hook(c) => c.execute(),
         Commands::RefreshExtra(c) => c.execute(),
         Commands::License(c) => c.execute(),
+        Commands::Lint(c) => c.execute(),
         Commands::Tree(c) => c.execute(),
         // Catch-all branch for hidden commands
         _ => {
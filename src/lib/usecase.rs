@@ -1,7 +1,12 @@
+pub mod changelog;
 pub mod configuration;
 pub mod error;
 pub mod license_metadata;
+pub mod lint_issue;
 pub mod metadata_spec;
+pub mod parsed_commit;
 pub mod repository;
+pub mod tag_format;
 pub mod type_aliases;
 pub mod usecases;
+pub mod version_description;
@@ -0,0 +1,736 @@
+use crate::domain::semantic_version::SemanticVersion;
+
+use super::{configuration::changelog::ChangelogFormat, tag_format::TagFormat};
+
+pub const NO_SCOPE_TITLE: &str = "General";
+pub const BREAKING_CHANGES_TITLE: &str = "BREAKING CHANGES";
+const SHORT_HASH_LENGTH: usize = 7;
+
+/**
+ * The changes between two bounds, grouped by type and then by scope, unless the configuration
+ * asks otherwise.
+ * When the version they are released with is known, it is used as title, along with the date
+ * of the release.
+ *
+ * The breaking changes can also be collected in a section of their own, before the types.
+ *
+ * The order of types, scopes and commits is the one the changelog is presented with.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Changelog {
+    version: Option<SemanticVersion>,
+    date: Option<String>,
+    from: Option<ChangelogBound>,
+    to: Option<ChangelogBound>,
+    breaking_changes: Vec<ChangelogCommit>,
+    groups: ChangelogGroups,
+}
+
+impl Changelog {
+    pub fn new(
+        version: Option<SemanticVersion>,
+        date: Option<String>,
+        from: Option<ChangelogBound>,
+        to: Option<ChangelogBound>,
+        breaking_changes: Vec<ChangelogCommit>,
+        groups: ChangelogGroups,
+    ) -> Self {
+        Changelog {
+            version,
+            date,
+            from,
+            to,
+            breaking_changes,
+            groups,
+        }
+    }
+
+    pub fn version(&self) -> Option<&SemanticVersion> {
+        self.version.as_ref()
+    }
+
+    pub fn date(&self) -> Option<&str> {
+        self.date.as_deref()
+    }
+
+    pub fn from(&self) -> Option<&ChangelogBound> {
+        self.from.as_ref()
+    }
+
+    pub fn to(&self) -> Option<&ChangelogBound> {
+        self.to.as_ref()
+    }
+
+    pub fn breaking_changes(&self) -> &[ChangelogCommit] {
+        &self.breaking_changes
+    }
+
+    pub fn groups(&self) -> &ChangelogGroups {
+        &self.groups
+    }
+
+    pub fn title(&self) -> String {
+        match (&self.version, &self.from, &self.to) {
+            (Some(v), _, _) => match &self.date {
+                Some(d) => format!("{} ({})", v, d),
+                None => v.to_string(),
+            },
+            (None, Some(from), Some(to)) => format!("Changes from {} to {}", from, to),
+            (None, Some(from), None) => format!("Changes from {}", from),
+            (None, None, Some(to)) => format!("Changes up to {}", to),
+            (None, None, None) => "Latest changes".to_string(),
+        }
+    }
+
+    pub fn format(&self, format: &ChangelogFormat) -> String {
+        let breaking_section = if self.breaking_changes.is_empty() {
+            None
+        } else {
+            Some(format!(
+                "{}\n{}\n",
+                format.typ()(&BREAKING_CHANGES_TITLE.to_string()),
+                format_list(
+                    self.breaking_changes
+                        .iter()
+                        .map(|it| it.format_breaking_change(format)),
+                    format
+                )
+            ))
+        };
+        format!(
+            "{}\n{}",
+            format.title()(&self.title()),
+            breaking_section
+                .into_iter()
+                .chain(self.groups.format(format))
+                .reduce(|acc, e| acc + "\n" + &e)
+                .unwrap_or_default()
+        )
+    }
+}
+
+/**
+ * A bound of the range of commits in a changelog: either a version or any other git reference.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangelogBound {
+    Version(SemanticVersion),
+    Ref(String),
+}
+
+impl ChangelogBound {
+    pub fn git_ref(&self, tag_format: &TagFormat) -> String {
+        match self {
+            ChangelogBound::Version(v) => tag_format.tag(v),
+            ChangelogBound::Ref(r) => r.clone(),
+        }
+    }
+}
+
+/**
+ * The commits of a changelog, either grouped by type and then by scope, grouped by scope and then
+ * by type, or listed without grouping.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangelogGroups {
+    ByType(Vec<ChangelogType>),
+    ByScope(Vec<ChangelogScopeGroup>),
+    Ungrouped(Vec<ChangelogCommit>),
+}
+
+impl ChangelogGroups {
+    fn format(&self, format: &ChangelogFormat) -> Vec<String> {
+        match self {
+            ChangelogGroups::ByType(types) => types.iter().map(|it| it.format(format)).collect(),
+            ChangelogGroups::ByScope(scopes) => scopes.iter().map(|it| it.format(format)).collect(),
+            ChangelogGroups::Ungrouped(commits) if commits.is_empty() => vec![],
+            ChangelogGroups::Ungrouped(commits) => vec![format!(
+                "{}\n",
+                format_list(commits.iter().map(|it| it.format(format)), format)
+            )],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangelogType {
+    name: String,
+    title: String,
+    scopes: Vec<ChangelogScope>,
+}
+
+impl ChangelogType {
+    pub fn new(name: String, title: String, scopes: Vec<ChangelogScope>) -> Self {
+        ChangelogType {
+            name,
+            title,
+            scopes,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn scopes(&self) -> &[ChangelogScope] {
+        &self.scopes
+    }
+
+    fn format(&self, format: &ChangelogFormat) -> String {
+        format!(
+            "{}\n{}\n",
+            format.typ()(&self.title),
+            self.scopes
+                .iter()
+                .map(|it| it.format(format))
+                .reduce(|acc, e| acc + "\n" + &e)
+                .unwrap_or_default()
+        )
+    }
+}
+
+/**
+ * The commits of a type with the same scope; the name is absent for the commits without scope.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangelogScope {
+    name: Option<String>,
+    commits: Vec<ChangelogCommit>,
+}
+
+impl ChangelogScope {
+    pub fn new(name: Option<String>, commits: Vec<ChangelogCommit>) -> Self {
+        ChangelogScope { name, commits }
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn commits(&self) -> &[ChangelogCommit] {
+        &self.commits
+    }
+
+    fn format(&self, format: &ChangelogFormat) -> String {
+        format!(
+            "{}\n{}",
+            format.scope()(&self.name().unwrap_or(NO_SCOPE_TITLE).to_owned()),
+            format_list(self.commits.iter().map(|it| it.format(format)), format)
+        )
+    }
+}
+
+/**
+ * The commits with the same scope, grouped by type; the name is absent for the commits without
+ * scope. The headers of the scopes are formatted as the ones of the types when grouping by type,
+ * and vice versa.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangelogScopeGroup {
+    name: Option<String>,
+    types: Vec<ChangelogTypeGroup>,
+}
+
+impl ChangelogScopeGroup {
+    pub fn new(name: Option<String>, types: Vec<ChangelogTypeGroup>) -> Self {
+        ChangelogScopeGroup { name, types }
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn types(&self) -> &[ChangelogTypeGroup] {
+        &self.types
+    }
+
+    fn format(&self, format: &ChangelogFormat) -> String {
+        format!(
+            "{}\n{}\n",
+            format.typ()(&self.name().unwrap_or(NO_SCOPE_TITLE).to_owned()),
+            self.types
+                .iter()
+                .map(|it| it.format(format))
+                .reduce(|acc, e| acc + "\n" + &e)
+                .unwrap_or_default()
+        )
+    }
+}
+
+/**
+ * The commits of a scope with the same type.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangelogTypeGroup {
+    name: String,
+    title: String,
+    commits: Vec<ChangelogCommit>,
+}
+
+impl ChangelogTypeGroup {
+    pub fn new(name: String, title: String, commits: Vec<ChangelogCommit>) -> Self {
+        ChangelogTypeGroup {
+            name,
+            title,
+            commits,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn commits(&self) -> &[ChangelogCommit] {
+        &self.commits
+    }
+
+    fn format(&self, format: &ChangelogFormat) -> String {
+        format!(
+            "{}\n{}",
+            format.scope()(&self.title),
+            format_list(self.commits.iter().map(|it| it.format(format)), format)
+        )
+    }
+}
+
+fn format_list(items: impl Iterator<Item = String>, format: &ChangelogFormat) -> String {
+    format.list()(&items.reduce(|acc, e| acc + "\n" + &e).unwrap_or_default())
+}
+
+/**
+ * A commit in a changelog, along with the link to the commit and the issues it references, when
+ * their URLs are known.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangelogCommit {
+    hash: String,
+    summary: String,
+    breaking: bool,
+    breaking_note: Option<String>,
+    url: Option<String>,
+    issues: Vec<ChangelogIssue>,
+}
+
+impl ChangelogCommit {
+    pub fn new(
+        hash: String,
+        summary: String,
+        breaking: bool,
+        breaking_note: Option<String>,
+        url: Option<String>,
+        issues: Vec<ChangelogIssue>,
+    ) -> Self {
+        ChangelogCommit {
+            hash,
+            summary,
+            breaking,
+            breaking_note,
+            url,
+            issues,
+        }
+    }
+
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+
+    pub fn short_hash(&self) -> &str {
+        self.hash
+            .get(..SHORT_HASH_LENGTH)
+            .unwrap_or(self.hash.as_str())
+    }
+
+    /**
+     * The description of the breaking change, which tells how to migrate.
+     */
+    pub fn breaking_note(&self) -> Option<&str> {
+        self.breaking_note.as_deref()
+    }
+
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    pub fn issues(&self) -> &[ChangelogIssue] {
+        &self.issues
+    }
+
+    pub fn summary(&self) -> &str {
+        &self.summary
+    }
+
+    pub fn breaking(&self) -> bool {
+        self.breaking
+    }
+
+    fn format(&self, format: &ChangelogFormat) -> String {
+        let summary = if self.breaking {
+            format.breaking()(&self.summary)
+        } else {
+            self.summary.clone()
+        };
+        self.format_with_hash(summary, format)
+    }
+
+    /**
+     * In the breaking changes section, the summary is followed by the migration note, if any.
+     */
+    fn format_breaking_change(&self, format: &ChangelogFormat) -> String {
+        let summary = match &self.breaking_note {
+            Some(note) => format!("{}: {}", self.summary, note),
+            None => self.summary.clone(),
+        };
+        self.format_with_hash(summary, format)
+    }
+
+    fn format_with_hash(&self, summary: String, format: &ChangelogFormat) -> String {
+        format.item()(&match format.hash() {
+            Some(hash) => summary + &hash(&self.short_hash().to_owned()),
+            None => summary,
+        })
+    }
+}
+
+/**
+ * An issue referenced by a commit, e.g. '#12' in the summary or in a 'Refs' footer.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangelogIssue {
+    id: String,
+    url: Option<String>,
+}
+
+impl ChangelogIssue {
+    pub fn new(id: String, url: Option<String>) -> Self {
+        ChangelogIssue { id, url }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        domain::semantic_version::SemanticVersion,
+        usecase::{
+            changelog::{
+                Changelog, ChangelogBound, ChangelogCommit, ChangelogGroups, ChangelogScope,
+                ChangelogScopeGroup, ChangelogType, ChangelogTypeGroup,
+            },
+            configuration::changelog::ChangelogFormat,
+        },
+    };
+
+    fn format() -> ChangelogFormat<'static> {
+        ChangelogFormat::new(
+            Box::new(|t| format!("# {}", t)),
+            Box::new(|t| format!("## {}", t)),
+            Box::new(|s| format!("### {}", s)),
+            Box::new(|l| l.to_string()),
+            Box::new(|i| format!("* {}", i)),
+            Box::new(|b| format!("**{}**", b)),
+            None,
+        )
+    }
+
+    fn commit(summary: &str, breaking: bool) -> ChangelogCommit {
+        ChangelogCommit::new(
+            String::new(),
+            summary.to_string(),
+            breaking,
+            None,
+            None,
+            vec![],
+        )
+    }
+
+    fn changelog() -> Changelog {
+        Changelog::new(
+            None,
+            None,
+            Some(ChangelogBound::Version(
+                SemanticVersion::new(0, 1, 0, None, None)
+                    .expect("Hand-crafted version is always correct"),
+            )),
+            None,
+            vec![],
+            ChangelogGroups::ByType(vec![
+                ChangelogType::new(
+                    "feat".to_string(),
+                    "Features".to_string(),
+                    vec![
+                        ChangelogScope::new(
+                            Some("API".to_string()),
+                            vec![commit("test message #1", true)],
+                        ),
+                        ChangelogScope::new(None, vec![commit("test message #6", false)]),
+                    ],
+                ),
+                ChangelogType::new(
+                    "test".to_string(),
+                    "test".to_string(),
+                    vec![ChangelogScope::new(
+                        None,
+                        vec![
+                            commit("test message #3", false),
+                            commit("test message #5", false),
+                        ],
+                    )],
+                ),
+            ]),
+        )
+    }
+
+    #[test]
+    fn format_item_not_breaking() {
+        let s = commit("test message #1", false).format(&format());
+        assert_eq!(s, "* test message #1");
+    }
+
+    #[test]
+    fn format_item_breaking() {
+        let s = commit("test message #1", true).format(&format());
+        assert_eq!(s, "* **test message #1**");
+    }
+
+    #[test]
+    fn format_item_with_hash() {
+        let format = ChangelogFormat::new(
+            Box::new(|t| t.to_string()),
+            Box::new(|t| t.to_string()),
+            Box::new(|s| s.to_string()),
+            Box::new(|l| l.to_string()),
+            Box::new(|i| format!("* {}", i)),
+            Box::new(|b| b.to_string()),
+            Some(Box::new(|h| format!(" ({})", h))),
+        );
+        let s = ChangelogCommit::new(
+            "0123456789abcdef".to_string(),
+            "test message #1".to_string(),
+            false,
+            None,
+            None,
+            vec![],
+        )
+        .format(&format);
+        assert_eq!(s, "* test message #1 (0123456)");
+    }
+
+    #[test]
+    fn format_scope_general() {
+        let s = ChangelogScope::new(
+            None,
+            vec![
+                commit("test message #3", false),
+                commit("test message #5", false),
+            ],
+        )
+        .format(&format());
+        assert_eq!(s, "### General\n* test message #3\n* test message #5");
+    }
+
+    #[test]
+    fn format_basic() {
+        let s = changelog().format(&format());
+        assert_eq!(
+            s,
+            concat!(
+                "# Changes from version 0.1.0\n",
+                "## Features\n",
+                "### API\n",
+                "* **test message #1**\n",
+                "### General\n",
+                "* test message #6\n",
+                "\n",
+                "## test\n",
+                "### General\n",
+                "* test message #3\n",
+                "* test message #5\n",
+            )
+        );
+    }
+
+    #[test]
+    fn format_grouped_by_scope() {
+        let s = Changelog::new(
+            None,
+            None,
+            None,
+            None,
+            vec![],
+            ChangelogGroups::ByScope(vec![
+                ChangelogScopeGroup::new(
+                    Some("API".to_string()),
+                    vec![
+                        ChangelogTypeGroup::new(
+                            "feat".to_string(),
+                            "Features".to_string(),
+                            vec![commit("test message #1", true)],
+                        ),
+                        ChangelogTypeGroup::new(
+                            "fix".to_string(),
+                            "fix".to_string(),
+                            vec![commit("test message #2", false)],
+                        ),
+                    ],
+                ),
+                ChangelogScopeGroup::new(
+                    None,
+                    vec![ChangelogTypeGroup::new(
+                        "feat".to_string(),
+                        "Features".to_string(),
+                        vec![commit("test message #6", false)],
+                    )],
+                ),
+            ]),
+        )
+        .format(&format());
+        assert_eq!(
+            s,
+            concat!(
+                "# Latest changes\n",
+                "## API\n",
+                "### Features\n",
+                "* **test message #1**\n",
+                "### fix\n",
+                "* test message #2\n",
+                "\n",
+                "## General\n",
+                "### Features\n",
+                "* test message #6\n",
+            )
+        );
+    }
+
+    #[test]
+    fn format_ungrouped() {
+        let s = Changelog::new(
+            None,
+            None,
+            None,
+            None,
+            vec![],
+            ChangelogGroups::Ungrouped(vec![
+                commit("test message #1", true),
+                commit("test message #2", false),
+            ]),
+        )
+        .format(&format());
+        assert_eq!(
+            s,
+            "# Latest changes\n* **test message #1**\n* test message #2\n"
+        );
+    }
+
+    #[test]
+    fn format_breaking_changes_section() {
+        let breaking = ChangelogCommit::new(
+            String::new(),
+            "test message #1".to_string(),
+            true,
+            Some("use the new endpoint".to_string()),
+            None,
+            vec![],
+        );
+        let s = Changelog::new(
+            None,
+            None,
+            None,
+            None,
+            vec![breaking.clone(), commit("test message #2", true)],
+            ChangelogGroups::ByType(vec![ChangelogType::new(
+                "feat".to_string(),
+                "Features".to_string(),
+                vec![ChangelogScope::new(None, vec![breaking])],
+            )]),
+        )
+        .format(&format());
+        assert_eq!(
+            s,
+            concat!(
+                "# Latest changes\n",
+                "## BREAKING CHANGES\n",
+                "* test message #1: use the new endpoint\n",
+                "* test message #2\n",
+                "\n",
+                "## Features\n",
+                "### General\n",
+                "* **test message #1**\n",
+            )
+        );
+    }
+
+    #[test]
+    fn format_empty_version() {
+        let s = Changelog::new(
+            None,
+            None,
+            None,
+            None,
+            vec![],
+            ChangelogGroups::ByType(vec![]),
+        )
+        .format(&format());
+        assert_eq!(s, "# Latest changes\n");
+    }
+
+    #[test]
+    fn format_with_version() {
+        let s = Changelog::new(
+            Some(
+                SemanticVersion::new(0, 2, 0, None, None)
+                    .expect("Hand-crafted version is always correct"),
+            ),
+            Some("2024-01-31".to_string()),
+            None,
+            None,
+            vec![],
+            ChangelogGroups::ByType(vec![]),
+        )
+        .format(&format());
+        assert_eq!(s, "# 0.2.0 (2024-01-31)\n");
+    }
+
+    #[test]
+    fn title_between_refs() {
+        let changelog = Changelog::new(
+            None,
+            None,
+            Some(ChangelogBound::Ref("v1.2.0".to_string())),
+            Some(ChangelogBound::Ref("hotfix".to_string())),
+            vec![],
+            ChangelogGroups::ByType(vec![]),
+        );
+        assert_eq!(changelog.title(), "Changes from v1.2.0 to hotfix");
+    }
+
+    #[test]
+    fn title_up_to_version() {
+        let changelog = Changelog::new(
+            None,
+            None,
+            None,
+            Some(ChangelogBound::Version(
+                SemanticVersion::new(1, 3, 0, None, None)
+                    .expect("Hand-crafted version is always correct"),
+            )),
+            vec![],
+            ChangelogGroups::ByType(vec![]),
+        );
+        assert_eq!(changelog.title(), "Changes up to version 1.3.0");
+    }
+}
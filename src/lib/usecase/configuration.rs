@@ -1,4 +1,5 @@
 pub mod changelog;
 pub mod commit;
 pub mod describe;
+pub mod lint;
 pub mod tag;
@@ -1,21 +1,106 @@
-use crate::domain::trigger::Trigger;
+use crate::{
+    domain::{semantic_version::SemanticVersion, trigger::Trigger},
+    usecase::{changelog::ChangelogBound, tag_format::TagFormat},
+};
 
-pub struct ChangelogConfiguration<'a> {
-    generate_from_latest_version: bool,
-    format: ChangelogFormat<'a>,
+pub struct ChangelogConfiguration {
+    range: ChangelogRange,
     exclude_trigger: Option<Trigger>,
+    order: ChangelogOrder,
+    links: ChangelogLinks,
+    breaking_section: bool,
+    group_by: ChangelogGroupBy,
+    version: Option<SemanticVersion>,
 }
 
-impl ChangelogConfiguration<'_> {
+impl ChangelogConfiguration {
     pub fn new(
-        generate_from_latest_version: bool,
-        format: ChangelogFormat,
+        range: ChangelogRange,
         exclude_trigger: Option<Trigger>,
+        order: ChangelogOrder,
+        links: ChangelogLinks,
+        breaking_section: bool,
+        group_by: ChangelogGroupBy,
+        version: Option<SemanticVersion>,
     ) -> ChangelogConfiguration {
         ChangelogConfiguration {
-            generate_from_latest_version,
-            format,
+            range,
             exclude_trigger,
+            order,
+            links,
+            breaking_section,
+            group_by,
+            version,
+        }
+    }
+
+    pub fn range(&self) -> &ChangelogRange {
+        &self.range
+    }
+
+    pub fn exclude_trigger(&self) -> Option<&Trigger> {
+        self.exclude_trigger.as_ref()
+    }
+
+    pub fn order(&self) -> &ChangelogOrder {
+        &self.order
+    }
+
+    pub fn links(&self) -> &ChangelogLinks {
+        &self.links
+    }
+
+    pub fn breaking_section(&self) -> bool {
+        self.breaking_section
+    }
+
+    pub fn group_by(&self) -> ChangelogGroupBy {
+        self.group_by
+    }
+
+    pub fn version(&self) -> Option<&SemanticVersion> {
+        self.version.as_ref()
+    }
+}
+
+/**
+ * How the commits are grouped in the changelog.
+ */
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ChangelogGroupBy {
+    #[default]
+    Type,
+    Scope,
+    None,
+}
+
+/**
+ * The range of commits of a changelog. Without explicit bounds, it starts from the last stable
+ * version (or the last version) and ends at HEAD.
+ */
+#[derive(Debug, Clone, Default)]
+pub struct ChangelogRange {
+    generate_from_latest_version: bool,
+    from: Option<ChangelogBound>,
+    to: Option<ChangelogBound>,
+    paths: Vec<String>,
+    tag_format: TagFormat,
+}
+
+impl ChangelogRange {
+    pub fn new(
+        generate_from_latest_version: bool,
+        from: Option<ChangelogBound>,
+        to: Option<ChangelogBound>,
+        paths: Vec<String>,
+        tag_format: TagFormat,
+    ) -> ChangelogRange {
+        ChangelogRange {
+            generate_from_latest_version,
+            from,
+            to,
+            paths,
+            tag_format,
         }
     }
 
@@ -23,12 +108,82 @@ impl ChangelogConfiguration<'_> {
         self.generate_from_latest_version
     }
 
-    pub fn format(&self) -> &ChangelogFormat {
-        &self.format
+    pub fn from(&self) -> Option<&ChangelogBound> {
+        self.from.as_ref()
     }
 
-    pub fn exclude_trigger(&self) -> Option<&Trigger> {
-        self.exclude_trigger.as_ref()
+    pub fn to(&self) -> Option<&ChangelogBound> {
+        self.to.as_ref()
+    }
+
+    pub fn paths(&self) -> &[String] {
+        &self.paths
+    }
+
+    pub fn tag_format(&self) -> &TagFormat {
+        &self.tag_format
+    }
+}
+
+/**
+ * The declared types come first, in the given order and with the given title, followed by the
+ * other types in alphabetical order. Scopes are sorted alphabetically, with the commits without
+ * scope either first or last.
+ */
+#[derive(Debug, Clone, Default)]
+pub struct ChangelogOrder {
+    types: Vec<(String, String)>,
+    general_scope_last: bool,
+}
+
+impl ChangelogOrder {
+    pub fn new(types: Vec<(String, String)>, general_scope_last: bool) -> ChangelogOrder {
+        ChangelogOrder {
+            types,
+            general_scope_last,
+        }
+    }
+
+    pub fn types(&self) -> &[(String, String)] {
+        &self.types
+    }
+
+    pub fn general_scope_last(&self) -> bool {
+        self.general_scope_last
+    }
+}
+
+pub const COMMIT_URL_PLACEHOLDER: &str = "{hash}";
+pub const ISSUE_URL_PLACEHOLDER: &str = "{issue}";
+
+/**
+ * The URL templates of commits and issues; the placeholders are replaced with the full hash of
+ * the commit and the number of the issue.
+ */
+#[derive(Debug, Clone, Default)]
+pub struct ChangelogLinks {
+    commit_url: Option<String>,
+    issue_url: Option<String>,
+}
+
+impl ChangelogLinks {
+    pub fn new(commit_url: Option<String>, issue_url: Option<String>) -> ChangelogLinks {
+        ChangelogLinks {
+            commit_url,
+            issue_url,
+        }
+    }
+
+    pub fn commit_url(&self, hash: &str) -> Option<String> {
+        self.commit_url
+            .as_ref()
+            .map(|it| it.replace(COMMIT_URL_PLACEHOLDER, hash))
+    }
+
+    pub fn issue_url(&self, issue: &str) -> Option<String> {
+        self.issue_url
+            .as_ref()
+            .map(|it| it.replace(ISSUE_URL_PLACEHOLDER, issue))
     }
 }
 
@@ -41,6 +196,7 @@ pub struct ChangelogFormat<'a> {
     list: ChangelogTransformer<'a>,
     item: ChangelogTransformer<'a>,
     breaking: ChangelogTransformer<'a>,
+    hash: Option<ChangelogTransformer<'a>>,
 }
 
 impl<'a> ChangelogFormat<'a> {
@@ -51,6 +207,7 @@ impl<'a> ChangelogFormat<'a> {
         list: ChangelogTransformer<'a>,
         item: ChangelogTransformer<'a>,
         breaking: ChangelogTransformer<'a>,
+        hash: Option<ChangelogTransformer<'a>>,
     ) -> ChangelogFormat<'a> {
         ChangelogFormat {
             title,
@@ -59,30 +216,35 @@ impl<'a> ChangelogFormat<'a> {
             list,
             item,
             breaking,
+            hash,
         }
     }
 
-    pub fn title(&self) -> &ChangelogTransformer {
+    pub fn title(&self) -> &ChangelogTransformer<'_> {
         &self.title
     }
 
-    pub fn typ(&self) -> &ChangelogTransformer {
+    pub fn typ(&self) -> &ChangelogTransformer<'_> {
         &self.typ
     }
 
-    pub fn scope(&self) -> &ChangelogTransformer {
+    pub fn scope(&self) -> &ChangelogTransformer<'_> {
         &self.scope
     }
 
-    pub fn list(&self) -> &ChangelogTransformer {
+    pub fn list(&self) -> &ChangelogTransformer<'_> {
         &self.list
     }
 
-    pub fn item(&self) -> &ChangelogTransformer {
+    pub fn item(&self) -> &ChangelogTransformer<'_> {
         &self.item
     }
 
-    pub fn breaking(&self) -> &ChangelogTransformer {
+    pub fn breaking(&self) -> &ChangelogTransformer<'_> {
         &self.breaking
     }
+
+    pub fn hash(&self) -> Option<&ChangelogTransformer<'_>> {
+        self.hash.as_ref()
+    }
 }
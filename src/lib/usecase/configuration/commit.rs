@@ -1,6 +1,9 @@
 use std::ops::Deref;
 
-use crate::usecase::error::commit_configuration_invariant_error::CommitConfigurationInvariantError;
+use crate::{
+    domain::{commit_footer::CommitFooter, commit_policy::CommitPolicy},
+    usecase::error::commit_configuration_invariant_error::CommitConfigurationInvariantError,
+};
 
 #[derive(Debug)]
 pub struct CommitConfiguration {
@@ -9,17 +12,22 @@ pub struct CommitConfiguration {
     is_breaking: bool,
     summary: String,
     message: Option<String>,
+    footers: Vec<CommitFooter>,
     allow_empty: AllowEmptyFlag,
+    policy: CommitPolicy,
 }
 
 impl CommitConfiguration {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         commit_type: String,
         scope: Option<String>,
         is_breaking: bool,
         summary: String,
         message: Option<String>,
+        footers: Vec<CommitFooter>,
         allow_empty: AllowEmptyFlag,
+        policy: CommitPolicy,
     ) -> Result<CommitConfiguration, CommitConfigurationInvariantError> {
         Self::type_checks(&commit_type)?;
         Self::scope_checks(scope.as_deref())?;
@@ -31,7 +39,9 @@ impl CommitConfiguration {
             is_breaking,
             summary,
             message,
+            footers,
             allow_empty,
+            policy,
         })
     }
 
@@ -55,10 +65,18 @@ impl CommitConfiguration {
         self.message.as_deref()
     }
 
+    pub fn footers(&self) -> &[CommitFooter] {
+        &self.footers
+    }
+
     pub fn allow_empty(&self) -> bool {
         *self.allow_empty
     }
 
+    pub fn policy(&self) -> &CommitPolicy {
+        &self.policy
+    }
+
     fn type_checks(t: &str) -> Result<(), CommitConfigurationInvariantError> {
         if t.is_empty() {
             return Err(CommitConfigurationInvariantError::new(
@@ -1,12 +1,20 @@
-use crate::{domain::trigger::Trigger, usecase::metadata_spec::MetadataSpec};
+use crate::{
+    domain::{semantic_version::SemanticVersion, trigger::Trigger},
+    usecase::{metadata_spec::MetadataSpec, tag_format::TagFormat, version_description::Change},
+};
 
 pub type PrereleasePattern<'a> = Box<dyn Fn(u32) -> String + 'a>;
-pub type OldPrereleasePattern<'a> = Box<dyn Fn(&str) -> u32 + 'a>;
+/// The number of an old prerelease, if it follows the old pattern.
+pub type OldPrereleasePattern<'a> = Box<dyn Fn(&str) -> Option<u32> + 'a>;
 
 pub struct DescribeConfiguration<'a> {
     prerelease: DescribePrereleaseConfiguration<'a>,
     metadata: DescribeMetadataConfiguration,
     triggers: DescribeTriggerConfiguration,
+    tag_format: TagFormat,
+    package: Option<DescribePackageConfiguration>,
+    paths: Vec<String>,
+    version: DescribeVersionConfiguration,
 }
 
 impl<'a> DescribeConfiguration<'a> {
@@ -14,14 +22,22 @@ impl<'a> DescribeConfiguration<'a> {
         prerelease: DescribePrereleaseConfiguration<'a>,
         metadata: DescribeMetadataConfiguration,
         triggers: DescribeTriggerConfiguration,
+        tag_format: TagFormat,
+        package: Option<DescribePackageConfiguration>,
+        paths: Vec<String>,
+        version: DescribeVersionConfiguration,
     ) -> DescribeConfiguration<'a> {
         DescribeConfiguration {
             prerelease,
             metadata,
             triggers,
+            tag_format,
+            package,
+            paths,
+            version,
         }
     }
-    pub fn prerelease(&self) -> &DescribePrereleaseConfiguration {
+    pub fn prerelease(&self) -> &DescribePrereleaseConfiguration<'_> {
         &self.prerelease
     }
     pub fn metadata(&self) -> &DescribeMetadataConfiguration {
@@ -30,6 +46,18 @@ impl<'a> DescribeConfiguration<'a> {
     pub fn triggers(&self) -> &DescribeTriggerConfiguration {
         &self.triggers
     }
+    pub fn tag_format(&self) -> &TagFormat {
+        &self.tag_format
+    }
+    pub fn package(&self) -> Option<&DescribePackageConfiguration> {
+        self.package.as_ref()
+    }
+    pub fn paths(&self) -> &[String] {
+        &self.paths
+    }
+    pub fn version(&self) -> &DescribeVersionConfiguration {
+        &self.version
+    }
 }
 
 pub struct DescribePrereleaseConfiguration<'a> {
@@ -111,3 +139,73 @@ impl DescribeTriggerConfiguration {
         &self.patch_trigger
     }
 }
+
+/**
+ * The commits of a package are the ones with its name as scope, or which touch its paths.
+ */
+pub struct DescribePackageConfiguration {
+    name: String,
+    paths: Vec<String>,
+}
+
+impl DescribePackageConfiguration {
+    pub fn new(name: String, paths: Vec<String>) -> DescribePackageConfiguration {
+        DescribePackageConfiguration { name, paths }
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn paths(&self) -> &[String] {
+        &self.paths
+    }
+}
+
+/**
+ * The new version is at least the given change from the last stable version, or exactly the
+ * given version, whatever the commits are.
+ * During the initial development (i.e. while the major version is 0), the changes of the commits
+ * can be lowered by one level, until the first major version is requested.
+ * Promoting releases the stable version of the last prerelease.
+ */
+#[derive(Debug, Default)]
+pub struct DescribeVersionConfiguration {
+    bump: Option<Change>,
+    version: Option<SemanticVersion>,
+    initial_development: bool,
+    graduate: bool,
+    promote: bool,
+}
+
+impl DescribeVersionConfiguration {
+    pub fn new(
+        bump: Option<Change>,
+        version: Option<SemanticVersion>,
+        initial_development: bool,
+        graduate: bool,
+        promote: bool,
+    ) -> DescribeVersionConfiguration {
+        DescribeVersionConfiguration {
+            bump,
+            version,
+            initial_development,
+            graduate,
+            promote,
+        }
+    }
+
+    pub fn bump(&self) -> Option<Change> {
+        self.bump
+    }
+    pub fn version(&self) -> Option<&SemanticVersion> {
+        self.version.as_ref()
+    }
+    pub fn initial_development(&self) -> bool {
+        self.initial_development
+    }
+    pub fn graduate(&self) -> bool {
+        self.graduate
+    }
+    pub fn promote(&self) -> bool {
+        self.promote
+    }
+}
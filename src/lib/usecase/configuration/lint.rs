@@ -0,0 +1,39 @@
+use crate::domain::commit_policy::CommitPolicy;
+
+pub struct LintConfiguration {
+    range: Option<String>,
+    policy: CommitPolicy,
+}
+
+impl LintConfiguration {
+    pub fn new(range: Option<String>, policy: CommitPolicy) -> LintConfiguration {
+        LintConfiguration { range, policy }
+    }
+
+    pub fn range(&self) -> Option<&str> {
+        self.range.as_deref()
+    }
+
+    pub fn policy(&self) -> &CommitPolicy {
+        &self.policy
+    }
+}
+
+pub struct LintMessageConfiguration {
+    path: String,
+    policy: CommitPolicy,
+}
+
+impl LintMessageConfiguration {
+    pub fn new(path: String, policy: CommitPolicy) -> LintMessageConfiguration {
+        LintMessageConfiguration { path, policy }
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn policy(&self) -> &CommitPolicy {
+        &self.policy
+    }
+}
@@ -2,12 +2,13 @@ use crate::{
     domain::semantic_version::SemanticVersion,
     usecase::{
         error::tag_configuration_invariant_error::TagConfigurationInvariantError,
-        type_aliases::AnyError,
+        tag_format::TagFormat, type_aliases::AnyError,
     },
 };
 
 pub struct TagConfiguration {
     version: SemanticVersion,
+    tag_format: TagFormat,
     message: Option<String>,
     sign: bool,
 }
@@ -15,12 +16,14 @@ pub struct TagConfiguration {
 impl TagConfiguration {
     pub fn new(
         version: SemanticVersion,
+        tag_format: TagFormat,
         message: Option<String>,
         sign: bool,
     ) -> Result<TagConfiguration, AnyError> {
         Self::message_checks(message.as_deref())?;
         Ok(TagConfiguration {
             version,
+            tag_format,
             message,
             sign,
         })
@@ -29,6 +32,9 @@ impl TagConfiguration {
     pub fn version(&self) -> &SemanticVersion {
         &self.version
     }
+    pub fn tag_format(&self) -> &TagFormat {
+        &self.tag_format
+    }
     pub fn message(&self) -> Option<&str> {
         self.message.as_deref()
     }
@@ -49,7 +55,7 @@ impl TagConfiguration {
 
 #[cfg(test)]
 mod tests {
-    use crate::domain::semantic_version::SemanticVersion;
+    use crate::{domain::semantic_version::SemanticVersion, usecase::tag_format::TagFormat};
 
     use super::TagConfiguration;
 
@@ -59,7 +65,7 @@ mod tests {
             .expect("Hand-crafted version is always correct");
         let message = Some(String::from("test"));
         let sign = false;
-        let result = TagConfiguration::new(version, message, sign);
+        let result = TagConfiguration::new(version, TagFormat::default(), message, sign);
         assert!(result.is_ok());
     }
 
@@ -69,7 +75,7 @@ mod tests {
             .expect("Hand-crafted version is always correct");
         let message = None;
         let sign = false;
-        let result = TagConfiguration::new(version, message, sign);
+        let result = TagConfiguration::new(version, TagFormat::default(), message, sign);
         assert!(result.is_ok());
     }
 
@@ -79,7 +85,7 @@ mod tests {
             .expect("Hand-crafted version is always correct");
         let message = Some(String::new());
         let sign = false;
-        let result = TagConfiguration::new(version, message, sign);
+        let result = TagConfiguration::new(version, TagFormat::default(), message, sign);
         assert!(result.is_err());
     }
 }
@@ -5,5 +5,7 @@ pub mod create_license_error;
 pub mod create_tag_error;
 pub mod describe_new_version_error;
 pub mod format_tree_error;
+pub mod lint_commits_error;
 pub mod refresh_types_and_scopes_error;
 pub mod tag_configuration_invariant_error;
+pub mod tag_format_invariant_error;
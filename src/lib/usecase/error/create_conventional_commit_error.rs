@@ -1,6 +1,9 @@
 use std::{error::Error, fmt::Display};
 
-use crate::domain::error::conventional_commit_error::ConventionalCommitError;
+use crate::domain::error::{
+    commit_policy_violation_error::CommitPolicyViolationError,
+    conventional_commit_error::ConventionalCommitError,
+};
 
 // The type of the Box value should be more specific, but it cannot as trait upcast is not
 // permitted yet
@@ -9,6 +12,7 @@ type RepositoryError = Box<dyn Error>;
 #[derive(Debug)]
 pub enum CreateConventionalCommitError {
     CreationError(ConventionalCommitError),
+    PolicyViolation(CommitPolicyViolationError),
     RepositoryError(RepositoryError),
 }
 
@@ -26,6 +30,7 @@ impl Error for CreateConventionalCommitError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             Self::CreationError(err) => Some(err),
+            Self::PolicyViolation(err) => Some(err),
             Self::RepositoryError(err) => Some(err.as_ref()),
         }
     }
@@ -37,8 +42,14 @@ impl From<ConventionalCommitError> for CreateConventionalCommitError {
     }
 }
 
+impl From<CommitPolicyViolationError> for CreateConventionalCommitError {
+    fn from(value: CommitPolicyViolationError) -> Self {
+        Self::PolicyViolation(value)
+    }
+}
+
 impl From<RepositoryError> for CreateConventionalCommitError {
-    fn from(value: Box<(dyn std::error::Error + 'static)>) -> Self {
+    fn from(value: Box<dyn std::error::Error + 'static>) -> Self {
         Self::RepositoryError(value)
     }
 }
@@ -68,6 +68,9 @@ impl From<RepositoryError> for DescribeNewVersionError {
 #[derive(Debug)]
 pub enum DescribeStableReleaseError {
     NoChanges(DescribeNoRelevantChangesError),
+    AlreadyGraduated(DescribeAlreadyGraduatedError),
+    NothingToPromote(DescribeNothingToPromoteError),
+    NotGreater(DescribeVersionNotGreaterError),
     RepositoryError(RepositoryError),
 }
 
@@ -85,6 +88,9 @@ impl Error for DescribeStableReleaseError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             Self::NoChanges(err) => Some(err),
+            Self::AlreadyGraduated(err) => Some(err),
+            Self::NothingToPromote(err) => Some(err),
+            Self::NotGreater(err) => Some(err),
             Self::RepositoryError(err) => Some(err.as_ref()),
         }
     }
@@ -96,6 +102,24 @@ impl From<DescribeNoRelevantChangesError> for DescribeStableReleaseError {
     }
 }
 
+impl From<DescribeAlreadyGraduatedError> for DescribeStableReleaseError {
+    fn from(value: DescribeAlreadyGraduatedError) -> Self {
+        Self::AlreadyGraduated(value)
+    }
+}
+
+impl From<DescribeNothingToPromoteError> for DescribeStableReleaseError {
+    fn from(value: DescribeNothingToPromoteError) -> Self {
+        Self::NothingToPromote(value)
+    }
+}
+
+impl From<DescribeVersionNotGreaterError> for DescribeStableReleaseError {
+    fn from(value: DescribeVersionNotGreaterError) -> Self {
+        Self::NotGreater(value)
+    }
+}
+
 impl From<Box<dyn Error>> for DescribeStableReleaseError {
     fn from(value: Box<dyn Error>) -> Self {
         Self::RepositoryError(value)
@@ -105,6 +129,7 @@ impl From<Box<dyn Error>> for DescribeStableReleaseError {
 #[derive(Debug)]
 pub enum DescribePrereleaseError {
     NoChanges(DescribeNoRelevantChangesError),
+    Outdated(DescribeOutdatedPrereleaseError),
     RepositoryError(RepositoryError),
 }
 
@@ -122,6 +147,7 @@ impl Error for DescribePrereleaseError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             Self::NoChanges(err) => Some(err),
+            Self::Outdated(err) => Some(err),
             Self::RepositoryError(err) => Some(err.as_ref()),
         }
     }
@@ -133,6 +159,12 @@ impl From<DescribeNoRelevantChangesError> for DescribePrereleaseError {
     }
 }
 
+impl From<DescribeOutdatedPrereleaseError> for DescribePrereleaseError {
+    fn from(value: DescribeOutdatedPrereleaseError) -> Self {
+        Self::Outdated(value)
+    }
+}
+
 impl From<Box<dyn Error>> for DescribePrereleaseError {
     fn from(value: Box<dyn Error>) -> Self {
         Self::RepositoryError(value)
@@ -185,3 +217,115 @@ impl Display for DescribeNoRelevantChangesError {
 }
 
 impl Error for DescribeNoRelevantChangesError {}
+
+/// The error may occur when the first major version is requested, but it has already been
+/// released.
+#[derive(Debug)]
+pub struct DescribeAlreadyGraduatedError {
+    last_stable_version: String,
+}
+
+impl DescribeAlreadyGraduatedError {
+    pub fn new(last_stable_version: String) -> DescribeAlreadyGraduatedError {
+        DescribeAlreadyGraduatedError {
+            last_stable_version,
+        }
+    }
+}
+
+impl Display for DescribeAlreadyGraduatedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the last stable version {} is already past the initial development",
+            self.last_stable_version
+        )
+    }
+}
+
+impl Error for DescribeAlreadyGraduatedError {}
+
+/// The error may occur when a prerelease is promoted, but the last version is not a prerelease.
+#[derive(Debug)]
+pub struct DescribeNothingToPromoteError {
+    last_version: Option<String>,
+}
+
+impl DescribeNothingToPromoteError {
+    pub fn new(last_version: Option<String>) -> DescribeNothingToPromoteError {
+        DescribeNothingToPromoteError { last_version }
+    }
+}
+
+impl Display for DescribeNothingToPromoteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.last_version {
+            Some(version) => write!(
+                f,
+                "the last version {} is not a prerelease, so there is nothing to promote",
+                version
+            ),
+            None => write!(f, "there are no versions, so there is nothing to promote"),
+        }
+    }
+}
+
+impl Error for DescribeNothingToPromoteError {}
+
+/// The error may occur when the new prerelease would not come after the last version, e.g. when
+/// moving back to a previous channel.
+#[derive(Debug)]
+pub struct DescribeOutdatedPrereleaseError {
+    new_version: String,
+    last_version: String,
+}
+
+impl DescribeOutdatedPrereleaseError {
+    pub fn new(new_version: String, last_version: String) -> DescribeOutdatedPrereleaseError {
+        DescribeOutdatedPrereleaseError {
+            new_version,
+            last_version,
+        }
+    }
+}
+
+impl Display for DescribeOutdatedPrereleaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the prerelease {} would not come after the last version {}",
+            self.new_version, self.last_version
+        )
+    }
+}
+
+impl Error for DescribeOutdatedPrereleaseError {}
+
+/// The error may occur when the new version is given, but it does not come after the last stable
+/// version.
+#[derive(Debug)]
+pub struct DescribeVersionNotGreaterError {
+    version: String,
+    last_stable_version: String,
+}
+
+impl DescribeVersionNotGreaterError {
+    pub fn new(version: String, last_stable_version: String) -> DescribeVersionNotGreaterError {
+        DescribeVersionNotGreaterError {
+            version,
+            last_stable_version,
+        }
+    }
+}
+
+impl Display for DescribeVersionNotGreaterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the version {} does not come after the last stable version {}",
+            self.version, self.last_stable_version
+        )
+    }
+}
+
+impl Error for DescribeVersionNotGreaterError {}
@@ -0,0 +1,32 @@
+use std::{error::Error, fmt::Display};
+
+type RepositoryError = Box<dyn Error>;
+
+#[derive(Debug)]
+pub enum LintCommitsError {
+    RepositoryError(RepositoryError),
+}
+
+impl Display for LintCommitsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "failed to lint commits: {}",
+            self.source().expect("source error is always present")
+        )
+    }
+}
+
+impl Error for LintCommitsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::RepositoryError(err) => Some(err.as_ref()),
+        }
+    }
+}
+
+impl From<RepositoryError> for LintCommitsError {
+    fn from(value: RepositoryError) -> Self {
+        Self::RepositoryError(value)
+    }
+}
@@ -0,0 +1,30 @@
+use std::{error::Error, fmt::Display};
+
+use crate::usecase::tag_format::VERSION_PLACEHOLDER;
+
+/// This error may happen during construction of [TagFormat], when
+/// the template does not contain the version placeholder.
+#[derive(Debug)]
+pub struct TagFormatInvariantError {
+    template: String,
+}
+
+impl TagFormatInvariantError {
+    pub fn new(template: &str) -> TagFormatInvariantError {
+        TagFormatInvariantError {
+            template: template.to_string(),
+        }
+    }
+}
+
+impl Display for TagFormatInvariantError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "tag template '{}' did not contain '{}' placeholder",
+            self.template, VERSION_PLACEHOLDER
+        )
+    }
+}
+
+impl Error for TagFormatInvariantError {}
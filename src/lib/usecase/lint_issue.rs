@@ -0,0 +1,28 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintIssue {
+    hash: String,
+    header: String,
+    reason: String,
+}
+
+impl LintIssue {
+    pub fn new(hash: &str, header: &str, reason: &str) -> Self {
+        LintIssue {
+            hash: hash.to_owned(),
+            header: header.to_owned(),
+            reason: reason.to_owned(),
+        }
+    }
+
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+
+    pub fn header(&self) -> &str {
+        &self.header
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
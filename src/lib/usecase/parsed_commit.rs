@@ -0,0 +1,47 @@
+use crate::domain::{commit_policy::CommitPolicy, commit_summary::CommitSummary};
+
+/**
+ * A commit read from the repository, with the outcome of the parsing of its message.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedCommit {
+    hash: String,
+    header: String,
+    summary: Result<CommitSummary, String>,
+}
+
+impl ParsedCommit {
+    pub fn new(hash: &str, header: &str, summary: Result<CommitSummary, String>) -> Self {
+        ParsedCommit {
+            hash: hash.to_owned(),
+            header: header.to_owned(),
+            summary,
+        }
+    }
+
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+
+    pub fn header(&self) -> &str {
+        &self.header
+    }
+
+    pub fn summary(&self) -> Result<&CommitSummary, &str> {
+        self.summary.as_ref().map_err(|e| e.as_str())
+    }
+
+    /**
+     * Returns the reason why the commit is not acceptable, if any.
+     */
+    pub fn violation(&self, policy: &CommitPolicy) -> Option<String> {
+        match self.summary() {
+            Err(e) => Some(e.to_owned()),
+            Ok(CommitSummary::FreeForm(_)) => Some("not a conventional commit".to_owned()),
+            Ok(CommitSummary::Conventional(c)) => policy
+                .check(c.typ(), c.scope())
+                .err()
+                .map(|e| e.to_string()),
+        }
+    }
+}
@@ -1,5 +1,7 @@
 pub mod bounded_commit_summary_ingress_repository;
+pub mod commit_message_ingress_repository;
 pub mod commit_metadata_ingress_repository;
+pub mod commit_range_ingress_repository;
 pub mod conventional_commit_egress_repository;
 pub mod full_commit_summary_history_ingress_repository;
 pub mod git_extra_egress_repository;
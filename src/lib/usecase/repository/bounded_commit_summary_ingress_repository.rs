@@ -1,13 +1,10 @@
-use std::rc::Rc;
-
-use crate::{
-    domain::{commit_summary::CommitSummary, semantic_version::SemanticVersion},
-    usecase::type_aliases::AnyError,
-};
+use crate::{domain::commit::Commit, usecase::type_aliases::AnyError};
 
 pub trait BoundedCommitSummaryIngressRepository {
     fn get_commits_from(
         &self,
-        version: Rc<Option<SemanticVersion>>,
-    ) -> Result<Box<dyn DoubleEndedIterator<Item = CommitSummary>>, AnyError>;
+        from: Option<&str>,
+        to: Option<&str>,
+        paths: &[String],
+    ) -> Result<Box<dyn DoubleEndedIterator<Item = Commit>>, AnyError>;
 }
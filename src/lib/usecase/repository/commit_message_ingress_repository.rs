@@ -0,0 +1,8 @@
+use crate::usecase::{parsed_commit::ParsedCommit, type_aliases::AnyError};
+
+pub trait CommitMessageIngressRepository {
+    /**
+     * Reads a commit message which has not been committed yet, so the hash of the result is empty.
+     */
+    fn get_message(&self, path: &str) -> Result<ParsedCommit, AnyError>;
+}
@@ -0,0 +1,8 @@
+use crate::usecase::{parsed_commit::ParsedCommit, type_aliases::AnyError};
+
+pub trait CommitRangeIngressRepository {
+    fn get_commits_in_range(
+        &self,
+        range: Option<&str>,
+    ) -> Result<Box<dyn Iterator<Item = ParsedCommit>>, AnyError>;
+}
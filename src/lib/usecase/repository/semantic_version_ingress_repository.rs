@@ -1,8 +1,22 @@
 use std::rc::Rc;
 
-use crate::{domain::semantic_version::SemanticVersion, usecase::type_aliases::AnyError};
+use crate::{
+    domain::semantic_version::SemanticVersion,
+    usecase::{tag_format::TagFormat, type_aliases::AnyError},
+};
 
 pub trait SemanticVersionIngressRepository {
-    fn last_version(&self) -> Result<Rc<Option<SemanticVersion>>, AnyError>;
-    fn last_stable_version(&self) -> Result<Rc<Option<SemanticVersion>>, AnyError>;
+    fn last_version(&self, tag_format: &TagFormat)
+        -> Result<Rc<Option<SemanticVersion>>, AnyError>;
+    fn last_stable_version(
+        &self,
+        tag_format: &TagFormat,
+    ) -> Result<Rc<Option<SemanticVersion>>, AnyError>;
+    /**
+     * Returns every released version with its date, from the oldest to the newest.
+     */
+    fn all_versions(
+        &self,
+        tag_format: &TagFormat,
+    ) -> Result<Vec<(SemanticVersion, String)>, AnyError>;
 }
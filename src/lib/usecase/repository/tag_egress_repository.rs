@@ -1,9 +1,13 @@
-use crate::{domain::semantic_version::SemanticVersion, usecase::type_aliases::AnyError};
+use crate::{
+    domain::semantic_version::SemanticVersion,
+    usecase::{tag_format::TagFormat, type_aliases::AnyError},
+};
 
 pub trait TagEgressRepository {
     fn create_tag(
         &self,
         version: &SemanticVersion,
+        tag_format: &TagFormat,
         message: Option<&str>,
         sign: bool,
     ) -> Result<(), AnyError>;
@@ -0,0 +1,112 @@
+use crate::{
+    domain::semantic_version::SemanticVersion,
+    usecase::error::tag_format_invariant_error::TagFormatInvariantError,
+};
+
+pub const VERSION_PLACEHOLDER: &str = "{version}";
+pub const DEFAULT_PACKAGE_TEMPLATE: &str = "v{version}";
+
+/**
+ * How versions are written in tag names, as a template where the version replaces the
+ * placeholder (e.g. 'v{version}' for 'v1.4.0'). By default, tags are bare versions.
+ */
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagFormat {
+    prefix: String,
+    suffix: String,
+}
+
+impl TagFormat {
+    pub fn new(template: &str) -> Result<Self, TagFormatInvariantError> {
+        match template.split_once(VERSION_PLACEHOLDER) {
+            Some((prefix, suffix)) => Ok(TagFormat {
+                prefix: prefix.to_owned(),
+                suffix: suffix.to_owned(),
+            }),
+            None => Err(TagFormatInvariantError::new(template)),
+        }
+    }
+
+    /**
+     * The tags of the versions of a package are prefixed with its name (e.g. 'api/v1.4.0').
+     */
+    pub fn for_package(package: &str, template: &str) -> Result<Self, TagFormatInvariantError> {
+        TagFormat::new(&format!("{}/{}", package, template))
+    }
+
+    pub fn tag(&self, version: &SemanticVersion) -> String {
+        format!("{}{}{}", self.prefix, version, self.suffix)
+    }
+
+    /**
+     * The glob pattern which matches the tags with this format.
+     */
+    pub fn pattern(&self) -> String {
+        format!("{}*{}", self.prefix, self.suffix)
+    }
+
+    /**
+     * The version part of the tag, if the tag has this format.
+     */
+    pub fn version<'a>(&self, tag: &'a str) -> Option<&'a str> {
+        tag.strip_prefix(&self.prefix)
+            .and_then(|it| it.strip_suffix(&self.suffix))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::semantic_version::SemanticVersion;
+
+    use super::{TagFormat, DEFAULT_PACKAGE_TEMPLATE};
+
+    #[test]
+    fn tag_default() {
+        let version = SemanticVersion::new(1, 4, 0, None, None)
+            .expect("Hand-crafted version is always correct");
+        assert_eq!(TagFormat::default().tag(&version), "1.4.0");
+    }
+
+    #[test]
+    fn tag_template() {
+        let version = SemanticVersion::new(1, 4, 0, None, None)
+            .expect("Hand-crafted version is always correct");
+        let format = TagFormat::new("release-{version}").expect("The template has a placeholder");
+        assert_eq!(format.tag(&version), "release-1.4.0");
+    }
+
+    #[test]
+    fn template_without_placeholder() {
+        assert!(TagFormat::new("v").is_err());
+    }
+
+    #[test]
+    fn tag_package() {
+        let version = SemanticVersion::new(1, 4, 0, None, None)
+            .expect("Hand-crafted version is always correct");
+        let format = TagFormat::for_package("api", DEFAULT_PACKAGE_TEMPLATE)
+            .expect("The template has a placeholder");
+        assert_eq!(format.tag(&version), "api/v1.4.0");
+        let format = TagFormat::for_package("api", "release-{version}")
+            .expect("The template has a placeholder");
+        assert_eq!(format.tag(&version), "api/release-1.4.0");
+    }
+
+    #[test]
+    fn version_template() {
+        let format = TagFormat::new("v{version}-final").expect("The template has a placeholder");
+        assert_eq!(format.pattern(), "v*-final");
+        assert_eq!(format.version("v1.4.0-final"), Some("1.4.0"));
+        assert_eq!(format.version("v1.4.0"), None);
+        assert_eq!(format.version("1.4.0-final"), None);
+    }
+
+    #[test]
+    fn version_package() {
+        let format = TagFormat::for_package("api", DEFAULT_PACKAGE_TEMPLATE)
+            .expect("The template has a placeholder");
+        assert_eq!(format.pattern(), "api/v*");
+        assert_eq!(format.version("api/v1.4.0"), Some("1.4.0"));
+        assert_eq!(format.version("web/v1.4.0"), None);
+    }
+}
@@ -1,8 +1,11 @@
 pub mod create_changelog;
 pub mod create_conventional_commit;
+pub mod create_full_changelog;
 pub mod create_license;
 pub mod create_tag;
 pub mod describe_new_version;
 pub mod format_tree_graph;
+pub mod lint_commits;
+pub mod lint_message;
 pub mod refresh_types_and_scopes;
 pub mod usecase;
@@ -1,16 +1,15 @@
 use ahash::{AHashMap, RandomState};
 
 use crate::{
-    domain::{
-        commit_summary::CommitSummary,
-        conventional_commit_summary::{
-            ConventionalCommitSummary, ConventionalCommitSummaryBreakingFlag,
-        },
-        semantic_version::SemanticVersion,
-        trigger::Trigger,
-    },
+    domain::{commit::Commit, commit_summary::CommitSummary, trigger::Trigger},
     usecase::{
-        configuration::changelog::{ChangelogConfiguration, ChangelogFormat},
+        changelog::{
+            Changelog, ChangelogBound, ChangelogCommit, ChangelogGroups, ChangelogIssue,
+            ChangelogScope, ChangelogScopeGroup, ChangelogType, ChangelogTypeGroup, NO_SCOPE_TITLE,
+        },
+        configuration::changelog::{
+            ChangelogConfiguration, ChangelogGroupBy, ChangelogLinks, ChangelogOrder,
+        },
         error::changelog_creation_error::ChangelogCreationError,
         repository::{
             bounded_commit_summary_ingress_repository::BoundedCommitSummaryIngressRepository,
@@ -21,14 +20,14 @@ use crate::{
 };
 
 pub struct CreateChangelogUseCase<'a> {
-    configuration: ChangelogConfiguration<'a>,
+    configuration: ChangelogConfiguration,
     commit_repository: &'a dyn BoundedCommitSummaryIngressRepository,
     version_repository: &'a dyn SemanticVersionIngressRepository,
 }
 
 impl<'a, 'b: 'a, 'c: 'a> CreateChangelogUseCase<'a> {
     pub fn new(
-        configuration: ChangelogConfiguration<'a>,
+        configuration: ChangelogConfiguration,
         commit_repository: &'b dyn BoundedCommitSummaryIngressRepository,
         version_repository: &'c dyn SemanticVersionIngressRepository,
     ) -> Self {
@@ -40,79 +39,176 @@ impl<'a, 'b: 'a, 'c: 'a> CreateChangelogUseCase<'a> {
     }
 }
 
-impl UseCase<String, ChangelogCreationError> for CreateChangelogUseCase<'_> {
-    fn execute(&self) -> Result<String, ChangelogCreationError> {
-        let from_version = if self.configuration.generate_from_latest_version() {
-            self.version_repository.last_version()?
-        } else {
-            self.version_repository.last_stable_version()?
+impl UseCase<Changelog, ChangelogCreationError> for CreateChangelogUseCase<'_> {
+    fn execute(&self) -> Result<Changelog, ChangelogCreationError> {
+        let range = self.configuration.range();
+        let from = match (range.from(), range.to()) {
+            (Some(from), _) => Some(from.clone()),
+            // An explicit end without start covers the whole history up to it
+            (None, Some(_)) => None,
+            (None, None) => {
+                let from_version = if range.generate_from_latest_version() {
+                    self.version_repository.last_version(range.tag_format())?
+                } else {
+                    self.version_repository
+                        .last_stable_version(range.tag_format())?
+                };
+                from_version.as_ref().clone().map(ChangelogBound::Version)
+            }
         };
-        let commit_list = self
-            .commit_repository
-            .get_commits_from(from_version.clone())?;
-
-        let type_map = categorize_commit_list(commit_list, self.configuration.exclude_trigger());
-        let text = format_types(self.configuration.format(), &type_map);
-        let title = format_title(self.configuration.format(), from_version.as_ref().as_ref());
-        Ok(format!("{}\n{}", title, text))
+        let to = range.to().cloned();
+        let commit_list = self.commit_repository.get_commits_from(
+            from.as_ref()
+                .map(|it| it.git_ref(range.tag_format()))
+                .as_deref(),
+            to.as_ref()
+                .map(|it| it.git_ref(range.tag_format()))
+                .as_deref(),
+            range.paths(),
+        )?;
+
+        let types = changelog_types(commit_list, &self.configuration);
+        Ok(Changelog::new(
+            self.configuration.version().cloned(),
+            None,
+            from,
+            to,
+            breaking_changes(&types, &self.configuration),
+            changelog_groups(types, &self.configuration),
+        ))
     }
 }
 
-#[inline(always)]
-fn format_title(format: &ChangelogFormat, version: Option<&SemanticVersion>) -> String {
-    let title = match version {
-        Some(v) => format!("Changes from version {}", v),
-        None => "Latest changes".to_string(),
-    };
-    format.title()(&title)
+/**
+ * Groups the commits by type and scope, in the order they are presented with.
+ */
+pub fn changelog_types(
+    list: impl Iterator<Item = Commit>,
+    configuration: &ChangelogConfiguration,
+) -> Vec<ChangelogType> {
+    sort_types(
+        categorize_commit_list(list, configuration.exclude_trigger(), configuration.links()),
+        configuration.order(),
+    )
+}
+
+/**
+ * The breaking commits of every type, if the configuration asks for a section of their own.
+ */
+pub fn breaking_changes(
+    types: &[ChangelogType],
+    configuration: &ChangelogConfiguration,
+) -> Vec<ChangelogCommit> {
+    if !configuration.breaking_section() {
+        return vec![];
+    }
+    types
+        .iter()
+        .flat_map(|it| it.scopes())
+        .flat_map(|it| it.commits())
+        .filter(|it| it.breaking())
+        .cloned()
+        .collect()
+}
+
+/**
+ * Arranges the types as the configuration asks: as they are, pivoted by scope, or as a single
+ * list of commits. The order of the types is kept in every scope.
+ */
+pub fn changelog_groups(
+    types: Vec<ChangelogType>,
+    configuration: &ChangelogConfiguration,
+) -> ChangelogGroups {
+    match configuration.group_by() {
+        ChangelogGroupBy::Type => ChangelogGroups::ByType(types),
+        ChangelogGroupBy::Scope => ChangelogGroups::ByScope(group_by_scope(
+            &types,
+            configuration.order().general_scope_last(),
+        )),
+        ChangelogGroupBy::None => ChangelogGroups::Ungrouped(
+            types
+                .iter()
+                .flat_map(|it| it.scopes())
+                .flat_map(|it| it.commits())
+                .cloned()
+                .collect(),
+        ),
+    }
 }
 
-const NO_SCOPE_TITLE: &str = "General";
 const NON_CONVENTIONAL_TYPE: &str = "NON CONVENTIONAL";
+const DEFAULT_TYPE_ORDER: [&str; 2] = ["feat", "fix"];
 
 const HASH_RANDOM_STATE: RandomState = RandomState::with_seeds(0, 0, 0, 0);
-type ScopeMap = AHashMap<String, Vec<ConventionalCommitSummary>>;
+type ScopeMap = AHashMap<String, Vec<ChangelogCommit>>;
 type TypeMap = AHashMap<String, ScopeMap>;
 
 #[inline(always)]
 fn categorize_commit_list(
-    list: impl Iterator<Item = CommitSummary>,
+    list: impl Iterator<Item = Commit>,
     exclude_trigger: Option<&Trigger>,
+    links: &ChangelogLinks,
 ) -> TypeMap {
     let mut types_map: TypeMap = AHashMap::with_hasher(HASH_RANDOM_STATE);
     list.for_each(|c| {
-        let surely_conventional = match c {
-            CommitSummary::Conventional(commit) => commit,
-            CommitSummary::FreeForm(free_form) => ConventionalCommitSummary::new(
-                NON_CONVENTIONAL_TYPE.to_owned(),
-                None,
-                ConventionalCommitSummaryBreakingFlag::Disabled,
-                free_form,
-            )
-            .expect("Hand-crafted conventional commit (from free-form) is expected to be correct"),
+        let (typ, scope, item) = match c.summary() {
+            CommitSummary::Conventional(commit) => {
+                if exclude_trigger
+                    .is_some_and(|it| it.accept(commit.typ(), commit.scope(), commit.breaking()))
+                {
+                    return;
+                }
+                (
+                    commit.typ(),
+                    scope_or_general(commit.scope()),
+                    changelog_commit(&c, commit.summary(), commit.breaking(), links),
+                )
+            }
+            CommitSummary::FreeForm(free_form) => (
+                NON_CONVENTIONAL_TYPE,
+                NO_SCOPE_TITLE,
+                changelog_commit(&c, free_form, false, links),
+            ),
         };
-        if !exclude_trigger.as_ref().is_some_and(|it| {
-            it.accept(
-                surely_conventional.typ(),
-                surely_conventional.scope(),
-                surely_conventional.breaking(),
-            )
-        }) {
-            ensure_inner_map_exists(&mut types_map, surely_conventional.typ());
-            let scopes_map = types_map
-                .get_mut(surely_conventional.typ())
-                .expect("The map is ensured to exist");
-            let scope = scope_or_general(surely_conventional.scope());
-            ensure_inner_vector_exists(scopes_map, &scope.to_owned());
-            scopes_map
-                .get_mut(scope)
-                .expect("The vector is ensured to exist")
-                .push(surely_conventional);
-        }
+        ensure_inner_map_exists(&mut types_map, typ);
+        let scopes_map = types_map.get_mut(typ).expect("The map is ensured to exist");
+        ensure_inner_vector_exists(scopes_map, &scope.to_owned());
+        scopes_map
+            .get_mut(scope)
+            .expect("The vector is ensured to exist")
+            .push(item);
     });
     types_map
 }
 
+/**
+ * A 'BREAKING CHANGE' footer makes the commit breaking even without the '!' in the summary.
+ */
+#[inline(always)]
+fn changelog_commit(
+    commit: &Commit,
+    summary: &str,
+    breaking: bool,
+    links: &ChangelogLinks,
+) -> ChangelogCommit {
+    let breaking_note = commit.breaking_note().map(str::to_owned);
+    ChangelogCommit::new(
+        commit.hash().to_owned(),
+        summary.to_owned(),
+        breaking || breaking_note.is_some(),
+        breaking_note,
+        links.commit_url(commit.hash()),
+        commit
+            .referenced_issues()
+            .into_iter()
+            .map(|it| {
+                let url = links.issue_url(&it);
+                ChangelogIssue::new(it, url)
+            })
+            .collect(),
+    )
+}
+
 #[inline(always)]
 fn ensure_inner_map_exists(types_map: &mut TypeMap, t: &str) {
     if !types_map.contains_key(t) {
@@ -127,88 +223,92 @@ fn ensure_inner_vector_exists(scope_map: &mut ScopeMap, s: &String) {
     }
 }
 
+/**
+ * The declared types come first (features and fixes if none is declared), then the others in
+ * alphabetical order; non-conventional commits are always last.
+ */
 #[inline]
-fn format_types(format: &ChangelogFormat, types_map: &TypeMap) -> String {
-    let feat_scopes = types_map.get("feat").map_or(String::new(), |scope_map| {
-        format!(
-            "{}\n{}\n\n",
-            format.typ()(&"feat".to_owned()),
-            format_scopes(format, scope_map)
-        )
-    });
-
-    let fix_scopes = types_map.get("fix").map_or(String::new(), |scope_map| {
-        format!(
-            "{}\n{}\n\n",
-            format.typ()(&"fix".to_owned()),
-            format_scopes(format, scope_map)
-        )
-    });
-
-    let non_conventional =
-        types_map
-            .get(NON_CONVENTIONAL_TYPE)
-            .map_or(String::new(), |scope_map| {
-                format!(
-                    "{}\n{}\n\n",
-                    format.typ()(&NON_CONVENTIONAL_TYPE.to_owned()),
-                    format_scopes(format, scope_map)
-                )
-            });
-
-    feat_scopes
-        + &fix_scopes
-        + &types_map
+fn sort_types(mut types_map: TypeMap, order: &ChangelogOrder) -> Vec<ChangelogType> {
+    let declared: Vec<(String, String)> = if order.types().is_empty() {
+        DEFAULT_TYPE_ORDER
             .iter()
-            .filter(|(key, _)| *key != "feat" && *key != "fix")
-            .map(|(key, value)| {
-                format!("{}\n{}\n", format.typ()(key), format_scopes(format, value))
+            .map(|it| (it.to_string(), it.to_string()))
+            .collect()
+    } else {
+        order.types().to_vec()
+    };
+    let general_scope_last = order.general_scope_last();
+    let mut types: Vec<ChangelogType> = declared
+        .into_iter()
+        .filter_map(|(typ, title)| {
+            types_map.remove(&typ).map(|scopes| {
+                ChangelogType::new(typ, title, sort_scopes(scopes, general_scope_last))
             })
-            .reduce(|acc, e| acc + "\n" + &e)
-            .unwrap_or_else(String::new)
-        + &non_conventional
-}
-
-#[inline]
-fn format_scopes(format: &ChangelogFormat, scope_map: &ScopeMap) -> String {
-    scope_map
-        .iter()
-        .map(|(key, value)| {
-            format!(
-                "{}\n{}",
-                format.scope()(key),
-                format_list(format, value.iter())
-            )
         })
-        .reduce(|acc, e| acc + "\n" + &e)
-        .unwrap_or_else(String::new)
+        .collect();
+    let non_conventional = types_map.remove_entry(NON_CONVENTIONAL_TYPE);
+    let mut others: Vec<(String, ScopeMap)> = types_map.into_iter().collect();
+    others.sort_by(|(a, _), (b, _)| a.cmp(b));
+    types.extend(
+        others
+            .into_iter()
+            .chain(non_conventional)
+            .map(|(typ, scopes)| {
+                ChangelogType::new(typ.clone(), typ, sort_scopes(scopes, general_scope_last))
+            }),
+    );
+    types
 }
 
 #[inline]
-fn format_list<'a>(
-    format: &ChangelogFormat,
-    commit_list: impl Iterator<Item = &'a ConventionalCommitSummary>,
-) -> String {
-    format.list()(
-        &commit_list
-            .map(|c| format_item(format, c))
-            .reduce(|acc, e| acc + "\n" + &e)
-            .unwrap_or_else(String::new),
-    )
+fn sort_scopes(scope_map: ScopeMap, general_scope_last: bool) -> Vec<ChangelogScope> {
+    let mut scopes: Vec<ChangelogScope> = scope_map
+        .into_iter()
+        .map(|(scope, commits)| {
+            ChangelogScope::new(Some(scope).filter(|it| it != NO_SCOPE_TITLE), commits)
+        })
+        .collect();
+    scopes.sort_by(|a, b| {
+        scope_order(a.name(), general_scope_last).cmp(&scope_order(b.name(), general_scope_last))
+    });
+    scopes
 }
 
 #[inline]
-fn format_item(format: &ChangelogFormat, commit: &ConventionalCommitSummary) -> String {
-    format.item()(&format_details(format, commit))
+fn group_by_scope(types: &[ChangelogType], general_scope_last: bool) -> Vec<ChangelogScopeGroup> {
+    let mut scopes: Vec<(Option<String>, Vec<ChangelogTypeGroup>)> = vec![];
+    types.iter().for_each(|typ| {
+        typ.scopes().iter().for_each(|scope| {
+            let group = ChangelogTypeGroup::new(
+                typ.name().to_owned(),
+                typ.title().to_owned(),
+                scope.commits().to_vec(),
+            );
+            match scopes
+                .iter_mut()
+                .find(|(name, _)| name.as_deref() == scope.name())
+            {
+                Some((_, groups)) => groups.push(group),
+                None => scopes.push((scope.name().map(str::to_owned), vec![group])),
+            }
+        })
+    });
+    scopes.sort_by(|(a, _), (b, _)| {
+        scope_order(a.as_deref(), general_scope_last)
+            .cmp(&scope_order(b.as_deref(), general_scope_last))
+    });
+    scopes
+        .into_iter()
+        .map(|(name, types)| ChangelogScopeGroup::new(name, types))
+        .collect()
 }
 
-#[inline]
-fn format_details(format: &ChangelogFormat, commit: &ConventionalCommitSummary) -> String {
-    if commit.breaking() {
-        format.breaking()(&commit.summary().to_string())
-    } else {
-        commit.summary().to_string()
-    }
+/**
+ * The first element of the key places the commits without scope before or after the others.
+ */
+#[inline(always)]
+fn scope_order(name: Option<&str>, general_scope_last: bool) -> (bool, Option<&str>) {
+    (general_scope_last == name.is_none(), name)
 }
 
 #[inline(always)]
@@ -225,6 +325,8 @@ mod tests {
 
     use crate::{
         domain::{
+            commit::Commit,
+            commit_footer::CommitFooter,
             commit_summary::CommitSummary,
             conventional_commit_summary::{
                 ConventionalCommitSummary, ConventionalCommitSummaryBreakingFlag,
@@ -233,44 +335,31 @@ mod tests {
             trigger::{self, BasicStatement, Trigger},
         },
         usecase::{
-            configuration::changelog::{ChangelogConfiguration, ChangelogFormat},
+            changelog::{
+                ChangelogBound, ChangelogCommit, ChangelogGroups, ChangelogIssue, NO_SCOPE_TITLE,
+            },
+            configuration::changelog::{
+                ChangelogConfiguration, ChangelogFormat, ChangelogGroupBy, ChangelogLinks,
+                ChangelogOrder, ChangelogRange,
+            },
             repository::{
                 bounded_commit_summary_ingress_repository::BoundedCommitSummaryIngressRepository,
                 semantic_version_ingress_repository::SemanticVersionIngressRepository,
             },
+            tag_format::TagFormat,
             type_aliases::AnyError,
             usecases::{
                 create_changelog::{
-                    categorize_commit_list, ensure_inner_map_exists, ensure_inner_vector_exists,
-                    format_details, format_item, format_list, format_scopes, format_title,
-                    format_types, scope_or_general, CreateChangelogUseCase, ScopeMap, TypeMap,
-                    HASH_RANDOM_STATE, NO_SCOPE_TITLE,
+                    breaking_changes, categorize_commit_list, changelog_commit, changelog_groups,
+                    changelog_types, ensure_inner_map_exists, ensure_inner_vector_exists,
+                    scope_or_general, sort_types, CreateChangelogUseCase, TypeMap,
+                    HASH_RANDOM_STATE, NON_CONVENTIONAL_TYPE,
                 },
                 usecase::UseCase,
             },
         },
     };
 
-    fn complete_commit() -> ConventionalCommitSummary {
-        ConventionalCommitSummary::new(
-            "feat".to_string(),
-            Some("API".to_string()),
-            ConventionalCommitSummaryBreakingFlag::Disabled,
-            "test message #1".to_string(),
-        )
-        .expect("Hand-crafted conventional commit summary is always correct")
-    }
-
-    fn breaking_commit() -> ConventionalCommitSummary {
-        ConventionalCommitSummary::new(
-            "feat".to_string(),
-            Some("API".to_string()),
-            ConventionalCommitSummaryBreakingFlag::Enabled,
-            "test message #1".to_string(),
-        )
-        .expect("Hand-crafted conventional commit summary is always correct")
-    }
-
     fn commit_list() -> Vec<ConventionalCommitSummary> {
         vec![
             ConventionalCommitSummary::new(
@@ -325,6 +414,19 @@ mod tests {
         ]
     }
 
+    fn item(commit: ConventionalCommitSummary) -> ChangelogCommit {
+        changelog_commit(
+            &Commit::new(
+                String::new(),
+                CommitSummary::Conventional(commit.clone()),
+                vec![],
+            ),
+            commit.summary(),
+            commit.breaking(),
+            &ChangelogLinks::default(),
+        )
+    }
+
     fn format() -> ChangelogFormat<'static> {
         ChangelogFormat::new(
             Box::new(|t| format!("# {}", t)),
@@ -333,53 +435,245 @@ mod tests {
             Box::new(|l| l.to_string()),
             Box::new(|i| format!("* {}", i)),
             Box::new(|b| format!("**{}**", b)),
+            None,
         )
     }
 
     #[test]
-    fn format_details_not_breaking() {
-        let c = complete_commit();
-        let s = format_details(&format(), &c);
-        assert_eq!(s, "test message #1".to_string());
+    fn sort_types_declared_order() {
+        let types_map = categorize_commit_list(
+            commit_list()
+                .into_iter()
+                .map(|c| Commit::new(String::new(), CommitSummary::Conventional(c), vec![])),
+            None,
+            &ChangelogLinks::default(),
+        );
+        let order = ChangelogOrder::new(
+            vec![
+                ("test".to_string(), "Tests".to_string()),
+                ("feat".to_string(), "Features".to_string()),
+            ],
+            true,
+        );
+        let types = sort_types(types_map, &order);
+        assert_eq!(
+            types
+                .iter()
+                .map(|it| (it.name(), it.title()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("test", "Tests"),
+                ("feat", "Features"),
+                ("docs", "docs"),
+                ("fix", "fix"),
+                ("refactor", "refactor"),
+            ]
+        );
+        assert_eq!(
+            types[0]
+                .scopes()
+                .iter()
+                .map(|it| it.name())
+                .collect::<Vec<_>>(),
+            vec![Some("API"), None]
+        );
+    }
+
+    #[test]
+    fn changelog_commit_with_links() {
+        let commit = Commit::new(
+            "0123456789abcdef".to_string(),
+            CommitSummary::FreeForm("Merge pull request #7".to_string()),
+            vec![],
+        );
+        let links = ChangelogLinks::new(
+            Some("https://example.com/commit/{hash}".to_string()),
+            Some("https://example.com/issues/{issue}".to_string()),
+        );
+        let item = changelog_commit(&commit, "Merge pull request #7", false, &links);
+        assert_eq!(
+            item.url(),
+            Some("https://example.com/commit/0123456789abcdef")
+        );
+        assert_eq!(
+            item.issues(),
+            &[ChangelogIssue::new(
+                "7".to_string(),
+                Some("https://example.com/issues/7".to_string())
+            )]
+        );
     }
 
     #[test]
-    fn format_details_breaking() {
-        let c = breaking_commit();
-        let s = format_details(&format(), &c);
-        assert_eq!(s, "**test message #1**".to_string());
+    fn breaking_changes_collected_across_types() {
+        let list = vec![
+            Commit::new(
+                String::new(),
+                CommitSummary::Conventional(commit_list()[0].clone()),
+                vec![],
+            ),
+            Commit::new(
+                String::new(),
+                CommitSummary::Conventional(
+                    ConventionalCommitSummary::new(
+                        "fix".to_string(),
+                        None,
+                        ConventionalCommitSummaryBreakingFlag::Enabled,
+                        "drop old endpoint".to_string(),
+                    )
+                    .expect("Hand-crafted conventional commit summary is always correct"),
+                ),
+                vec![],
+            ),
+            Commit::new(
+                String::new(),
+                CommitSummary::Conventional(commit_list()[2].clone()),
+                vec![CommitFooter::new(
+                    "BREAKING CHANGE".to_string(),
+                    "run the tests with the new runner".to_string(),
+                )
+                .expect("Hand-crafted footer is correct")],
+            ),
+        ];
+        let configuration = ChangelogConfiguration::new(
+            ChangelogRange::default(),
+            None,
+            ChangelogOrder::default(),
+            ChangelogLinks::default(),
+            true,
+            ChangelogGroupBy::Type,
+            None,
+        );
+        let types = changelog_types(list.into_iter(), &configuration);
+        let breaking = breaking_changes(&types, &configuration);
+        assert_eq!(
+            breaking
+                .iter()
+                .map(|it| (it.summary(), it.breaking_note()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("drop old endpoint", None),
+                ("test message #3", Some("run the tests with the new runner")),
+            ]
+        );
     }
 
     #[test]
-    fn format_item_basic() {
-        let c = complete_commit();
-        let s = format_item(&format(), &c);
-        assert_eq!(s, "* test message #1".to_string());
+    fn breaking_changes_disabled() {
+        let list = vec![Commit::new(
+            String::new(),
+            CommitSummary::Conventional(
+                ConventionalCommitSummary::new(
+                    "fix".to_string(),
+                    None,
+                    ConventionalCommitSummaryBreakingFlag::Enabled,
+                    "drop old endpoint".to_string(),
+                )
+                .expect("Hand-crafted conventional commit summary is always correct"),
+            ),
+            vec![],
+        )];
+        let configuration = ChangelogConfiguration::new(
+            ChangelogRange::default(),
+            None,
+            ChangelogOrder::default(),
+            ChangelogLinks::default(),
+            false,
+            ChangelogGroupBy::Type,
+            None,
+        );
+        let types = changelog_types(list.into_iter(), &configuration);
+        assert!(breaking_changes(&types, &configuration).is_empty());
     }
 
     #[test]
-    fn format_list_basic() {
-        let l = commit_list();
-        let s = format_list(&format(), l.iter());
+    fn changelog_groups_by_scope() {
+        let configuration = ChangelogConfiguration::new(
+            ChangelogRange::default(),
+            None,
+            ChangelogOrder::default(),
+            ChangelogLinks::default(),
+            false,
+            ChangelogGroupBy::Scope,
+            None,
+        );
+        let types = changelog_types(
+            commit_list()
+                .into_iter()
+                .map(|c| Commit::new(String::new(), CommitSummary::Conventional(c), vec![])),
+            &configuration,
+        );
+        let scopes = match changelog_groups(types, &configuration) {
+            ChangelogGroups::ByScope(scopes) => scopes,
+            _ => panic!("The changelog is grouped by scope"),
+        };
         assert_eq!(
-            s,
-            format!(
-                "{}\n{}\n{}\n{}\n{}\n{}\n{}",
-                "* test message #1",
-                "* test message #2",
-                "* test message #3",
-                "* test message #4",
-                "* test message #5",
-                "* test message #6",
-                "* test message #7",
-            )
-        )
+            scopes
+                .iter()
+                .map(|scope| (
+                    scope.name(),
+                    scope
+                        .types()
+                        .iter()
+                        .map(|typ| (typ.name(), typ.commits()[0].summary()))
+                        .collect::<Vec<_>>()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    None,
+                    vec![
+                        ("feat", "test message #6"),
+                        ("docs", "test message #5"),
+                        ("test", "test message #3"),
+                    ]
+                ),
+                (
+                    Some("API"),
+                    vec![
+                        ("feat", "test message #1"),
+                        ("fix", "test message #2"),
+                        ("test", "test message #7"),
+                    ]
+                ),
+                (Some("exclude"), vec![("refactor", "test message #4")]),
+            ]
+        );
     }
 
     #[test]
-    fn format_list_empty() {
-        let s = format_list(&format(), [].iter());
-        assert_eq!(s, "".to_string());
+    fn changelog_groups_none() {
+        let configuration = ChangelogConfiguration::new(
+            ChangelogRange::default(),
+            None,
+            ChangelogOrder::default(),
+            ChangelogLinks::default(),
+            false,
+            ChangelogGroupBy::None,
+            None,
+        );
+        let types = changelog_types(
+            commit_list()
+                .into_iter()
+                .map(|c| Commit::new(String::new(), CommitSummary::Conventional(c), vec![])),
+            &configuration,
+        );
+        let commits = match changelog_groups(types, &configuration) {
+            ChangelogGroups::Ungrouped(commits) => commits,
+            _ => panic!("The changelog is not grouped"),
+        };
+        assert_eq!(
+            commits.iter().map(|it| it.summary()).collect::<Vec<_>>(),
+            vec![
+                "test message #6",
+                "test message #1",
+                "test message #2",
+                "test message #5",
+                "test message #4",
+                "test message #3",
+                "test message #7",
+            ]
+        );
     }
 
     #[test]
@@ -394,40 +688,18 @@ mod tests {
         assert_eq!(s, NO_SCOPE_TITLE.to_owned());
     }
 
-    #[test]
-    fn format_scopes_basic() {
-        let mut scope_map: ScopeMap = AHashMap::with_hasher(HASH_RANDOM_STATE);
-        let l = commit_list();
-        l.iter().for_each(|c| {
-            let scope = scope_or_general(c.scope());
-            ensure_inner_vector_exists(&mut scope_map, &scope.to_owned());
-            scope_map.get_mut(scope).unwrap().push(c.clone());
-        });
-        let s = format_scopes(&format(), &scope_map);
-        assert_eq!(
-            s,
-            concat!(
-                "### API\n",
-                "* test message #1\n",
-                "* test message #2\n",
-                "* test message #7\n",
-                "### exclude\n",
-                "* test message #4\n",
-                "### General\n",
-                "* test message #3\n",
-                "* test message #5\n",
-                "* test message #6"
-            )
-        );
-    }
-
     #[test]
     fn categorize_commit_list_basic() {
         let m = categorize_commit_list(
-            commit_list()
-                .iter()
-                .map(|it| CommitSummary::Conventional(it.clone())),
+            commit_list().iter().map(|it| {
+                Commit::new(
+                    String::new(),
+                    CommitSummary::Conventional(it.clone()),
+                    vec![],
+                )
+            }),
             None,
+            &ChangelogLinks::default(),
         );
         let expected = {
             let mut temp: TypeMap = AHashMap::with_hasher(HASH_RANDOM_STATE);
@@ -438,7 +710,7 @@ mod tests {
             feat_commits
                 .get_mut("API")
                 .expect("Vector just created")
-                .push(
+                .push(item(
                     ConventionalCommitSummary::new(
                         "feat".to_string(),
                         Some("API".to_string()),
@@ -446,12 +718,12 @@ mod tests {
                         "test message #1".to_string(),
                     )
                     .expect("Hand-crafted conventional commit summary is always correct"),
-                );
+                ));
             ensure_inner_vector_exists(feat_commits, &NO_SCOPE_TITLE.to_string());
             feat_commits
                 .get_mut(NO_SCOPE_TITLE)
                 .expect("Vector just created")
-                .push(
+                .push(item(
                     ConventionalCommitSummary::new(
                         "feat".to_string(),
                         None,
@@ -459,7 +731,7 @@ mod tests {
                         "test message #6".to_string(),
                     )
                     .expect("Hand-crafted conventional commit summary is always correct"),
-                );
+                ));
 
             ensure_inner_map_exists(&mut temp, "fix");
             let fix_commits = temp.get_mut("fix").expect("Map just created");
@@ -467,7 +739,7 @@ mod tests {
             fix_commits
                 .get_mut("API")
                 .expect("Vector just created")
-                .push(
+                .push(item(
                     ConventionalCommitSummary::new(
                         "fix".to_string(),
                         Some("API".to_string()),
@@ -475,7 +747,7 @@ mod tests {
                         "test message #2".to_string(),
                     )
                     .expect("Hand-crafted conventional commit summary is always correct"),
-                );
+                ));
 
             ensure_inner_map_exists(&mut temp, "test");
             let test_commits = temp.get_mut("test").expect("Map just created");
@@ -483,7 +755,7 @@ mod tests {
             test_commits
                 .get_mut("API")
                 .expect("Vector just created")
-                .push(
+                .push(item(
                     ConventionalCommitSummary::new(
                         "test".to_string(),
                         Some("API".to_string()),
@@ -491,12 +763,12 @@ mod tests {
                         "test message #7".to_string(),
                     )
                     .expect("Hand-crafted conventional commit summary is always correct"),
-                );
+                ));
             ensure_inner_vector_exists(test_commits, &NO_SCOPE_TITLE.to_string());
             test_commits
                 .get_mut(NO_SCOPE_TITLE)
                 .expect("Vector just created")
-                .push(
+                .push(item(
                     ConventionalCommitSummary::new(
                         "test".to_string(),
                         None,
@@ -504,7 +776,7 @@ mod tests {
                         "test message #3".to_string(),
                     )
                     .expect("Hand-crafted conventional commit summary is always correct"),
-                );
+                ));
 
             ensure_inner_map_exists(&mut temp, "refactor");
             let refactor_commits = temp.get_mut("refactor").expect("Map just created");
@@ -512,7 +784,7 @@ mod tests {
             refactor_commits
                 .get_mut("exclude")
                 .expect("Vector just created")
-                .push(
+                .push(item(
                     ConventionalCommitSummary::new(
                         "refactor".to_string(),
                         Some("exclude".to_string()),
@@ -520,7 +792,7 @@ mod tests {
                         "test message #4".to_string(),
                     )
                     .expect("Hand-crafted conventional commit summary is always correct"),
-                );
+                ));
 
             ensure_inner_map_exists(&mut temp, "docs");
             let docs_commits = temp.get_mut("docs").expect("Map just created");
@@ -528,7 +800,7 @@ mod tests {
             docs_commits
                 .get_mut(NO_SCOPE_TITLE)
                 .expect("Vector just created")
-                .push(
+                .push(item(
                     ConventionalCommitSummary::new(
                         "docs".to_string(),
                         None,
@@ -536,7 +808,7 @@ mod tests {
                         "test message #5".to_string(),
                     )
                     .expect("Hand-crafted conventional commit summary is always correct"),
-                );
+                ));
             temp
         };
         assert_eq!(m, expected);
@@ -545,9 +817,13 @@ mod tests {
     #[test]
     fn categorize_commit_list_with_exclude_trigger() {
         let m = categorize_commit_list(
-            commit_list()
-                .iter()
-                .map(|it| CommitSummary::Conventional(it.clone())),
+            commit_list().iter().map(|it| {
+                Commit::new(
+                    String::new(),
+                    CommitSummary::Conventional(it.clone()),
+                    vec![],
+                )
+            }),
             Some(Trigger::new(trigger::Start::Basic(BasicStatement::In(
                 trigger::InNode {
                     object: trigger::ObjectNode::Scope(trigger::ScopeNode {}),
@@ -557,6 +833,7 @@ mod tests {
                 },
             ))))
             .as_ref(),
+            &ChangelogLinks::default(),
         );
         let expected = {
             let mut temp: TypeMap = AHashMap::with_hasher(HASH_RANDOM_STATE);
@@ -567,7 +844,7 @@ mod tests {
             feat_commits
                 .get_mut("API")
                 .expect("Vector just created")
-                .push(
+                .push(item(
                     ConventionalCommitSummary::new(
                         "feat".to_string(),
                         Some("API".to_string()),
@@ -575,12 +852,12 @@ mod tests {
                         "test message #1".to_string(),
                     )
                     .expect("Hand-crafted conventional commit summary is always correct"),
-                );
+                ));
             ensure_inner_vector_exists(feat_commits, &NO_SCOPE_TITLE.to_string());
             feat_commits
                 .get_mut(NO_SCOPE_TITLE)
                 .expect("Vector just created")
-                .push(
+                .push(item(
                     ConventionalCommitSummary::new(
                         "feat".to_string(),
                         None,
@@ -588,7 +865,7 @@ mod tests {
                         "test message #6".to_string(),
                     )
                     .expect("Hand-crafted conventional commit summary is always correct"),
-                );
+                ));
 
             ensure_inner_map_exists(&mut temp, "fix");
             let fix_commits = temp.get_mut("fix").expect("Map just created");
@@ -596,7 +873,7 @@ mod tests {
             fix_commits
                 .get_mut("API")
                 .expect("Vector just created")
-                .push(
+                .push(item(
                     ConventionalCommitSummary::new(
                         "fix".to_string(),
                         Some("API".to_string()),
@@ -604,7 +881,7 @@ mod tests {
                         "test message #2".to_string(),
                     )
                     .expect("Hand-crafted conventional commit summary is always correct"),
-                );
+                ));
 
             ensure_inner_map_exists(&mut temp, "test");
             let test_commits = temp.get_mut("test").expect("Map just created");
@@ -612,7 +889,7 @@ mod tests {
             test_commits
                 .get_mut("API")
                 .expect("Vector just created")
-                .push(
+                .push(item(
                     ConventionalCommitSummary::new(
                         "test".to_string(),
                         Some("API".to_string()),
@@ -620,12 +897,12 @@ mod tests {
                         "test message #7".to_string(),
                     )
                     .expect("Hand-crafted conventional commit summary is always correct"),
-                );
+                ));
             ensure_inner_vector_exists(test_commits, &NO_SCOPE_TITLE.to_string());
             test_commits
                 .get_mut(NO_SCOPE_TITLE)
                 .expect("Vector just created")
-                .push(
+                .push(item(
                     ConventionalCommitSummary::new(
                         "test".to_string(),
                         None,
@@ -633,7 +910,7 @@ mod tests {
                         "test message #3".to_string(),
                     )
                     .expect("Hand-crafted conventional commit summary is always correct"),
-                );
+                ));
 
             ensure_inner_map_exists(&mut temp, "docs");
             let docs_commits = temp.get_mut("docs").expect("Map just created");
@@ -641,7 +918,7 @@ mod tests {
             docs_commits
                 .get_mut(NO_SCOPE_TITLE)
                 .expect("Vector just created")
-                .push(
+                .push(item(
                     ConventionalCommitSummary::new(
                         "docs".to_string(),
                         None,
@@ -649,67 +926,36 @@ mod tests {
                         "test message #5".to_string(),
                     )
                     .expect("Hand-crafted conventional commit summary is always correct"),
-                );
+                ));
             temp
         };
         assert_eq!(m, expected);
     }
 
     #[test]
-    fn format_types_basic() {
-        let c = commit_list();
-        let s = format_types(
-            &format(),
-            &categorize_commit_list(
-                c.iter().map(|it| CommitSummary::Conventional(it.clone())),
-                None,
-            ),
+    fn categorize_commit_list_free_form() {
+        let m = categorize_commit_list(
+            vec![Commit::new(
+                "a1b2c3".to_string(),
+                CommitSummary::FreeForm("Update readme".to_string()),
+                vec![],
+            )]
+            .into_iter(),
+            None,
+            &ChangelogLinks::default(),
         );
         assert_eq!(
-            s,
-            concat!(
-                "## feat\n",
-                "### API\n",
-                "* test message #1\n",
-                "### General\n",
-                "* test message #6\n",
-                "\n",
-                "## fix\n",
-                "### API\n",
-                "* test message #2\n",
-                "\n",
-                "## test\n",
-                "### API\n",
-                "* test message #7\n",
-                "### General\n",
-                "* test message #3\n",
-                "\n",
-                "## docs\n",
-                "### General\n",
-                "* test message #5\n",
-                "\n",
-                "## refactor\n",
-                "### exclude\n",
-                "* test message #4\n",
-            )
-        );
-    }
-
-    #[test]
-    fn format_title_basic() {
-        let v = Some(
-            SemanticVersion::new(0, 1, 0, None, None)
-                .expect("Hand-crafted version is always correct"),
+            m.get(NON_CONVENTIONAL_TYPE)
+                .and_then(|it| it.get(NO_SCOPE_TITLE)),
+            Some(&vec![ChangelogCommit::new(
+                "a1b2c3".to_string(),
+                "Update readme".to_string(),
+                false,
+                None,
+                None,
+                vec![]
+            )])
         );
-        let s = format_title(&format(), v.as_ref());
-        assert_eq!(s, "# Changes from version 0.1.0");
-    }
-
-    #[test]
-    fn format_title_empty_version() {
-        let v = None;
-        let s = format_title(&format(), v);
-        assert_eq!(s, "# Latest changes");
     }
 
     struct MockCommitRepository {}
@@ -717,20 +963,27 @@ mod tests {
     impl BoundedCommitSummaryIngressRepository for MockCommitRepository {
         fn get_commits_from(
             &self,
-            _version: Rc<Option<SemanticVersion>>,
-        ) -> Result<Box<dyn DoubleEndedIterator<Item = CommitSummary>>, AnyError> {
-            Ok(Box::new(
-                commit_list()
-                    .into_iter()
-                    .map(|c| CommitSummary::Conventional(c.clone())),
-            ))
+            _from: Option<&str>,
+            _to: Option<&str>,
+            _paths: &[String],
+        ) -> Result<Box<dyn DoubleEndedIterator<Item = Commit>>, AnyError> {
+            Ok(Box::new(commit_list().into_iter().map(|c| {
+                Commit::new(
+                    String::new(),
+                    CommitSummary::Conventional(c.clone()),
+                    vec![],
+                )
+            })))
         }
     }
 
     struct MockVersionRepository {}
 
     impl SemanticVersionIngressRepository for MockVersionRepository {
-        fn last_version(&self) -> Result<Rc<Option<SemanticVersion>>, AnyError> {
+        fn last_version(
+            &self,
+            _tag_format: &TagFormat,
+        ) -> Result<Rc<Option<SemanticVersion>>, AnyError> {
             Ok(Some(
                 SemanticVersion::new(0, 1, 0, Some("dev1".to_string()), None)
                     .expect("Hand-crafted version is always correct"),
@@ -738,18 +991,36 @@ mod tests {
             .into())
         }
 
-        fn last_stable_version(&self) -> Result<Rc<Option<SemanticVersion>>, AnyError> {
+        fn last_stable_version(
+            &self,
+            _tag_format: &TagFormat,
+        ) -> Result<Rc<Option<SemanticVersion>>, AnyError> {
             Ok(Some(
                 SemanticVersion::new(0, 1, 0, None, None)
                     .expect("Hand-crafted version is always correct"),
             )
             .into())
         }
+
+        fn all_versions(
+            &self,
+            _tag_format: &TagFormat,
+        ) -> Result<Vec<(SemanticVersion, String)>, AnyError> {
+            unreachable!()
+        }
     }
 
     #[test]
     fn execute_basic() {
-        let configuration = ChangelogConfiguration::new(false, format(), None);
+        let configuration = ChangelogConfiguration::new(
+            ChangelogRange::new(false, None, None, vec![], TagFormat::default()),
+            None,
+            ChangelogOrder::default(),
+            ChangelogLinks::default(),
+            false,
+            ChangelogGroupBy::Type,
+            None,
+        );
         let commit_repository = MockCommitRepository {};
         let version_repository = MockVersionRepository {};
         let usecase =
@@ -757,39 +1028,141 @@ mod tests {
         let changelog = usecase.execute();
         assert!(changelog.is_ok());
         assert_eq!(
-            changelog.expect("Just asserted it OK-ness"),
+            changelog
+                .expect("Just asserted it OK-ness")
+                .format(&format()),
             concat!(
                 "# Changes from version 0.1.0\n",
                 "## feat\n",
-                "### API\n",
-                "* test message #1\n",
                 "### General\n",
                 "* test message #6\n",
+                "### API\n",
+                "* test message #1\n",
                 "\n",
                 "## fix\n",
                 "### API\n",
                 "* test message #2\n",
                 "\n",
-                "## test\n",
-                "### API\n",
-                "* test message #7\n",
-                "### General\n",
-                "* test message #3\n",
-                "\n",
                 "## docs\n",
                 "### General\n",
                 "* test message #5\n",
                 "\n",
                 "## refactor\n",
                 "### exclude\n",
-                "* test message #4\n"
+                "* test message #4\n",
+                "\n",
+                "## test\n",
+                "### General\n",
+                "* test message #3\n",
+                "### API\n",
+                "* test message #7\n"
             )
         );
     }
 
+    #[test]
+    fn execute_between_bounds() {
+        let configuration = ChangelogConfiguration::new(
+            ChangelogRange::new(
+                false,
+                Some(ChangelogBound::Ref("v1.2.0".to_string())),
+                Some(ChangelogBound::Version(
+                    SemanticVersion::new(1, 3, 0, None, None)
+                        .expect("Hand-crafted version is always correct"),
+                )),
+                vec![],
+                TagFormat::default(),
+            ),
+            None,
+            ChangelogOrder::default(),
+            ChangelogLinks::default(),
+            false,
+            ChangelogGroupBy::Type,
+            None,
+        );
+        let commit_repository = MockCommitRepository {};
+        let version_repository = MockVersionRepository {};
+        let usecase =
+            CreateChangelogUseCase::new(configuration, &commit_repository, &version_repository);
+        let changelog = usecase.execute().expect("Mock repositories do not fail");
+        assert_eq!(changelog.title(), "Changes from v1.2.0 to version 1.3.0");
+    }
+
+    struct MockTaggedCommitRepository {}
+
+    impl BoundedCommitSummaryIngressRepository for MockTaggedCommitRepository {
+        fn get_commits_from(
+            &self,
+            from: Option<&str>,
+            _to: Option<&str>,
+            _paths: &[String],
+        ) -> Result<Box<dyn DoubleEndedIterator<Item = Commit>>, AnyError> {
+            assert_eq!(from, Some("v0.1.0"));
+            Ok(Box::new(std::iter::empty()))
+        }
+    }
+
+    #[test]
+    fn execute_with_tag_template() {
+        let configuration = ChangelogConfiguration::new(
+            ChangelogRange::new(
+                false,
+                None,
+                None,
+                vec![],
+                TagFormat::new("v{version}").expect("The template has a placeholder"),
+            ),
+            None,
+            ChangelogOrder::default(),
+            ChangelogLinks::default(),
+            false,
+            ChangelogGroupBy::Type,
+            None,
+        );
+        let commit_repository = MockTaggedCommitRepository {};
+        let version_repository = MockVersionRepository {};
+        let usecase =
+            CreateChangelogUseCase::new(configuration, &commit_repository, &version_repository);
+        let changelog = usecase.execute().expect("Mock repositories do not fail");
+        assert_eq!(changelog.title(), "Changes from version 0.1.0");
+    }
+
+    #[test]
+    fn execute_up_to_bound() {
+        let configuration = ChangelogConfiguration::new(
+            ChangelogRange::new(
+                false,
+                None,
+                Some(ChangelogBound::Ref("hotfix".to_string())),
+                vec![],
+                TagFormat::default(),
+            ),
+            None,
+            ChangelogOrder::default(),
+            ChangelogLinks::default(),
+            false,
+            ChangelogGroupBy::Type,
+            None,
+        );
+        let commit_repository = MockCommitRepository {};
+        let version_repository = MockVersionRepository {};
+        let usecase =
+            CreateChangelogUseCase::new(configuration, &commit_repository, &version_repository);
+        let changelog = usecase.execute().expect("Mock repositories do not fail");
+        assert_eq!(changelog.from(), None);
+    }
+
     #[test]
     fn execute_from_latest_version() {
-        let configuration = ChangelogConfiguration::new(true, format(), None);
+        let configuration = ChangelogConfiguration::new(
+            ChangelogRange::new(true, None, None, vec![], TagFormat::default()),
+            None,
+            ChangelogOrder::default(),
+            ChangelogLinks::default(),
+            false,
+            ChangelogGroupBy::Type,
+            None,
+        );
         let commit_repository = MockCommitRepository {};
         let version_repository = MockVersionRepository {};
         let usecase =
@@ -797,25 +1170,21 @@ mod tests {
         let changelog = usecase.execute();
         assert!(changelog.is_ok());
         assert_eq!(
-            changelog.expect("Just asserted its OK-ness"),
+            changelog
+                .expect("Just asserted its OK-ness")
+                .format(&format()),
             concat!(
                 "# Changes from version 0.1.0-dev1\n",
                 "## feat\n",
-                "### API\n",
-                "* test message #1\n",
                 "### General\n",
                 "* test message #6\n",
+                "### API\n",
+                "* test message #1\n",
                 "\n",
                 "## fix\n",
                 "### API\n",
                 "* test message #2\n",
                 "\n",
-                "## test\n",
-                "### API\n",
-                "* test message #7\n",
-                "### General\n",
-                "* test message #3\n",
-                "\n",
                 "## docs\n",
                 "### General\n",
                 "* test message #5\n",
@@ -823,6 +1192,12 @@ mod tests {
                 "## refactor\n",
                 "### exclude\n",
                 "* test message #4\n",
+                "\n",
+                "## test\n",
+                "### General\n",
+                "* test message #3\n",
+                "### API\n",
+                "* test message #7\n"
             )
         );
     }
@@ -837,7 +1212,15 @@ mod tests {
                 },
             },
         ))));
-        let configuration = ChangelogConfiguration::new(false, format(), trigger);
+        let configuration = ChangelogConfiguration::new(
+            ChangelogRange::new(false, None, None, vec![], TagFormat::default()),
+            trigger,
+            ChangelogOrder::default(),
+            ChangelogLinks::default(),
+            false,
+            ChangelogGroupBy::Type,
+            None,
+        );
         let commit_repository = MockCommitRepository {};
         let version_repository = MockVersionRepository {};
         let usecase =
@@ -845,28 +1228,30 @@ mod tests {
         let changelog = usecase.execute();
         assert!(changelog.is_ok());
         assert_eq!(
-            changelog.expect("Just asserted its OK-ness"),
+            changelog
+                .expect("Just asserted its OK-ness")
+                .format(&format()),
             concat!(
                 "# Changes from version 0.1.0\n",
                 "## feat\n",
-                "### API\n",
-                "* test message #1\n",
                 "### General\n",
                 "* test message #6\n",
+                "### API\n",
+                "* test message #1\n",
                 "\n",
                 "## fix\n",
                 "### API\n",
                 "* test message #2\n",
                 "\n",
-                "## test\n",
-                "### API\n",
-                "* test message #7\n",
-                "### General\n",
-                "* test message #3\n",
-                "\n",
                 "## docs\n",
                 "### General\n",
                 "* test message #5\n",
+                "\n",
+                "## test\n",
+                "### General\n",
+                "* test message #3\n",
+                "### API\n",
+                "* test message #7\n"
             )
         );
     }
@@ -881,7 +1266,15 @@ mod tests {
                 },
             },
         ))));
-        let configuration = ChangelogConfiguration::new(true, format(), trigger);
+        let configuration = ChangelogConfiguration::new(
+            ChangelogRange::new(true, None, None, vec![], TagFormat::default()),
+            trigger,
+            ChangelogOrder::default(),
+            ChangelogLinks::default(),
+            false,
+            ChangelogGroupBy::Type,
+            None,
+        );
         let commit_repository = MockCommitRepository {};
         let version_repository = MockVersionRepository {};
         let usecase =
@@ -889,28 +1282,30 @@ mod tests {
         let changelog = usecase.execute();
         assert!(changelog.is_ok());
         assert_eq!(
-            changelog.expect("Just asserted its OK-ness"),
+            changelog
+                .expect("Just asserted its OK-ness")
+                .format(&format()),
             concat!(
                 "# Changes from version 0.1.0-dev1\n",
                 "## feat\n",
-                "### API\n",
-                "* test message #1\n",
                 "### General\n",
                 "* test message #6\n",
+                "### API\n",
+                "* test message #1\n",
                 "\n",
                 "## fix\n",
                 "### API\n",
                 "* test message #2\n",
                 "\n",
-                "## test\n",
-                "### API\n",
-                "* test message #7\n",
-                "### General\n",
-                "* test message #3\n",
-                "\n",
                 "## docs\n",
                 "### General\n",
                 "* test message #5\n",
+                "\n",
+                "## test\n",
+                "### General\n",
+                "* test message #3\n",
+                "### API\n",
+                "* test message #7\n"
             )
         );
     }
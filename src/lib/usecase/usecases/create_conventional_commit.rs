@@ -30,12 +30,16 @@ impl UseCase<ConventionalCommit, CreateConventionalCommitError>
     for CreateConventionalCommitUseCase<'_>
 {
     fn execute(&self) -> Result<ConventionalCommit, CreateConventionalCommitError> {
+        self.configuration
+            .policy()
+            .check(self.configuration.typ(), self.configuration.scope())?;
         let commit = ConventionalCommit::new(
             self.configuration.typ().to_owned(),
             self.configuration.scope().map(|it| it.to_owned()),
             self.configuration.is_breaking().into(),
             self.configuration.summary().to_owned(),
             self.configuration.message().map(|it| it.to_owned()),
+            self.configuration.footers().to_vec(),
         )?;
         if self.configuration.allow_empty() {
             self.commit_repository.create_empty_commit(&commit)?;
@@ -51,9 +55,10 @@ mod tests {
     use std::{error::Error, fmt::Display};
 
     use crate::{
-        domain::conventional_commit::ConventionalCommit,
+        domain::{commit_policy::CommitPolicy, conventional_commit::ConventionalCommit},
         usecase::{
             configuration::commit::{AllowEmptyFlag, CommitConfiguration},
+            error::create_conventional_commit_error::CreateConventionalCommitError,
             repository::conventional_commit_egress_repository::ConventionalCommitEgressRepository,
             type_aliases::AnyError,
             usecases::{
@@ -96,7 +101,9 @@ mod tests {
             false,
             "test".to_string(),
             None,
+            vec![],
             AllowEmptyFlag::Disabled,
+            CommitPolicy::default(),
         )
         .expect("This configuration is well-formed")
     }
@@ -108,7 +115,9 @@ mod tests {
             true,
             "test".to_string(),
             Some("Message body".to_string()),
+            vec![],
             AllowEmptyFlag::Disabled,
+            CommitPolicy::default(),
         )
         .expect("This configuration is well-formed")
     }
@@ -130,4 +139,25 @@ mod tests {
         let result = usecase.execute();
         assert!(result.is_err());
     }
+
+    #[test]
+    fn execute_usecase_policy_violation() {
+        let config = CommitConfiguration::new(
+            "fetaure".to_string(),
+            None,
+            false,
+            "test".to_string(),
+            None,
+            vec![],
+            AllowEmptyFlag::Disabled,
+            CommitPolicy::new(Some(vec!["feat".to_string(), "fix".to_string()]), None),
+        )
+        .expect("This configuration is well-formed");
+        let commit_repository = MockCommitRepository {};
+        let usecase = CreateConventionalCommitUseCase::new(config, &commit_repository);
+        let result = usecase.execute();
+        assert!(
+            result.is_err_and(|e| matches!(e, CreateConventionalCommitError::PolicyViolation(..)))
+        );
+    }
 }
@@ -0,0 +1,218 @@
+use crate::usecase::{
+    changelog::{Changelog, ChangelogBound},
+    configuration::changelog::ChangelogConfiguration,
+    error::changelog_creation_error::ChangelogCreationError,
+    repository::{
+        bounded_commit_summary_ingress_repository::BoundedCommitSummaryIngressRepository,
+        semantic_version_ingress_repository::SemanticVersionIngressRepository,
+    },
+    usecases::{
+        create_changelog::{breaking_changes, changelog_groups, changelog_types},
+        usecase::UseCase,
+    },
+};
+
+/**
+ * Creates a changelog for each released version, from the newest to the oldest.
+ * The changes after the last version come first, if any.
+ */
+pub struct CreateFullChangelogUseCase<'a> {
+    configuration: ChangelogConfiguration,
+    commit_repository: &'a dyn BoundedCommitSummaryIngressRepository,
+    version_repository: &'a dyn SemanticVersionIngressRepository,
+}
+
+impl<'a, 'b: 'a, 'c: 'a> CreateFullChangelogUseCase<'a> {
+    pub fn new(
+        configuration: ChangelogConfiguration,
+        commit_repository: &'b dyn BoundedCommitSummaryIngressRepository,
+        version_repository: &'c dyn SemanticVersionIngressRepository,
+    ) -> Self {
+        CreateFullChangelogUseCase {
+            configuration,
+            commit_repository,
+            version_repository,
+        }
+    }
+}
+
+impl UseCase<Vec<Changelog>, ChangelogCreationError> for CreateFullChangelogUseCase<'_> {
+    fn execute(&self) -> Result<Vec<Changelog>, ChangelogCreationError> {
+        let mut changelogs = vec![];
+        let tag_format = self.configuration.range().tag_format();
+        let mut base_version: Option<ChangelogBound> = None;
+        for (version, date) in self.version_repository.all_versions(tag_format)? {
+            let commits = self.commit_repository.get_commits_from(
+                base_version
+                    .as_ref()
+                    .map(|it| it.git_ref(tag_format))
+                    .as_deref(),
+                Some(&tag_format.tag(&version)),
+                self.configuration.range().paths(),
+            )?;
+            let types = changelog_types(commits, &self.configuration);
+            changelogs.push(Changelog::new(
+                Some(version.clone()),
+                Some(date),
+                base_version,
+                None,
+                breaking_changes(&types, &self.configuration),
+                changelog_groups(types, &self.configuration),
+            ));
+            base_version = Some(ChangelogBound::Version(version));
+        }
+        let unreleased = changelog_types(
+            self.commit_repository.get_commits_from(
+                base_version
+                    .as_ref()
+                    .map(|it| it.git_ref(tag_format))
+                    .as_deref(),
+                None,
+                self.configuration.range().paths(),
+            )?,
+            &self.configuration,
+        );
+        if !unreleased.is_empty() {
+            changelogs.push(Changelog::new(
+                self.configuration.version().cloned(),
+                None,
+                base_version,
+                None,
+                breaking_changes(&unreleased, &self.configuration),
+                changelog_groups(unreleased, &self.configuration),
+            ));
+        }
+        changelogs.reverse();
+        Ok(changelogs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::{
+        domain::{
+            commit::Commit, commit_summary::CommitSummary, semantic_version::SemanticVersion,
+        },
+        usecase::{
+            changelog::{Changelog, ChangelogBound, ChangelogGroups},
+            configuration::changelog::{
+                ChangelogConfiguration, ChangelogGroupBy, ChangelogLinks, ChangelogOrder,
+                ChangelogRange,
+            },
+            repository::{
+                bounded_commit_summary_ingress_repository::BoundedCommitSummaryIngressRepository,
+                semantic_version_ingress_repository::SemanticVersionIngressRepository,
+            },
+            tag_format::TagFormat,
+            type_aliases::AnyError,
+            usecases::{create_full_changelog::CreateFullChangelogUseCase, usecase::UseCase},
+        },
+    };
+
+    fn first_type(changelog: &Changelog) -> &str {
+        match changelog.groups() {
+            ChangelogGroups::ByType(types) => types[0].name(),
+            _ => panic!("The changelog is grouped by type"),
+        }
+    }
+
+    fn version(minor: u32) -> SemanticVersion {
+        SemanticVersion::new(0, minor, 0, None, None)
+            .expect("Hand-crafted version is always correct")
+    }
+
+    struct MockCommitRepository {}
+
+    impl BoundedCommitSummaryIngressRepository for MockCommitRepository {
+        fn get_commits_from(
+            &self,
+            from: Option<&str>,
+            to: Option<&str>,
+            _paths: &[String],
+        ) -> Result<Box<dyn DoubleEndedIterator<Item = Commit>>, AnyError> {
+            let summary = match (from, to) {
+                (None, Some(_)) => Some("feat: first feature"),
+                (Some(_), Some(_)) => Some("fix: first fix"),
+                (Some(_), None) => None,
+                (None, None) => unreachable!(),
+            };
+            Ok(Box::new(summary.into_iter().map(|it| {
+                Commit::new(
+                    String::new(),
+                    it.parse::<CommitSummary>()
+                        .expect("Hand-crafted summary is correct"),
+                    vec![],
+                )
+            })))
+        }
+    }
+
+    struct MockVersionRepository {}
+
+    impl SemanticVersionIngressRepository for MockVersionRepository {
+        fn last_version(
+            &self,
+            _tag_format: &TagFormat,
+        ) -> Result<Rc<Option<SemanticVersion>>, AnyError> {
+            unreachable!()
+        }
+
+        fn last_stable_version(
+            &self,
+            _tag_format: &TagFormat,
+        ) -> Result<Rc<Option<SemanticVersion>>, AnyError> {
+            unreachable!()
+        }
+
+        fn all_versions(
+            &self,
+            _tag_format: &TagFormat,
+        ) -> Result<Vec<(SemanticVersion, String)>, AnyError> {
+            Ok(vec![
+                (version(1), "2024-01-01".to_string()),
+                (version(2), "2024-02-01".to_string()),
+            ])
+        }
+    }
+
+    #[test]
+    fn execute_basic() {
+        let configuration = ChangelogConfiguration::new(
+            ChangelogRange::new(false, None, None, vec![], TagFormat::default()),
+            None,
+            ChangelogOrder::default(),
+            ChangelogLinks::default(),
+            false,
+            ChangelogGroupBy::Type,
+            None,
+        );
+        let usecase = CreateFullChangelogUseCase::new(
+            configuration,
+            &MockCommitRepository {},
+            &MockVersionRepository {},
+        );
+        let changelogs = usecase.execute().expect("Mock repositories do not fail");
+        assert_eq!(
+            changelogs
+                .iter()
+                .map(|it| (
+                    it.version().cloned(),
+                    it.date(),
+                    it.from().cloned(),
+                    first_type(it)
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    Some(version(2)),
+                    Some("2024-02-01"),
+                    Some(ChangelogBound::Version(version(1))),
+                    "fix"
+                ),
+                (Some(version(1)), Some("2024-01-01"), None, "feat"),
+            ]
+        );
+    }
+}
@@ -26,6 +26,7 @@ impl UseCase<(), TagCreationError> for CreateTagUseCase<'_> {
     fn execute(&self) -> Result<(), TagCreationError> {
         Ok(self.tag_write_repository.create_tag(
             self.configuration.version(),
+            self.configuration.tag_format(),
             self.configuration.message(),
             self.configuration.sign(),
         )?)
@@ -40,8 +41,8 @@ mod tests {
         domain::semantic_version::SemanticVersion,
         usecase::{
             configuration::tag::TagConfiguration,
-            repository::tag_egress_repository::TagEgressRepository, type_aliases::AnyError,
-            usecases::usecase::UseCase,
+            repository::tag_egress_repository::TagEgressRepository, tag_format::TagFormat,
+            type_aliases::AnyError, usecases::usecase::UseCase,
         },
     };
 
@@ -70,6 +71,7 @@ mod tests {
         fn create_tag(
             &self,
             version: &SemanticVersion,
+            _tag_format: &TagFormat,
             message: Option<&str>,
             sign: bool,
         ) -> Result<(), AnyError> {
@@ -85,6 +87,7 @@ mod tests {
         let tag_configuration = TagConfiguration::new(
             SemanticVersion::new(1, 0, 0, None, None)
                 .expect("Hand-crafted version is always correct"),
+            TagFormat::default(),
             Some("test".to_string()),
             true,
         )
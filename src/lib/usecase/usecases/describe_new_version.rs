@@ -1,12 +1,14 @@
 use std::{error::Error, rc::Rc};
 
 use crate::{
-    domain::{commit_summary::CommitSummary, semantic_version::SemanticVersion},
+    domain::{commit::Commit, commit_summary::CommitSummary, semantic_version::SemanticVersion},
     usecase::{
         configuration::describe::DescribeConfiguration,
         error::describe_new_version_error::{
-            DescribeMetadataError, DescribeNewVersionError, DescribeNoRelevantChangesError,
-            DescribePrereleaseError, DescribeStableReleaseError,
+            DescribeAlreadyGraduatedError, DescribeMetadataError, DescribeNewVersionError,
+            DescribeNoRelevantChangesError, DescribeNothingToPromoteError,
+            DescribeOutdatedPrereleaseError, DescribePrereleaseError, DescribeStableReleaseError,
+            DescribeVersionNotGreaterError,
         },
         repository::{
             bounded_commit_summary_ingress_repository::BoundedCommitSummaryIngressRepository,
@@ -14,6 +16,7 @@ use crate::{
             semantic_version_ingress_repository::SemanticVersionIngressRepository,
         },
         type_aliases::AnyError,
+        version_description::{Change, VersionDescription},
     },
 };
 
@@ -28,15 +31,52 @@ pub struct CalculateNewVersionUseCase<'a> {
     version_repository: &'a dyn SemanticVersionIngressRepository,
 }
 
-impl UseCase<(SemanticVersion, Option<SemanticVersion>), DescribeNewVersionError>
-    for CalculateNewVersionUseCase<'_>
-{
-    fn execute(
-        &self,
-    ) -> Result<(SemanticVersion, Option<SemanticVersion>), DescribeNewVersionError> {
-        let base_version = self.version_repository.last_stable_version()?;
+impl UseCase<VersionDescription, DescribeNewVersionError> for CalculateNewVersionUseCase<'_> {
+    fn execute(&self) -> Result<VersionDescription, DescribeNewVersionError> {
+        let base_version = self
+            .version_repository
+            .last_stable_version(self.configuration.tag_format())?;
+        let commits = self
+            .classify_commits_from(base_version.clone())
+            .map_err(DescribeStableReleaseError::from)?;
+        if let Some(version) = self.configuration.version().version() {
+            let change = match base_version.as_ref() {
+                Some(base) if version <= base => {
+                    return Err(DescribeStableReleaseError::from(
+                        DescribeVersionNotGreaterError::new(version.to_string(), base.to_string()),
+                    )
+                    .into())
+                }
+                Some(base) => Change::between(base, version),
+                None => Change::between(&SemanticVersion::new(0, 0, 0, None, None)?, version),
+            };
+            return Ok(VersionDescription::new(
+                version.clone(),
+                base_version.as_ref().clone(),
+                change,
+                commits,
+            ));
+        }
+        let (stable_version, change) = if self.configuration.version().promote() {
+            let stable_version = self.promoted_stable()?;
+            let change = Change::between(
+                base_version
+                    .as_ref()
+                    .as_ref()
+                    .unwrap_or(&SemanticVersion::new(0, 0, 0, None, None)?),
+                &SemanticVersion::new(
+                    stable_version.major,
+                    stable_version.minor,
+                    stable_version.patch,
+                    None,
+                    None,
+                )?,
+            );
+            (stable_version, change)
+        } else {
+            self.next_stable(base_version.clone(), &commits)?
+        };
         let new_version = {
-            let stable_version = self.next_stable(base_version.clone())?;
             let prerelease = if self.configuration.prerelease().is_active() {
                 Some(self.update_prerelease(&stable_version)?)
             } else {
@@ -51,7 +91,12 @@ impl UseCase<(SemanticVersion, Option<SemanticVersion>), DescribeNewVersionError
                 metadata,
             )?
         };
-        Ok((new_version, base_version.as_ref().clone()))
+        Ok(VersionDescription::new(
+            new_version,
+            base_version.as_ref().clone(),
+            change,
+            commits,
+        ))
     }
 }
 
@@ -71,16 +116,62 @@ impl<'a, 'b: 'a, 'c: 'a, 'd: 'a> CalculateNewVersionUseCase<'a> {
     }
 
     #[inline]
-    fn greatest_change_from(
+    fn classify_commits_from(
         &self,
         version: Rc<Option<SemanticVersion>>,
-    ) -> Result<Change, Box<dyn Error>> {
+    ) -> Result<Vec<(CommitSummary, Change)>, Box<dyn Error>> {
         Ok(self
-            .commit_summary_repository
-            .get_commits_from(version)?
-            .map(|it| self.commit_to_change(&it))
+            .commits_since(version.as_ref())?
+            .into_iter()
+            .map(|it| {
+                let change = self.commit_to_change(it.summary());
+                (it.summary().clone(), change)
+            })
+            .collect())
+    }
+
+    /**
+     * The commits after the tag of the given version. For a package, only the ones with its
+     * name as scope or which touch its paths are kept.
+     */
+    #[inline]
+    fn commits_since(&self, version: &Option<SemanticVersion>) -> Result<Vec<Commit>, AnyError> {
+        let from = version
+            .as_ref()
+            .map(|it| self.configuration.tag_format().tag(it));
+        let commits = self.commit_summary_repository.get_commits_from(
+            from.as_deref(),
+            None,
+            self.configuration.paths(),
+        )?;
+        Ok(match self.configuration.package() {
+            None => commits.collect(),
+            Some(package) => {
+                let touching_paths: Vec<String> = if package.paths().is_empty() {
+                    vec![]
+                } else {
+                    self.commit_summary_repository
+                        .get_commits_from(from.as_deref(), None, package.paths())?
+                        .map(|it| it.hash().to_owned())
+                        .collect()
+                };
+                commits
+                    .filter(|it| {
+                        matches!(it.summary(), CommitSummary::Conventional(c) if c.scope() == Some(package.name()))
+                            || touching_paths.iter().any(|hash| hash == it.hash())
+                    })
+                    .collect()
+            }
+        })
+    }
+
+    #[inline]
+    fn greatest_change(commits: &[(CommitSummary, Change)]) -> Change {
+        commits
+            .iter()
+            .map(|(_, change)| *change)
             .max()
-            .unwrap_or(Change::None))
+            .unwrap_or(Change::None)
     }
 
     #[inline]
@@ -115,19 +206,51 @@ impl<'a, 'b: 'a, 'c: 'a, 'd: 'a> CalculateNewVersionUseCase<'a> {
     }
 
     #[inline]
+    /**
+     * The first stable version is considered a minor change from 0.0.0, unless a major change is
+     * forced. The forced change is the minimum change of the other versions.
+     * Graduating from the initial development always leads to 1.0.0.
+     */
     fn next_stable(
         &self,
         base_version: Rc<Option<SemanticVersion>>,
-    ) -> Result<StableVersion, DescribeStableReleaseError> {
+        commits: &[(CommitSummary, Change)],
+    ) -> Result<(StableVersion, Change), DescribeStableReleaseError> {
+        if self.configuration.version().graduate() {
+            return match base_version.as_ref() {
+                Some(base) if base.major() > 0 => {
+                    Err(DescribeAlreadyGraduatedError::new(base.to_string()).into())
+                }
+                _ => Ok((StableVersion::new(1, 0, 0), Change::Major)),
+            };
+        }
+        let forced_change = self.configuration.version().bump().unwrap_or(Change::None);
         Ok(if base_version.is_none() {
-            StableVersion::first_stable()
+            if forced_change == Change::Major {
+                (StableVersion::new(1, 0, 0), Change::Major)
+            } else {
+                (StableVersion::first_stable(), Change::Minor)
+            }
         } else {
-            let greatest_change = self.greatest_change_from(base_version.clone())?;
             let base_version = base_version
                 .as_ref()
                 .as_ref()
                 .expect("base version must be present in this branch");
-            match greatest_change {
+            let commits_change = Self::greatest_change(commits);
+            // While the major version is 0, breaking changes bump the minor and features the patch
+            let commits_change = if self.configuration.version().initial_development()
+                && base_version.major() == 0
+            {
+                match commits_change {
+                    Change::Major => Change::Minor,
+                    Change::Minor => Change::Patch,
+                    other => other,
+                }
+            } else {
+                commits_change
+            };
+            let greatest_change = commits_change.max(forced_change);
+            let stable_version = match greatest_change {
                 Change::Major => StableVersion::new(base_version.major() + 1, 0, 0),
                 Change::Minor => {
                     StableVersion::new(base_version.major(), base_version.minor() + 1, 0)
@@ -148,16 +271,38 @@ impl<'a, 'b: 'a, 'c: 'a, 'd: 'a> CalculateNewVersionUseCase<'a> {
                         return Err(DescribeNoRelevantChangesError {}.into());
                     }
                 }
-            }
+            };
+            (stable_version, greatest_change)
         })
     }
 
+    /**
+     * The stable version of the last prerelease, which must be the last version.
+     */
+    #[inline]
+    fn promoted_stable(&self) -> Result<StableVersion, DescribeStableReleaseError> {
+        let last_version = self
+            .version_repository
+            .last_version(self.configuration.tag_format())?;
+        match last_version.as_ref() {
+            Some(last) if last.prerelease().is_some() => {
+                Ok(StableVersion::new(last.major(), last.minor(), last.patch()))
+            }
+            other => Err(DescribeNothingToPromoteError::new(
+                other.as_ref().map(|it| it.to_string()),
+            )
+            .into()),
+        }
+    }
+
     #[inline]
     fn update_prerelease(
         &self,
         next_stable: &StableVersion,
     ) -> Result<String, DescribePrereleaseError> {
-        let last_version = self.version_repository.last_version()?;
+        let last_version = self
+            .version_repository
+            .last_version(self.configuration.tag_format())?;
         let is_stable_updated = match last_version.as_ref() {
             Some(old) => {
                 next_stable.major != old.major()
@@ -166,37 +311,72 @@ impl<'a, 'b: 'a, 'c: 'a, 'd: 'a> CalculateNewVersionUseCase<'a> {
             }
             None => true,
         };
-        if self
-            .commit_summary_repository
-            .get_commits_from(last_version.clone())?
-            .count()
-            == 0
+        // A prerelease which does not follow the old pattern belongs to another channel
+        let is_channel_changed = !is_stable_updated
+            && last_version
+                .as_ref()
+                .as_ref()
+                .and_then(|it| it.prerelease())
+                .is_some_and(|it| self.configuration.prerelease().old_pattern()(it).is_none());
+        if (!is_channel_changed && self.commits_since(last_version.as_ref())?.is_empty())
             || (!is_stable_updated
                 && last_version
                     .as_ref()
                     .as_ref()
                     .is_some_and(|it| it.prerelease().is_none()))
         {
-            Err(DescribeNoRelevantChangesError::new().into())
+            return Err(DescribeNoRelevantChangesError::new().into());
+        }
+        let next_prerelease_number = if self.configuration.prerelease().pattern_changed() {
+            FIRST_PRERELEASE_NUMBER
         } else {
-            let next_prerelease_number = if self.configuration.prerelease().pattern_changed()
-                || is_stable_updated
-            {
-                FIRST_PRERELEASE_NUMBER
-            } else {
-                let semantic_version = last_version
-                    .as_ref()
-                    .as_ref()
-                    .expect("stable is not updated, so last version is bound to exist");
-                let last_version_prerelease = semantic_version.prerelease().expect("prerelease is present, else this function would have returned an error already");
-                let old_prerelease_number =
-                    self.configuration.prerelease().old_pattern()(last_version_prerelease);
-                old_prerelease_number + 1
-            };
-            Ok(self.configuration.prerelease().pattern()(
-                next_prerelease_number,
-            ))
+            self.last_prerelease_number(next_stable)?
+                .map_or(FIRST_PRERELEASE_NUMBER, |it| it + 1)
+        };
+        let prerelease = self.configuration.prerelease().pattern()(next_prerelease_number);
+        // An invalid prerelease is reported when the new version is created
+        if let (Some(last), Ok(new_version)) = (
+            last_version.as_ref(),
+            SemanticVersion::new(
+                next_stable.major,
+                next_stable.minor,
+                next_stable.patch,
+                Some(prerelease.clone()),
+                None,
+            ),
+        ) {
+            if new_version <= *last {
+                return Err(DescribeOutdatedPrereleaseError::new(
+                    new_version.to_string(),
+                    last.to_string(),
+                )
+                .into());
+            }
         }
+        Ok(prerelease)
+    }
+
+    /**
+     * The greatest number among the prereleases of the given stable version which follow the
+     * old pattern, i.e. which belong to the same channel.
+     */
+    #[inline]
+    fn last_prerelease_number(
+        &self,
+        stable: &StableVersion,
+    ) -> Result<Option<u32>, DescribePrereleaseError> {
+        Ok(self
+            .version_repository
+            .all_versions(self.configuration.tag_format())?
+            .iter()
+            .filter(|(it, _)| {
+                it.major() == stable.major
+                    && it.minor() == stable.minor
+                    && it.patch() == stable.patch
+            })
+            .filter_map(|(it, _)| it.prerelease())
+            .filter_map(|it| self.configuration.prerelease().old_pattern()(it))
+            .max())
     }
 
     #[inline]
@@ -213,14 +393,6 @@ impl<'a, 'b: 'a, 'c: 'a, 'd: 'a> CalculateNewVersionUseCase<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
-enum Change {
-    None,
-    Patch,
-    Minor,
-    Major,
-}
-
 #[derive(Debug)]
 struct StableVersion {
     major: u32,
@@ -245,10 +417,11 @@ impl StableVersion {
 #[cfg(test)]
 mod tests {
 
-    use std::rc::Rc;
+    use std::{rc::Rc, str::FromStr};
 
     use crate::{
         domain::{
+            commit::Commit,
             commit_summary::CommitSummary,
             conventional_commit_summary::{
                 ConventionalCommitSummary, ConventionalCommitSummaryBreakingFlag,
@@ -261,21 +434,23 @@ mod tests {
         },
         usecase::{
             configuration::describe::{
-                DescribeConfiguration, DescribeMetadataConfiguration,
+                DescribeConfiguration, DescribeMetadataConfiguration, DescribePackageConfiguration,
                 DescribePrereleaseConfiguration, DescribeTriggerConfiguration,
+                DescribeVersionConfiguration,
+            },
+            error::describe_new_version_error::{
+                DescribeNewVersionError, DescribePrereleaseError, DescribeStableReleaseError,
             },
-            error::describe_new_version_error::DescribeNewVersionError,
             metadata_spec::MetadataSpec,
             repository::{
                 bounded_commit_summary_ingress_repository::BoundedCommitSummaryIngressRepository,
                 commit_metadata_ingress_repository::CommitMetadataIngressRepository,
                 semantic_version_ingress_repository::SemanticVersionIngressRepository,
             },
+            tag_format::{TagFormat, DEFAULT_PACKAGE_TEMPLATE},
             type_aliases::AnyError,
-            usecases::{
-                describe_new_version::{CalculateNewVersionUseCase, Change},
-                usecase::UseCase,
-            },
+            usecases::{describe_new_version::CalculateNewVersionUseCase, usecase::UseCase},
+            version_description::{Change, VersionDescription},
         },
     };
 
@@ -301,7 +476,7 @@ mod tests {
         let prerelease_configuration = DescribePrereleaseConfiguration::new(
             false,
             Box::new(|it| it.to_string()),
-            Box::new(|_it| 0),
+            Box::new(|_it| Some(0)),
             false,
         );
         let metadata_configuration = DescribeMetadataConfiguration::new(vec![]);
@@ -310,6 +485,10 @@ mod tests {
             prerelease_configuration,
             metadata_configuration,
             trigger_configuration,
+            TagFormat::default(),
+            None,
+            vec![],
+            DescribeVersionConfiguration::default(),
         )
     }
 
@@ -333,20 +512,21 @@ mod tests {
     impl BoundedCommitSummaryIngressRepository for MockCommitSummaryRepository {
         fn get_commits_from(
             &self,
-            version: Rc<Option<SemanticVersion>>,
-        ) -> Result<Box<dyn DoubleEndedIterator<Item = CommitSummary>>, AnyError> {
+            from: Option<&str>,
+            _to: Option<&str>,
+            _paths: &[String],
+        ) -> Result<Box<dyn DoubleEndedIterator<Item = Commit>>, AnyError> {
             Ok(Box::new(
-                if version
-                    .as_ref()
-                    .clone()
-                    .is_some_and(|it| it.prerelease().is_some())
-                {
+                if from.is_some_and(|it| {
+                    SemanticVersion::from_str(it).is_ok_and(|it| it.prerelease().is_some())
+                }) {
                     self.from_prerelease.clone().into_iter()
                 } else {
                     let mut full = self.commit_list.clone();
                     full.append(self.from_prerelease.clone().as_mut());
                     full.into_iter()
-                },
+                }
+                .map(|it| Commit::new(String::new(), it, vec![])),
             ))
         }
     }
@@ -368,13 +548,30 @@ mod tests {
     }
 
     impl SemanticVersionIngressRepository for MockVersionRepository {
-        fn last_version(&self) -> Result<Rc<Option<SemanticVersion>>, AnyError> {
+        fn last_version(
+            &self,
+            _tag_format: &TagFormat,
+        ) -> Result<Rc<Option<SemanticVersion>>, AnyError> {
             Ok(self.last_version.clone())
         }
 
-        fn last_stable_version(&self) -> Result<Rc<Option<SemanticVersion>>, AnyError> {
+        fn last_stable_version(
+            &self,
+            _tag_format: &TagFormat,
+        ) -> Result<Rc<Option<SemanticVersion>>, AnyError> {
             Ok(self.stable_version.clone())
         }
+
+        fn all_versions(
+            &self,
+            _tag_format: &TagFormat,
+        ) -> Result<Vec<(SemanticVersion, String)>, AnyError> {
+            Ok([self.stable_version.as_ref(), self.last_version.as_ref()]
+                .into_iter()
+                .flatten()
+                .map(|it| (it.clone(), String::new()))
+                .collect())
+        }
     }
 
     // test ancillary methods
@@ -424,8 +621,8 @@ mod tests {
             &commit_metadata_repository,
             &version_repository,
         );
-        let result = usecase
-            .greatest_change_from(
+        let commits = usecase
+            .classify_commits_from(
                 Some(
                     SemanticVersion::new(0, 1, 0, None, None)
                         .expect("Hand-crafted version must be correct"),
@@ -433,8 +630,9 @@ mod tests {
                 .into(),
             )
             .expect(
-                "greatest_change_from can only fail during commit list retrieval, which is mocked",
+                "classify_commits_from can only fail during commit list retrieval, which is mocked",
             );
+        let result = CalculateNewVersionUseCase::greatest_change(&commits);
         assert_eq!(result, Change::Minor);
     }
 
@@ -453,8 +651,8 @@ mod tests {
             &commit_metadata_repository,
             &version_repository,
         );
-        let result = usecase
-            .greatest_change_from(
+        let commits = usecase
+            .classify_commits_from(
                 Some(
                     SemanticVersion::new(0, 1, 0, None, None)
                         .expect("Hand-crafted version must be correct"),
@@ -462,8 +660,9 @@ mod tests {
                 .into(),
             )
             .expect(
-                "greatest_change_from can only fail during commit list retrieval, which is mocked",
+                "classify_commits_from can only fail during commit list retrieval, which is mocked",
             );
+        let result = CalculateNewVersionUseCase::greatest_change(&commits);
         assert_eq!(result, Change::None);
     }
 
@@ -619,7 +818,7 @@ mod tests {
             .execute()
             .expect("The first release should not have an error");
         assert_eq!(
-            result.0,
+            *result.new_version(),
             SemanticVersion::new(0, 1, 0, None, None)
                 .expect("Hand-crafted version must be correct")
         );
@@ -630,7 +829,7 @@ mod tests {
         let prerelease_configuration = DescribePrereleaseConfiguration::new(
             true,
             Box::new(|it| format!("dev{}", it)),
-            Box::new(|_it| 0),
+            Box::new(|_it| Some(0)),
             false,
         );
         let metadata_configuration = DescribeMetadataConfiguration::new(vec![]);
@@ -639,6 +838,10 @@ mod tests {
             prerelease_configuration,
             metadata_configuration,
             trigger_configuration,
+            TagFormat::default(),
+            None,
+            vec![],
+            DescribeVersionConfiguration::default(),
         );
         let commit_summary_repository = MockCommitSummaryRepository::new(
             vec![CommitSummary::Conventional(
@@ -675,7 +878,7 @@ mod tests {
             .execute()
             .expect("The first release should not have an error");
         assert_eq!(
-            result.0,
+            *result.new_version(),
             SemanticVersion::new(0, 1, 0, Some("dev1".to_string()), None)
                 .expect("Hand-crafted version must be correct")
         );
@@ -688,9 +891,7 @@ mod tests {
             Box::new(|it| format!("dev{}", it)),
             Box::new(|it| {
                 it.strip_prefix("dev")
-                    .expect("mock implementation must have a prefix 'dev'")
-                    .parse()
-                    .expect("the value must be a number")
+                    .map(|it| it.parse().expect("the value must be a number"))
             }),
             false,
         );
@@ -700,6 +901,10 @@ mod tests {
             prerelease_configuration,
             metadata_configuration,
             trigger_configuration,
+            TagFormat::default(),
+            None,
+            vec![],
+            DescribeVersionConfiguration::default(),
         );
         let commit_summary_repository = MockCommitSummaryRepository::new(
             vec![CommitSummary::Conventional(
@@ -732,7 +937,7 @@ mod tests {
             .execute()
             .expect("The first release should not have an error");
         assert_eq!(
-            result.0,
+            *result.new_version(),
             SemanticVersion::new(0, 1, 1, None, None)
                 .expect("Hand-crafted version must be correct")
         );
@@ -745,9 +950,7 @@ mod tests {
             Box::new(|it| format!("dev{}", it)),
             Box::new(|it| {
                 it.strip_prefix("dev")
-                    .expect("mock implementation must have a prefix 'dev'")
-                    .parse()
-                    .expect("the value must be a number")
+                    .map(|it| it.parse().expect("the value must be a number"))
             }),
             false,
         );
@@ -757,6 +960,10 @@ mod tests {
             prerelease_configuration,
             metadata_configuration,
             trigger_configuration,
+            TagFormat::default(),
+            None,
+            vec![],
+            DescribeVersionConfiguration::default(),
         );
         let commit_summary_repository = MockCommitSummaryRepository::new(
             vec![CommitSummary::Conventional(
@@ -789,12 +996,648 @@ mod tests {
             .execute()
             .expect("The first release should not have an error");
         assert_eq!(
-            result.0,
+            *result.new_version(),
             SemanticVersion::new(0, 2, 0, None, None)
                 .expect("Hand-crafted version must be correct")
         );
     }
 
+    struct MockPackageCommitSummaryRepository {}
+
+    impl BoundedCommitSummaryIngressRepository for MockPackageCommitSummaryRepository {
+        fn get_commits_from(
+            &self,
+            from: Option<&str>,
+            _to: Option<&str>,
+            paths: &[String],
+        ) -> Result<Box<dyn DoubleEndedIterator<Item = Commit>>, AnyError> {
+            assert_eq!(from, Some("api/v1.0.0"));
+            let commits = if paths.is_empty() {
+                vec![
+                    ("a1", "feat(web): add a page"),
+                    ("b2", "fix(api): fix an endpoint"),
+                    ("c3", "feat: add a shared endpoint"),
+                ]
+            } else {
+                assert_eq!(paths, ["api/"]);
+                vec![("c3", "feat: add a shared endpoint")]
+            };
+            Ok(Box::new(commits.into_iter().map(|(hash, message)| {
+                Commit::new(
+                    hash.to_string(),
+                    CommitSummary::from_str(message)
+                        .expect("Hand-crafted commits are always correct"),
+                    vec![],
+                )
+            })))
+        }
+    }
+
+    #[test]
+    fn package_considers_its_scope_and_paths() {
+        let configuration = DescribeConfiguration::new(
+            DescribePrereleaseConfiguration::new(
+                false,
+                Box::new(|it| it.to_string()),
+                Box::new(|_it| Some(0)),
+                false,
+            ),
+            DescribeMetadataConfiguration::new(vec![]),
+            trigger_configuration(),
+            TagFormat::for_package("api", DEFAULT_PACKAGE_TEMPLATE)
+                .expect("The template has a placeholder"),
+            Some(DescribePackageConfiguration::new(
+                "api".to_string(),
+                vec!["api/".to_string()],
+            )),
+            vec![],
+            DescribeVersionConfiguration::default(),
+        );
+        let commit_summary_repository = MockPackageCommitSummaryRepository {};
+        let commit_metadata_repository = MockCommitMetadataRepository {};
+        let version_repository = MockVersionRepository {
+            stable_version: Some(
+                SemanticVersion::new(1, 0, 0, None, None)
+                    .expect("Hand-crafted version must be correct"),
+            )
+            .into(),
+            last_version: None.into(),
+        };
+        let usecase = CalculateNewVersionUseCase::new(
+            configuration,
+            &commit_summary_repository,
+            &commit_metadata_repository,
+            &version_repository,
+        );
+        let result = usecase.execute().expect("The package has relevant changes");
+        assert_eq!(
+            *result.new_version(),
+            SemanticVersion::new(1, 1, 0, None, None)
+                .expect("Hand-crafted version must be correct")
+        );
+    }
+
+    struct MockPathCommitSummaryRepository {}
+
+    impl BoundedCommitSummaryIngressRepository for MockPathCommitSummaryRepository {
+        fn get_commits_from(
+            &self,
+            _from: Option<&str>,
+            _to: Option<&str>,
+            paths: &[String],
+        ) -> Result<Box<dyn DoubleEndedIterator<Item = Commit>>, AnyError> {
+            let mut commits = vec!["fix: fix a typo in the docs"];
+            if paths.is_empty() {
+                commits.push("feat: add a feature");
+            }
+            Ok(Box::new(commits.into_iter().map(|message| {
+                Commit::new(
+                    String::new(),
+                    CommitSummary::from_str(message)
+                        .expect("Hand-crafted commits are always correct"),
+                    vec![],
+                )
+            })))
+        }
+    }
+
+    #[test]
+    fn paths_restrict_considered_commits() {
+        let configuration = DescribeConfiguration::new(
+            DescribePrereleaseConfiguration::new(
+                false,
+                Box::new(|it| it.to_string()),
+                Box::new(|_it| Some(0)),
+                false,
+            ),
+            DescribeMetadataConfiguration::new(vec![]),
+            trigger_configuration(),
+            TagFormat::default(),
+            None,
+            vec!["docs/**".to_string()],
+            DescribeVersionConfiguration::default(),
+        );
+        let commit_summary_repository = MockPathCommitSummaryRepository {};
+        let commit_metadata_repository = MockCommitMetadataRepository {};
+        let version_repository = MockVersionRepository {
+            stable_version: Some(
+                SemanticVersion::new(1, 0, 0, None, None)
+                    .expect("Hand-crafted version must be correct"),
+            )
+            .into(),
+            last_version: None.into(),
+        };
+        let usecase = CalculateNewVersionUseCase::new(
+            configuration,
+            &commit_summary_repository,
+            &commit_metadata_repository,
+            &version_repository,
+        );
+        let result = usecase.execute().expect("The paths have relevant changes");
+        assert_eq!(
+            *result.new_version(),
+            SemanticVersion::new(1, 0, 1, None, None)
+                .expect("Hand-crafted version must be correct")
+        );
+    }
+
+    fn forced_configuration<'a>(
+        version: DescribeVersionConfiguration,
+    ) -> DescribeConfiguration<'a> {
+        DescribeConfiguration::new(
+            DescribePrereleaseConfiguration::new(
+                false,
+                Box::new(|it| it.to_string()),
+                Box::new(|_it| Some(0)),
+                false,
+            ),
+            DescribeMetadataConfiguration::new(vec![]),
+            trigger_configuration(),
+            TagFormat::default(),
+            None,
+            vec![],
+            version,
+        )
+    }
+
+    fn fix_and_docs_repository() -> MockCommitSummaryRepository {
+        MockCommitSummaryRepository::new(
+            vec![
+                CommitSummary::Conventional(
+                    ConventionalCommitSummary::new(
+                        "fix".to_owned(),
+                        None,
+                        ConventionalCommitSummaryBreakingFlag::Disabled,
+                        "test".to_string(),
+                    )
+                    .expect("Hand-crafted commits are always correct"),
+                ),
+                CommitSummary::Conventional(
+                    ConventionalCommitSummary::new(
+                        "docs".to_owned(),
+                        None,
+                        ConventionalCommitSummaryBreakingFlag::Disabled,
+                        "test".to_string(),
+                    )
+                    .expect("Hand-crafted commits are always correct"),
+                ),
+            ],
+            vec![],
+        )
+    }
+
+    fn stable_version_repository() -> MockVersionRepository {
+        MockVersionRepository {
+            stable_version: Some(
+                SemanticVersion::new(1, 0, 0, None, None)
+                    .expect("Hand-crafted version must be correct"),
+            )
+            .into(),
+            last_version: None.into(),
+        }
+    }
+
+    #[test]
+    fn bump_forces_a_greater_change() {
+        let configuration = forced_configuration(DescribeVersionConfiguration::new(
+            Some(Change::Major),
+            None,
+            false,
+            false,
+            false,
+        ));
+        let commit_summary_repository = fix_and_docs_repository();
+        let commit_metadata_repository = MockCommitMetadataRepository {};
+        let version_repository = stable_version_repository();
+        let usecase = CalculateNewVersionUseCase::new(
+            configuration,
+            &commit_summary_repository,
+            &commit_metadata_repository,
+            &version_repository,
+        );
+        let result = usecase.execute().expect("The bump is always relevant");
+        assert_eq!(
+            *result.new_version(),
+            SemanticVersion::new(2, 0, 0, None, None)
+                .expect("Hand-crafted version must be correct")
+        );
+        assert_eq!(result.change(), Change::Major);
+    }
+
+    #[test]
+    fn bump_does_not_lower_the_change() {
+        let configuration = forced_configuration(DescribeVersionConfiguration::new(
+            Some(Change::Patch),
+            None,
+            false,
+            false,
+            false,
+        ));
+        let commit_summary_repository = MockCommitSummaryRepository::new(
+            vec![CommitSummary::Conventional(
+                ConventionalCommitSummary::new(
+                    "feat".to_owned(),
+                    None,
+                    ConventionalCommitSummaryBreakingFlag::Disabled,
+                    "test".to_string(),
+                )
+                .expect("Hand-crafted commits are always correct"),
+            )],
+            vec![],
+        );
+        let commit_metadata_repository = MockCommitMetadataRepository {};
+        let version_repository = stable_version_repository();
+        let usecase = CalculateNewVersionUseCase::new(
+            configuration,
+            &commit_summary_repository,
+            &commit_metadata_repository,
+            &version_repository,
+        );
+        let result = usecase.execute().expect("The commits are relevant");
+        assert_eq!(
+            *result.new_version(),
+            SemanticVersion::new(1, 1, 0, None, None)
+                .expect("Hand-crafted version must be correct")
+        );
+    }
+
+    #[test]
+    fn set_version_ignores_the_commits() {
+        let version = SemanticVersion::new(1, 5, 0, Some("rc.1".to_string()), None)
+            .expect("Hand-crafted version must be correct");
+        let configuration = forced_configuration(DescribeVersionConfiguration::new(
+            None,
+            Some(version.clone()),
+            false,
+            false,
+            false,
+        ));
+        let commit_summary_repository = fix_and_docs_repository();
+        let commit_metadata_repository = MockCommitMetadataRepository {};
+        let version_repository = stable_version_repository();
+        let usecase = CalculateNewVersionUseCase::new(
+            configuration,
+            &commit_summary_repository,
+            &commit_metadata_repository,
+            &version_repository,
+        );
+        let result = usecase
+            .execute()
+            .expect("The set version comes after the last stable one");
+        assert_eq!(*result.new_version(), version);
+        assert_eq!(result.change(), Change::Minor);
+        assert_eq!(result.commits().len(), 2);
+    }
+
+    #[test]
+    fn set_version_not_after_the_last_stable_is_an_error() {
+        for version in ["0.2.0", "1.0.0", "1.0.0-rc.1"] {
+            let configuration = forced_configuration(DescribeVersionConfiguration::new(
+                None,
+                Some(
+                    SemanticVersion::from_str(version)
+                        .expect("Hand-crafted version must be correct"),
+                ),
+                false,
+                false,
+                false,
+            ));
+            let commit_summary_repository = fix_and_docs_repository();
+            let commit_metadata_repository = MockCommitMetadataRepository {};
+            let version_repository = stable_version_repository();
+            let usecase = CalculateNewVersionUseCase::new(
+                configuration,
+                &commit_summary_repository,
+                &commit_metadata_repository,
+                &version_repository,
+            );
+            assert!(matches!(
+                usecase.execute(),
+                Err(DescribeNewVersionError::StableRelease(
+                    DescribeStableReleaseError::NotGreater(_)
+                ))
+            ));
+        }
+    }
+
+    fn initial_development_usecase_result(
+        base_version: SemanticVersion,
+        breaking: bool,
+        version: DescribeVersionConfiguration,
+    ) -> Result<VersionDescription, DescribeNewVersionError> {
+        let configuration = forced_configuration(version);
+        let commit_summary_repository = MockCommitSummaryRepository::new(
+            vec![CommitSummary::Conventional(
+                ConventionalCommitSummary::new(
+                    "feat".to_owned(),
+                    None,
+                    breaking.into(),
+                    "test".to_string(),
+                )
+                .expect("Hand-crafted commits are always correct"),
+            )],
+            vec![],
+        );
+        let commit_metadata_repository = MockCommitMetadataRepository {};
+        let version_repository = MockVersionRepository {
+            stable_version: Some(base_version).into(),
+            last_version: None.into(),
+        };
+        let usecase = CalculateNewVersionUseCase::new(
+            configuration,
+            &commit_summary_repository,
+            &commit_metadata_repository,
+            &version_repository,
+        );
+        usecase.execute()
+    }
+
+    #[test]
+    fn initial_development_lowers_the_changes() {
+        let base = SemanticVersion::new(0, 4, 0, None, None)
+            .expect("Hand-crafted version must be correct");
+        let breaking = initial_development_usecase_result(
+            base.clone(),
+            true,
+            DescribeVersionConfiguration::new(None, None, true, false, false),
+        )
+        .expect("The commits are relevant");
+        assert_eq!(
+            *breaking.new_version(),
+            SemanticVersion::new(0, 5, 0, None, None)
+                .expect("Hand-crafted version must be correct")
+        );
+        assert_eq!(breaking.change(), Change::Minor);
+        let feature = initial_development_usecase_result(
+            base,
+            false,
+            DescribeVersionConfiguration::new(None, None, true, false, false),
+        )
+        .expect("The commits are relevant");
+        assert_eq!(
+            *feature.new_version(),
+            SemanticVersion::new(0, 4, 1, None, None)
+                .expect("Hand-crafted version must be correct")
+        );
+    }
+
+    #[test]
+    fn initial_development_ends_with_the_first_major_version() {
+        let result = initial_development_usecase_result(
+            SemanticVersion::new(1, 4, 0, None, None)
+                .expect("Hand-crafted version must be correct"),
+            true,
+            DescribeVersionConfiguration::new(None, None, true, false, false),
+        )
+        .expect("The commits are relevant");
+        assert_eq!(
+            *result.new_version(),
+            SemanticVersion::new(2, 0, 0, None, None)
+                .expect("Hand-crafted version must be correct")
+        );
+    }
+
+    #[test]
+    fn graduate_releases_the_first_major_version() {
+        let result = initial_development_usecase_result(
+            SemanticVersion::new(0, 4, 0, None, None)
+                .expect("Hand-crafted version must be correct"),
+            false,
+            DescribeVersionConfiguration::new(None, None, true, true, false),
+        )
+        .expect("The initial development can be ended");
+        assert_eq!(
+            *result.new_version(),
+            SemanticVersion::new(1, 0, 0, None, None)
+                .expect("Hand-crafted version must be correct")
+        );
+        assert_eq!(result.change(), Change::Major);
+    }
+
+    #[test]
+    fn graduate_after_the_first_major_version_is_an_error() {
+        let result = initial_development_usecase_result(
+            SemanticVersion::new(1, 4, 0, None, None)
+                .expect("Hand-crafted version must be correct"),
+            false,
+            DescribeVersionConfiguration::new(None, None, false, true, false),
+        );
+        assert!(matches!(
+            result,
+            Err(DescribeNewVersionError::StableRelease(
+                DescribeStableReleaseError::AlreadyGraduated(_)
+            ))
+        ));
+    }
+
+    struct MockTaggedVersionRepository {
+        versions: Vec<SemanticVersion>,
+    }
+
+    impl SemanticVersionIngressRepository for MockTaggedVersionRepository {
+        fn last_version(
+            &self,
+            _tag_format: &TagFormat,
+        ) -> Result<Rc<Option<SemanticVersion>>, AnyError> {
+            Ok(self.versions.iter().max().cloned().into())
+        }
+
+        fn last_stable_version(
+            &self,
+            _tag_format: &TagFormat,
+        ) -> Result<Rc<Option<SemanticVersion>>, AnyError> {
+            Ok(self
+                .versions
+                .iter()
+                .filter(|it| it.prerelease().is_none())
+                .max()
+                .cloned()
+                .into())
+        }
+
+        fn all_versions(
+            &self,
+            _tag_format: &TagFormat,
+        ) -> Result<Vec<(SemanticVersion, String)>, AnyError> {
+            Ok(self
+                .versions
+                .iter()
+                .map(|it| (it.clone(), String::new()))
+                .collect())
+        }
+    }
+
+    /**
+     * The repository is tagged with 1.1.0 and the given versions, and a feature was added since
+     * 1.1.0. The prereleases are described on the given channel.
+     */
+    fn channel_usecase_result(
+        tags: &[&str],
+        channel: &'static str,
+        new_commits: bool,
+        prerelease: bool,
+        version: DescribeVersionConfiguration,
+    ) -> Result<VersionDescription, DescribeNewVersionError> {
+        let feature = CommitSummary::Conventional(
+            ConventionalCommitSummary::new(
+                "feat".to_owned(),
+                None,
+                ConventionalCommitSummaryBreakingFlag::Disabled,
+                "test".to_string(),
+            )
+            .expect("Hand-crafted commits are always correct"),
+        );
+        let configuration = DescribeConfiguration::new(
+            DescribePrereleaseConfiguration::new(
+                prerelease,
+                Box::new(move |it| format!("{}.{}", channel, it)),
+                Box::new(move |it| {
+                    it.strip_prefix(channel)
+                        .and_then(|it| it.strip_prefix('.'))
+                        .map(|it| it.parse().expect("the value must be a number"))
+                }),
+                false,
+            ),
+            DescribeMetadataConfiguration::new(vec![]),
+            trigger_configuration(),
+            TagFormat::default(),
+            None,
+            vec![],
+            version,
+        );
+        let commit_summary_repository = MockCommitSummaryRepository::new(
+            vec![feature.clone()],
+            if new_commits { vec![feature] } else { vec![] },
+        );
+        let commit_metadata_repository = MockCommitMetadataRepository {};
+        let version_repository = MockTaggedVersionRepository {
+            versions: ["1.1.0"]
+                .iter()
+                .chain(tags)
+                .map(|it| {
+                    SemanticVersion::from_str(it).expect("Hand-crafted version must be correct")
+                })
+                .collect(),
+        };
+        let usecase = CalculateNewVersionUseCase::new(
+            configuration,
+            &commit_summary_repository,
+            &commit_metadata_repository,
+            &version_repository,
+        );
+        usecase.execute()
+    }
+
+    #[test]
+    fn channel_change_restarts_the_numbering() {
+        let result = channel_usecase_result(
+            &["1.2.0-alpha.3"],
+            "beta",
+            false,
+            true,
+            DescribeVersionConfiguration::default(),
+        )
+        .expect("Changing channel does not need new commits");
+        assert_eq!(result.new_version().to_string(), "1.2.0-beta.1");
+    }
+
+    #[test]
+    fn same_channel_continues_the_numbering() {
+        let result = channel_usecase_result(
+            &["1.2.0-beta.1"],
+            "beta",
+            true,
+            true,
+            DescribeVersionConfiguration::default(),
+        )
+        .expect("There are new commits");
+        assert_eq!(result.new_version().to_string(), "1.2.0-beta.2");
+        let result = channel_usecase_result(
+            &["1.2.0-beta.1"],
+            "beta",
+            false,
+            true,
+            DescribeVersionConfiguration::default(),
+        );
+        assert!(matches!(
+            result,
+            Err(DescribeNewVersionError::Prerelease(
+                DescribePrereleaseError::NoChanges(_)
+            ))
+        ));
+    }
+
+    #[test]
+    fn channel_continues_from_its_greatest_tag() {
+        let result = channel_usecase_result(
+            &["1.2.0-alpha.1", "1.2.0-beta.1", "1.2.0-beta.2"],
+            "beta",
+            true,
+            true,
+            DescribeVersionConfiguration::default(),
+        )
+        .expect("There are new commits");
+        assert_eq!(result.new_version().to_string(), "1.2.0-beta.3");
+    }
+
+    #[test]
+    fn moving_back_to_a_previous_channel_is_an_error() {
+        let tags = ["1.2.0-alpha.1", "1.2.0-beta.1"];
+        let result = channel_usecase_result(
+            &tags[..1],
+            "beta",
+            false,
+            true,
+            DescribeVersionConfiguration::default(),
+        )
+        .expect("Changing channel does not need new commits");
+        assert_eq!(result.new_version().to_string(), "1.2.0-beta.1");
+        let result = channel_usecase_result(
+            &tags,
+            "alpha",
+            true,
+            true,
+            DescribeVersionConfiguration::default(),
+        );
+        assert!(matches!(
+            result,
+            Err(DescribeNewVersionError::Prerelease(
+                DescribePrereleaseError::Outdated(_)
+            ))
+        ));
+    }
+
+    #[test]
+    fn promote_releases_the_last_prerelease() {
+        let result = channel_usecase_result(
+            &["1.2.0-rc.2"],
+            "rc",
+            false,
+            false,
+            DescribeVersionConfiguration::new(None, None, false, false, true),
+        )
+        .expect("The last version is a prerelease");
+        assert_eq!(result.new_version().to_string(), "1.2.0");
+        assert_eq!(result.change(), Change::Minor);
+    }
+
+    #[test]
+    fn promote_without_a_prerelease_is_an_error() {
+        let result = channel_usecase_result(
+            &[],
+            "rc",
+            true,
+            false,
+            DescribeVersionConfiguration::new(None, None, false, false, true),
+        );
+        assert!(matches!(
+            result,
+            Err(DescribeNewVersionError::StableRelease(
+                DescribeStableReleaseError::NothingToPromote(_)
+            ))
+        ));
+    }
+
     #[test]
     fn major_trigger_proc_major_number_increase() {
         let prerelease_configuration = DescribePrereleaseConfiguration::new(
@@ -802,9 +1645,7 @@ mod tests {
             Box::new(|it| format!("dev{}", it)),
             Box::new(|it| {
                 it.strip_prefix("dev")
-                    .expect("mock implementation must have a prefix 'dev'")
-                    .parse()
-                    .expect("the value must be a number")
+                    .map(|it| it.parse().expect("the value must be a number"))
             }),
             false,
         );
@@ -814,6 +1655,10 @@ mod tests {
             prerelease_configuration,
             metadata_configuration,
             trigger_configuration,
+            TagFormat::default(),
+            None,
+            vec![],
+            DescribeVersionConfiguration::default(),
         );
         let commit_summary_repository = MockCommitSummaryRepository::new(
             vec![CommitSummary::Conventional(
@@ -846,7 +1691,7 @@ mod tests {
             .execute()
             .expect("The first release should not have an error");
         assert_eq!(
-            result.0,
+            *result.new_version(),
             SemanticVersion::new(1, 0, 0, None, None)
                 .expect("Hand-crafted version must be correct")
         );
@@ -859,9 +1704,7 @@ mod tests {
             Box::new(|it| format!("dev{}", it)),
             Box::new(|it| {
                 it.strip_prefix("dev")
-                    .expect("mock implementation must have a prefix 'dev'")
-                    .parse()
-                    .expect("the value must be a number")
+                    .map(|it| it.parse().expect("the value must be a number"))
             }),
             false,
         );
@@ -871,6 +1714,10 @@ mod tests {
             prerelease_configuration,
             metadata_configuration,
             trigger_configuration,
+            TagFormat::default(),
+            None,
+            vec![],
+            DescribeVersionConfiguration::default(),
         );
         let commit_summary_repository = MockCommitSummaryRepository::new(
             vec![CommitSummary::Conventional(
@@ -913,9 +1760,7 @@ mod tests {
             Box::new(|it| format!("dev{}", it)),
             Box::new(|it| {
                 it.strip_prefix("alpha")
-                    .expect("mock implementation must have a prefix 'alpha'")
-                    .parse()
-                    .expect("the value must be a number")
+                    .map(|it| it.parse().expect("the value must be a number"))
             }),
             true,
         );
@@ -925,6 +1770,10 @@ mod tests {
             prerelease_configuration,
             metadata_configuration,
             trigger_configuration,
+            TagFormat::default(),
+            None,
+            vec![],
+            DescribeVersionConfiguration::default(),
         );
         let commit_summary_repository = MockCommitSummaryRepository::new(
             vec![
@@ -978,7 +1827,7 @@ mod tests {
         );
         let result = usecase.execute().expect("This calc should be correct");
         assert_eq!(
-            result.0,
+            *result.new_version(),
             SemanticVersion::new(0, 1, 1, Some("dev1".to_string()), None)
                 .expect("Hand-crafted version must be correct")
         );
@@ -989,7 +1838,7 @@ mod tests {
         let prerelease_configuration = DescribePrereleaseConfiguration::new(
             true,
             Box::new(|it| it.to_string()),
-            Box::new(|it| it.parse().expect("The value must be a valid number")),
+            Box::new(|it| Some(it.parse().expect("The value must be a valid number"))),
             false,
         );
         let metadata_configuration = DescribeMetadataConfiguration::new(vec![]);
@@ -998,6 +1847,10 @@ mod tests {
             prerelease_configuration,
             metadata_configuration,
             trigger_configuration,
+            TagFormat::default(),
+            None,
+            vec![],
+            DescribeVersionConfiguration::default(),
         );
         let commit_summary_repository = MockCommitSummaryRepository::new(
             vec![CommitSummary::Conventional(
@@ -1042,9 +1895,7 @@ mod tests {
             Box::new(|it| format!("dev{}", it)),
             Box::new(|it| {
                 it.strip_prefix("dev")
-                    .expect("mock implementation must have a prefix 'dev'")
-                    .parse()
-                    .expect("the value must be a number")
+                    .map(|it| it.parse().expect("the value must be a number"))
             }),
             false,
         );
@@ -1054,6 +1905,10 @@ mod tests {
             prerelease_configuration,
             metadata_configuration,
             trigger_configuration,
+            TagFormat::default(),
+            None,
+            vec![],
+            DescribeVersionConfiguration::default(),
         );
         let commit_summary_repository = MockCommitSummaryRepository::new(
             vec![
@@ -1107,7 +1962,7 @@ mod tests {
         );
         let result = usecase.execute().expect("This calc should be correct");
         assert_eq!(
-            result.0,
+            *result.new_version(),
             SemanticVersion::new(0, 2, 0, Some("dev1".to_string()), None)
                 .expect("Hand-crafted version must be correct")
         );
@@ -1120,9 +1975,7 @@ mod tests {
             Box::new(|it| format!("dev{}", it)),
             Box::new(|it| {
                 it.strip_prefix("dev")
-                    .expect("mock implementation must have a prefix 'dev'")
-                    .parse()
-                    .expect("the value must be a number")
+                    .map(|it| it.parse().expect("the value must be a number"))
             }),
             false,
         );
@@ -1132,6 +1985,10 @@ mod tests {
             prerelease_configuration,
             metadata_configuration,
             trigger_configuration,
+            TagFormat::default(),
+            None,
+            vec![],
+            DescribeVersionConfiguration::default(),
         );
         let commit_summary_repository = MockCommitSummaryRepository::new(
             vec![CommitSummary::Conventional(
@@ -1178,9 +2035,7 @@ mod tests {
             Box::new(|it| format!("dev{}", it)),
             Box::new(|it| {
                 it.strip_prefix("dev")
-                    .expect("mock implementation must have a prefix 'dev'")
-                    .parse()
-                    .expect("the value must be a number")
+                    .map(|it| it.parse().expect("the value must be a number"))
             }),
             false,
         );
@@ -1190,6 +2045,10 @@ mod tests {
             prerelease_configuration,
             metadata_configuration,
             trigger_configuration,
+            TagFormat::default(),
+            None,
+            vec![],
+            DescribeVersionConfiguration::default(),
         );
         let commit_summary_repository = MockCommitSummaryRepository::new(
             vec![CommitSummary::Conventional(
@@ -1232,7 +2091,7 @@ mod tests {
         );
         let result = usecase.execute().expect("This calc should be correct");
         assert_eq!(
-            result.0,
+            *result.new_version(),
             SemanticVersion::new(0, 1, 1, Some("dev2".to_string()), None)
                 .expect("Hand-crafted version must be correct")
         );
@@ -1243,7 +2102,7 @@ mod tests {
         let prerelease_configuration = DescribePrereleaseConfiguration::new(
             true,
             Box::new(|it| it.to_string()),
-            Box::new(|it| it.parse().expect("The value must be a number")),
+            Box::new(|it| Some(it.parse().expect("The value must be a number"))),
             false,
         );
         let metadata_configuration = DescribeMetadataConfiguration::new(vec![]);
@@ -1252,6 +2111,10 @@ mod tests {
             prerelease_configuration,
             metadata_configuration,
             trigger_configuration,
+            TagFormat::default(),
+            None,
+            vec![],
+            DescribeVersionConfiguration::default(),
         );
         let commit_summary_repository = MockCommitSummaryRepository::new(
             vec![
@@ -1301,7 +2164,7 @@ mod tests {
         );
         let result = usecase.execute().expect("This calc should be correct");
         assert_eq!(
-            result.0,
+            *result.new_version(),
             SemanticVersion::new(0, 1, 0, Some("2".to_string()), None)
                 .expect("Hand-crafted version must be correct")
         );
@@ -1316,9 +2179,7 @@ mod tests {
             Box::new(|it| format!("dev{}", it)),
             Box::new(|it| {
                 it.strip_prefix("dev")
-                    .expect("mock implementation must have a prefix 'dev'")
-                    .parse()
-                    .expect("the value must be a number")
+                    .map(|it| it.parse().expect("the value must be a number"))
             }),
             false,
         );
@@ -1328,6 +2189,10 @@ mod tests {
             prerelease_configuration,
             metadata_configuration,
             trigger_configuration,
+            TagFormat::default(),
+            None,
+            vec![],
+            DescribeVersionConfiguration::default(),
         );
         let commit_summary_repository = MockCommitSummaryRepository::new(vec![], vec![]);
         let commit_metadata_repository = MockCommitMetadataRepository {};
@@ -1358,9 +2223,7 @@ mod tests {
             Box::new(|it| format!("dev{}", it)),
             Box::new(|it| {
                 it.strip_prefix("dev")
-                    .expect("mock implementation must have a prefix 'dev'")
-                    .parse()
-                    .expect("the value must be a number")
+                    .map(|it| it.parse().expect("the value must be a number"))
             }),
             false,
         );
@@ -1370,6 +2233,10 @@ mod tests {
             prerelease_configuration,
             metadata_configuration,
             trigger_configuration,
+            TagFormat::default(),
+            None,
+            vec![],
+            DescribeVersionConfiguration::default(),
         );
         let commit_summary_repository = MockCommitSummaryRepository::new(vec![], vec![]);
         let commit_metadata_repository = MockCommitMetadataRepository {};
@@ -1400,9 +2267,7 @@ mod tests {
             Box::new(|it| format!("dev{}", it)),
             Box::new(|it| {
                 it.strip_prefix("dev")
-                    .expect("mock implementation must have a prefix 'dev'")
-                    .parse()
-                    .expect("the value must be a number")
+                    .map(|it| it.parse().expect("the value must be a number"))
             }),
             false,
         );
@@ -1413,6 +2278,10 @@ mod tests {
             prerelease_configuration,
             metadata_configuration,
             trigger_configuration,
+            TagFormat::default(),
+            None,
+            vec![],
+            DescribeVersionConfiguration::default(),
         );
         let commit_summary_repository = MockCommitSummaryRepository::new(vec![], vec![]);
         let commit_metadata_repository = MockCommitMetadataRepository {};
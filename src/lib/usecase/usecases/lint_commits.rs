@@ -0,0 +1,143 @@
+use crate::usecase::{
+    configuration::lint::LintConfiguration, error::lint_commits_error::LintCommitsError,
+    lint_issue::LintIssue,
+    repository::commit_range_ingress_repository::CommitRangeIngressRepository,
+};
+
+use super::usecase::UseCase;
+
+pub struct LintCommitsUseCase<'a> {
+    configuration: LintConfiguration,
+    commit_repository: &'a dyn CommitRangeIngressRepository,
+}
+
+impl<'a, 'b: 'a> LintCommitsUseCase<'a> {
+    pub fn new(
+        configuration: LintConfiguration,
+        commit_repository: &'b dyn CommitRangeIngressRepository,
+    ) -> Self {
+        LintCommitsUseCase {
+            configuration,
+            commit_repository,
+        }
+    }
+}
+
+impl UseCase<Vec<LintIssue>, LintCommitsError> for LintCommitsUseCase<'_> {
+    fn execute(&self) -> Result<Vec<LintIssue>, LintCommitsError> {
+        Ok(self
+            .commit_repository
+            .get_commits_in_range(self.configuration.range())?
+            .filter_map(|it| {
+                it.violation(self.configuration.policy())
+                    .map(|reason| LintIssue::new(it.hash(), it.header(), &reason))
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        domain::{
+            commit_policy::CommitPolicy,
+            commit_summary::CommitSummary,
+            conventional_commit_summary::{
+                ConventionalCommitSummary, ConventionalCommitSummaryBreakingFlag,
+            },
+        },
+        usecase::{
+            configuration::lint::LintConfiguration,
+            parsed_commit::ParsedCommit,
+            repository::commit_range_ingress_repository::CommitRangeIngressRepository,
+            type_aliases::AnyError,
+            usecases::{lint_commits::LintCommitsUseCase, usecase::UseCase},
+        },
+    };
+
+    struct MockCommitRangeIngressRepository {}
+
+    fn conventional(typ: &str, scope: Option<&str>) -> CommitSummary {
+        CommitSummary::Conventional(
+            ConventionalCommitSummary::new(
+                typ.to_string(),
+                scope.map(|it| it.to_string()),
+                ConventionalCommitSummaryBreakingFlag::Disabled,
+                "test".to_string(),
+            )
+            .expect("Hand-crafted commit summary is always correct"),
+        )
+    }
+
+    impl CommitRangeIngressRepository for MockCommitRangeIngressRepository {
+        fn get_commits_in_range(
+            &self,
+            _range: Option<&str>,
+        ) -> Result<Box<dyn Iterator<Item = ParsedCommit>>, AnyError> {
+            Ok(Box::new(
+                vec![
+                    ParsedCommit::new(
+                        "aaaaaaa",
+                        "feat(api): test",
+                        Ok(conventional("feat", Some("api"))),
+                    ),
+                    ParsedCommit::new(
+                        "bbbbbbb",
+                        "Update readme",
+                        Ok(CommitSummary::FreeForm("Update readme".to_string())),
+                    ),
+                    ParsedCommit::new(
+                        "ccccccc",
+                        "Feat: test",
+                        Err("invalid type 'Feat'".to_string()),
+                    ),
+                    ParsedCommit::new(
+                        "ddddddd",
+                        "fetaure: test",
+                        Ok(conventional("fetaure", None)),
+                    ),
+                    ParsedCommit::new(
+                        "eeeeeee",
+                        "fix(cli): test",
+                        Ok(conventional("fix", Some("cli"))),
+                    ),
+                ]
+                .into_iter(),
+            ))
+        }
+    }
+
+    #[test]
+    fn unrestricted_lint_reports_invalid_commits() {
+        let repository = MockCommitRangeIngressRepository {};
+        let usecase = LintCommitsUseCase::new(
+            LintConfiguration::new(None, CommitPolicy::default()),
+            &repository,
+        );
+        let issues = usecase.execute().expect("Mock repository never fails");
+        assert_eq!(
+            issues.iter().map(|it| it.hash()).collect::<Vec<_>>(),
+            vec!["bbbbbbb", "ccccccc"]
+        );
+        assert_eq!(issues[1].reason(), "invalid type 'Feat'");
+    }
+
+    #[test]
+    fn policy_lint_reports_unknown_types_and_scopes() {
+        let repository = MockCommitRangeIngressRepository {};
+        let policy = CommitPolicy::new(
+            Some(vec!["feat".to_string(), "fix".to_string()]),
+            Some(vec!["api".to_string()]),
+        );
+        let usecase = LintCommitsUseCase::new(LintConfiguration::new(None, policy), &repository);
+        let issues = usecase.execute().expect("Mock repository never fails");
+        assert_eq!(
+            issues.iter().map(|it| it.hash()).collect::<Vec<_>>(),
+            vec!["bbbbbbb", "ccccccc", "ddddddd", "eeeeeee"]
+        );
+        assert_eq!(
+            issues[2].reason(),
+            "type 'fetaure' is not allowed (did you mean 'feat'?)"
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use crate::usecase::{
+    configuration::lint::LintMessageConfiguration, error::lint_commits_error::LintCommitsError,
+    repository::commit_message_ingress_repository::CommitMessageIngressRepository,
+};
+
+use super::usecase::UseCase;
+
+/// Messages generated by git (and by the merge of pull requests), which are accepted as they are.
+const GENERATED_MESSAGE_PREFIXES: [&str; 13] = [
+    "Merge branch '",
+    "Merge branches '",
+    "Merge remote-tracking branch '",
+    "Merge remote-tracking branches '",
+    "Merge tag '",
+    "Merge tags '",
+    "Merge commit '",
+    "Merge commits '",
+    "Merge pull request #",
+    "Revert \"",
+    "fixup! ",
+    "squash! ",
+    "amend! ",
+];
+
+pub struct LintMessageUseCase<'a> {
+    configuration: LintMessageConfiguration,
+    message_repository: &'a dyn CommitMessageIngressRepository,
+}
+
+impl<'a, 'b: 'a> LintMessageUseCase<'a> {
+    pub fn new(
+        configuration: LintMessageConfiguration,
+        message_repository: &'b dyn CommitMessageIngressRepository,
+    ) -> Self {
+        LintMessageUseCase {
+            configuration,
+            message_repository,
+        }
+    }
+}
+
+impl UseCase<Option<String>, LintCommitsError> for LintMessageUseCase<'_> {
+    fn execute(&self) -> Result<Option<String>, LintCommitsError> {
+        let message = self
+            .message_repository
+            .get_message(self.configuration.path())?;
+        if GENERATED_MESSAGE_PREFIXES
+            .iter()
+            .any(|it| message.header().starts_with(it))
+        {
+            Ok(None)
+        } else {
+            Ok(message.violation(self.configuration.policy()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        domain::{
+            commit_policy::CommitPolicy,
+            commit_summary::CommitSummary,
+            conventional_commit_summary::{
+                ConventionalCommitSummary, ConventionalCommitSummaryBreakingFlag,
+            },
+        },
+        usecase::{
+            configuration::lint::LintMessageConfiguration,
+            parsed_commit::ParsedCommit,
+            repository::commit_message_ingress_repository::CommitMessageIngressRepository,
+            type_aliases::AnyError,
+            usecases::{lint_message::LintMessageUseCase, usecase::UseCase},
+        },
+    };
+
+    struct MockCommitMessageIngressRepository {}
+
+    impl CommitMessageIngressRepository for MockCommitMessageIngressRepository {
+        fn get_message(&self, path: &str) -> Result<ParsedCommit, AnyError> {
+            Ok(match path {
+                "valid" => ParsedCommit::new(
+                    "",
+                    "feat: test",
+                    Ok(CommitSummary::Conventional(
+                        ConventionalCommitSummary::new(
+                            "feat".to_string(),
+                            None,
+                            ConventionalCommitSummaryBreakingFlag::Disabled,
+                            "test".to_string(),
+                        )
+                        .expect("Hand-crafted commit summary is always correct"),
+                    )),
+                ),
+                header => {
+                    ParsedCommit::new("", header, Ok(CommitSummary::FreeForm(header.to_string())))
+                }
+            })
+        }
+    }
+
+    fn lint(path: &str, policy: CommitPolicy) -> Option<String> {
+        let repository = MockCommitMessageIngressRepository {};
+        LintMessageUseCase::new(
+            LintMessageConfiguration::new(path.to_string(), policy),
+            &repository,
+        )
+        .execute()
+        .expect("Mock repository never fails")
+    }
+
+    #[test]
+    fn valid_message_has_no_violation() {
+        assert_eq!(lint("valid", CommitPolicy::default()), None);
+    }
+
+    #[test]
+    fn free_form_message_is_refused() {
+        assert_eq!(
+            lint("wip", CommitPolicy::default()),
+            Some("not a conventional commit".to_string())
+        );
+    }
+
+    #[test]
+    fn generated_message_is_accepted() {
+        assert_eq!(lint("Merge branch 'main'", CommitPolicy::default()), None);
+        assert_eq!(
+            lint(
+                "Merge pull request #7 from user/branch",
+                CommitPolicy::default()
+            ),
+            None
+        );
+        assert_eq!(lint("Revert \"feat: test\"", CommitPolicy::default()), None);
+    }
+
+    #[test]
+    fn message_resembling_a_generated_one_is_refused() {
+        assert_eq!(
+            lint("Merge everything", CommitPolicy::default()),
+            Some("not a conventional commit".to_string())
+        );
+    }
+
+    #[test]
+    fn policy_is_applied_to_message() {
+        assert_eq!(
+            lint(
+                "valid",
+                CommitPolicy::new(Some(vec!["fix".to_string()]), None)
+            ),
+            Some("type 'feat' is not allowed (did you mean 'fix'?)".to_string())
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use std::cmp::Ordering;
+
+use crate::domain::{commit_summary::CommitSummary, semantic_version::SemanticVersion};
+
+/**
+ * The change a commit triggers on the version.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Change {
+    None,
+    Patch,
+    Minor,
+    Major,
+}
+
+impl Change {
+    /**
+     * The change which leads from a version to another one; a version which is not greater is
+     * no change.
+     */
+    pub fn between(old: &SemanticVersion, new: &SemanticVersion) -> Change {
+        match (
+            new.major().cmp(&old.major()),
+            new.minor().cmp(&old.minor()),
+            new.patch().cmp(&old.patch()),
+        ) {
+            (Ordering::Greater, _, _) => Change::Major,
+            (Ordering::Equal, Ordering::Greater, _) => Change::Minor,
+            (Ordering::Equal, Ordering::Equal, Ordering::Greater) => Change::Patch,
+            _ => Change::None,
+        }
+    }
+}
+
+/**
+ * The outcome of the calculation of a new version, along with the commits it is based on.
+ */
+#[derive(Debug)]
+pub struct VersionDescription {
+    new_version: SemanticVersion,
+    old_version: Option<SemanticVersion>,
+    change: Change,
+    commits: Vec<(CommitSummary, Change)>,
+}
+
+impl VersionDescription {
+    pub fn new(
+        new_version: SemanticVersion,
+        old_version: Option<SemanticVersion>,
+        change: Change,
+        commits: Vec<(CommitSummary, Change)>,
+    ) -> Self {
+        VersionDescription {
+            new_version,
+            old_version,
+            change,
+            commits,
+        }
+    }
+
+    pub fn new_version(&self) -> &SemanticVersion {
+        &self.new_version
+    }
+
+    pub fn old_version(&self) -> Option<&SemanticVersion> {
+        self.old_version.as_ref()
+    }
+
+    pub fn change(&self) -> Change {
+        self.change
+    }
+
+    pub fn commits(&self) -> &[(CommitSummary, Change)] {
+        &self.commits
+    }
+
+    pub fn commits_with_change(&self, change: Change) -> impl Iterator<Item = &CommitSummary> {
+        self.commits
+            .iter()
+            .filter(move |(_, c)| *c == change)
+            .map(|(commit, _)| commit)
+    }
+}